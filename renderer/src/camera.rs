@@ -1,10 +1,16 @@
 use cgmath::*;
+use simulation::infall::{FallEnd, FreeFall};
+use simulation::outcome::RayOutcome;
+use simulation::KerrBlackHole;
 use winit::event::ElementState;
 use winit::keyboard::KeyCode;
 
 #[cfg(target_arch = "wasm32")]
 use web_sys;
 
+/// Proper time, in units of the mass, that passes per second of a free fall
+const FALL_RATE: f32 = 4.0;
+
 #[rustfmt::skip]
 pub const OPENGL_TO_WGPU_MATRIX: cgmath::Matrix4<f32> = cgmath::Matrix4::new(
     1.0, 0.0, 0.0, 0.0,
@@ -79,12 +85,15 @@ pub struct CameraUniform {
     pub render_width: f32,
     pub render_height: f32,
     pub _padding5: [f32; 2],  // Maintain 16-byte alignment
+    /// Lorentz transformation taking a photon's (direction, frequency) in the camera
+    /// observer's frame to the frame the shader traces in, both in world axes
+    pub observer_frame: [[f32; 4]; 4],
 }
 
 impl CameraUniform {
     pub fn new() -> Self {
         // Compile-time size check to ensure proper GPU buffer alignment
-        const _: () = assert!(std::mem::size_of::<CameraUniform>() == 160);
+        const _: () = assert!(std::mem::size_of::<CameraUniform>() == 224);
         use cgmath::SquareMatrix;
         Self {
             view_proj: cgmath::Matrix4::identity().into(),
@@ -103,6 +112,7 @@ impl CameraUniform {
             render_width: 1920.0,
             render_height: 1080.0,
             _padding5: [0.0; 2],
+            observer_frame: cgmath::Matrix4::identity().into(),
         }
     }
 
@@ -177,6 +187,9 @@ pub struct CameraController {
     // Mouselook mode control
     pub mouselook_enabled: bool,
     pub right_mouse_pressed: bool,
+    // Free fall along a timelike geodesic instead of flying
+    pub fall: Option<FreeFall>,
+    fall_end: Option<(f32, FallEnd)>,
 }
 
 impl CameraController {
@@ -225,6 +238,8 @@ impl CameraController {
             // Start with mouselook disabled, user can enable with Escape
             mouselook_enabled: false,
             right_mouse_pressed: false,
+            fall: None,
+            fall_end: None,
         }
     }
 
@@ -235,6 +250,7 @@ impl CameraController {
     }
 
     pub fn reset_camera(&mut self, camera: &mut Camera) {
+        self.stop_fall();
         camera.eye = self.initial_position;
         self.yaw = self.initial_yaw;
         self.pitch = self.initial_pitch;
@@ -254,6 +270,51 @@ impl CameraController {
         camera.up = Vector3::unit_y();
     }
 
+    /// Start falling freely from the camera's position, with +y as the spin axis
+    ///
+    /// Without a `launch_speed` the camera drops as if it had fallen from rest at infinity.
+    /// With one it sets off along its view direction at that speed, in units of c, relative
+    /// to the zero angular momentum observer, which is only possible outside the horizon.
+    pub fn start_fall(&mut self, camera: &Camera, black_hole: KerrBlackHole<f32>, launch_speed: Option<f32>) {
+        let eye = camera.eye.to_vec();
+        let r = eye.magnitude();
+        if r <= 0.0 {
+            return;
+        }
+        let theta = (eye.y / r).acos();
+        let phi = eye.z.atan2(eye.x);
+        let fall = match launch_speed {
+            None => Some(FreeFall::from_rest_at_infinity(black_hole, [r, theta, phi])),
+            Some(speed) => {
+                let forward = (camera.target - camera.eye).normalize() * speed;
+                let [radial, polar, azimuthal] = spherical_axes(theta, phi);
+                let velocity = [forward.dot(radial), forward.dot(polar), forward.dot(azimuthal)];
+                FreeFall::launch(black_hole, [r, theta, phi], velocity)
+            }
+        };
+        if let Some(mut fall) = fall {
+            self.fall_end = fall.time_to_end().map(|(tau, end)| (fall.proper_time + tau, end));
+            // Frames cut the steps short, so a fall from far out can outlast the step budget
+            fall.max_steps = u32::MAX;
+            self.fall = Some(fall);
+            self.current_velocity = Vector3::zero();
+        }
+    }
+
+    pub fn stop_fall(&mut self) {
+        self.fall = None;
+        self.fall_end = None;
+    }
+
+    /// Proper time left before the fall ends inside the hole, in units of the mass, and how
+    /// it ends, or `None` when not falling or on a fall that escapes
+    #[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+    pub fn fall_time_remaining(&self) -> Option<(f32, FallEnd)> {
+        let fall = self.fall.as_ref()?;
+        let (end_time, end) = self.fall_end?;
+        Some(((end_time - fall.proper_time).max(0.0) / fall.black_hole.mass, end))
+    }
+
     pub fn check_and_clear_reset_request(&mut self) -> bool {
         if self.reset_requested {
             self.reset_requested = false;
@@ -535,6 +596,23 @@ impl CameraController {
 
         // Note: Startup help flash message is now handled purely in JavaScript
 
+        // A free fall carries the eye along its geodesic; only looking around is left
+        if let Some(fall) = &mut self.fall {
+            let outcome = fall.advance(FALL_RATE * fall.black_hole.mass * dt);
+            let [_, r, theta, phi] = fall.geodesic.position;
+            let [radial, _, _] = spherical_axes(theta, phi);
+            camera.eye = Point3::from_vec(radial * r);
+            match outcome {
+                RayOutcome::Continuing => {}
+                // Start over once the singularity or the end of the coordinates is reached
+                RayOutcome::Captured(_) => self.reset_requested = true,
+                _ => self.stop_fall(),
+            }
+            camera.target = camera.eye + forward;
+            camera.up = up;
+            return;
+        }
+
         // Handle touch input for movement
         let mut touch_fwd = 0.0;
         let mut touch_strafe = 0.0;
@@ -594,3 +672,15 @@ impl CameraController {
     }
 
 }
+
+/// World directions of r̂, θ̂ and φ̂ at (θ, φ), with +y as the polar axis and φ increasing
+/// from +x towards +z, the sense the shader drags frames in
+fn spherical_axes(theta: f32, phi: f32) -> [Vector3<f32>; 3] {
+    let (sin_theta, cos_theta) = theta.sin_cos();
+    let (sin_phi, cos_phi) = phi.sin_cos();
+    [
+        Vector3::new(sin_theta * cos_phi, cos_theta, sin_theta * sin_phi),
+        Vector3::new(cos_theta * cos_phi, -sin_theta, cos_theta * sin_phi),
+        Vector3::new(-sin_phi, 0.0, cos_phi),
+    ]
+}
@@ -26,9 +26,21 @@ extern "C" {
     #[wasm_bindgen(js_name = updateDebugInfo)]
     fn js_update_debug_info(position: &[f32], orientation: &[f32], last_key: &str, fps: f32, render_width: f32, render_height: f32, velocity: &[f32]);
     
+    #[wasm_bindgen(js_name = updateTidalInfo)]
+    fn js_update_tidal_info(radius: f32, stretch: f32, compression: f32, tension: f32);
+    
     #[wasm_bindgen(js_name = updateProfilingInfo)]
     fn js_update_profiling_info(cpu_time: f32, gpu_time: f32, update_time: f32, render_time: f32, gpu_supported: bool);
     
+    #[wasm_bindgen(js_name = updateFallInfo)]
+    fn js_update_fall_info(falling: bool, radius: f32, time_remaining: f32, end: &str);
+    
+    #[wasm_bindgen(js_name = updateObserverInfo)]
+    fn js_update_observer_info(visible: bool, observer: &str, fallback: &str);
+    
+    #[wasm_bindgen(js_name = updateChargeLimit)]
+    fn js_update_charge_limit(charge: f32, max_charge: f32);
+    
     #[wasm_bindgen(js_name = updateFpsCounter)]
     fn js_update_fps_counter(fps: f32, visible: bool);
     
@@ -41,6 +53,41 @@ extern "C" {
 
 use wgpu::util::{DeviceExt, StagingBelt};
 
+/// Mass of the hole, in solar masses, that the HUD's tidal and free-fall readouts assume
+#[cfg(target_arch = "wasm32")]
+const TIDAL_SOLAR_MASSES: f32 = 10.0;
+
+/// Length in meters and mass in kilograms of the body the tidal readout describes
+#[cfg(target_arch = "wasm32")]
+const TIDAL_BODY: (f32, f32) = (2.0, 70.0);
+
+/// Speed, in units of c, at which L launches the camera along its view
+const LAUNCH_SPEED: f32 = 0.5;
+
+/// Temperature in kelvin of the hottest gas in the accretion disk or torus, picked for
+/// visible colors
+const DISK_PEAK_TEMPERATURE: f32 = 10_000.0;
+
+/// Inner edge and pressure maximum of the torus, in units of the mass
+const TORUS_RADII: (f32, f32) = (8.0, 12.0);
+
+/// Absorption coefficient at the torus pressure maximum per unit mass length, keeping the
+/// torus optically thin
+const TORUS_OPACITY: f32 = 0.2;
+
+/// Width of the parabolic jet funnel, R = w √(M|z|), and half-opening angle in radians of
+/// the conical one
+const JET_FUNNEL: (f32, f32) = (1.0, 0.15);
+
+/// Comoving jet emissivity on the funnel wall at r = M, per unit mass length
+const JET_EMISSIVITY: f32 = 4.0;
+
+/// Observed temperatures in kelvin spanned by the blackbody color lookup table
+const COLOR_TABLE_RANGE: (f32, f32) = (500.0, 50_000.0);
+
+/// Entries in the blackbody color lookup table, log-spaced over `COLOR_TABLE_RANGE`
+const COLOR_TABLE_SIZE: usize = 256;
+
 mod camera;
 use camera::{Camera, CameraController, CameraUniform};
 
@@ -70,8 +117,209 @@ struct BlackHoleUniform {
     frame_drag_coefficient: f32,
     /// Precomputed escape distance squared
     escape_distance_sq: f32,
-    /// Final padding to align struct to 64 bytes
-    _padding2: [f32; 4],
+    /// Electric charge Q in geometric units
+    charge: f32,
+    /// Precomputed charge repulsion factor (2 * charge²)
+    charge_factor: f32,
+    /// Wormhole throat radius
+    throat_radius: f32,
+    /// Scene to trace (0: black hole, 1: wormhole)
+    scene_mode: f32,
+    /// Inner edge of the accretion disk, at the prograde ISCO
+    disk_inner_radius: f32,
+    /// Outer edge of the accretion disk (0 when the disk is hidden)
+    disk_outer_radius: f32,
+    /// Reciprocal of the disk's peak Novikov–Thorne flux
+    disk_flux_scale: f32,
+    /// Temperature in kelvin of the disk's hottest ring
+    disk_temperature: f32,
+    /// Dimensionless spin magnitude the disk profile was computed for
+    disk_spin: f32,
+    /// Temperature in kelvin of the first entry of the color lookup table
+    color_table_min_temperature: f32,
+    /// Temperature in kelvin of the last entry of the color lookup table
+    color_table_max_temperature: f32,
+    /// Specific angular momentum ℓ of the torus gas
+    torus_angular_momentum: f32,
+    /// Effective potential W_in on the torus surface
+    torus_surface_potential: f32,
+    /// Specific enthalpy minus one at the torus pressure maximum
+    torus_peak_enthalpy: f32,
+    /// Inner edge of the torus on the equator
+    torus_inner_radius: f32,
+    /// Outer edge of the torus on the equator (0 when the torus is hidden)
+    torus_outer_radius: f32,
+    /// Polytropic index of the torus gas
+    torus_polytropic_index: f32,
+    /// Absorption coefficient at the torus pressure maximum
+    torus_opacity: f32,
+    /// Temperature in kelvin at the torus pressure maximum
+    torus_temperature: f32,
+    /// Shape of the jet funnel (0: parabolic, 1: conical)
+    jet_shape: f32,
+    /// Unit-luminance linear sRGB of the jet's power-law spectrum
+    jet_color: [f32; 3],
+    /// Width of a parabolic funnel or tangent of a conical one's half-opening angle
+    jet_width: f32,
+    /// Bulk Lorentz factor of the jet plasma
+    jet_lorentz_factor: f32,
+    /// Comoving jet emissivity on the funnel wall at r = M
+    jet_emissivity: f32,
+    /// Spectral index α of the jet's j_ν ∝ ν^(-α)
+    jet_spectral_index: f32,
+    /// Radius beyond which the jet no longer shines (0 when the jet is hidden)
+    jet_length: f32,
+}
+
+impl BlackHoleUniform {
+    /// Build the uniform with precomputed constants for the black hole or wormhole scene,
+    /// with the accretion disk or torus and the jet if they are shown
+    fn new(
+        black_hole: &simulation::kerr_newman::KerrNewmanBlackHole,
+        wormhole: &simulation::wormhole::EllisWormhole,
+        wormhole_mode: bool,
+        disk: Option<&simulation::disk::ThinDisk>,
+        torus: Option<&simulation::torus::PolishDoughnut>,
+        jet: Option<&simulation::jet::Jet>,
+        ray_steps: f32,
+    ) -> Self {
+        let mass = black_hole.mass;
+        let spin = black_hole.spin / mass; // Dimensionless spin for the shader
+        let schwarzschild_radius = 2.0 * mass;
+        let effective_horizon = black_hole.outer_horizon();
+        let frame_drag_coefficient = (spin * spin) * schwarzschild_radius * schwarzschild_radius * 0.5;
+        let escape_distance = 200.0 * mass;
+
+        Self {
+            position: [0.0, 0.0, 0.0], // Centered at origin
+            _padding1: 0.0,
+            mass,
+            spin,
+            ray_steps,
+            schwarzschild_radius,
+            effective_horizon,
+            effective_horizon_sq: effective_horizon * effective_horizon,
+            frame_drag_coefficient,
+            escape_distance_sq: escape_distance * escape_distance,
+            charge: black_hole.charge,
+            charge_factor: 2.0 * black_hole.charge * black_hole.charge,
+            throat_radius: wormhole.throat_radius,
+            scene_mode: if wormhole_mode { 1.0 } else { 0.0 },
+            disk_inner_radius: disk.map_or(0.0, |disk| disk.inner_radius),
+            disk_outer_radius: disk.map_or(0.0, |disk| disk.outer_radius),
+            disk_flux_scale: disk.map_or(0.0, |disk| 1.0 / disk.peak_flux()),
+            disk_temperature: disk.map_or(0.0, |disk| disk.peak_temperature),
+            disk_spin: disk.map_or(0.0, |disk| disk.black_hole.spin.abs() / disk.black_hole.mass),
+            color_table_min_temperature: COLOR_TABLE_RANGE.0,
+            color_table_max_temperature: COLOR_TABLE_RANGE.1,
+            // The shader orbits gas along its frame dragging whatever the sign of the spin
+            torus_angular_momentum: torus.map_or(0.0, |torus| torus.angular_momentum.abs()),
+            torus_surface_potential: torus.map_or(0.0, |torus| torus.surface_potential()),
+            torus_peak_enthalpy: torus.map_or(0.0, |torus| torus.peak_enthalpy()),
+            torus_inner_radius: torus.map_or(0.0, |torus| torus.inner_radius),
+            torus_outer_radius: torus.map_or(0.0, |torus| torus.outer_radius),
+            torus_polytropic_index: torus.map_or(0.0, |torus| torus.polytropic_index),
+            torus_opacity: torus.map_or(0.0, |torus| torus.opacity),
+            torus_temperature: torus.map_or(0.0, |torus| torus.peak_temperature),
+            jet_shape: match jet.map(|jet| jet.shape) {
+                Some(simulation::jet::JetShape::Conical { .. }) => 1.0,
+                _ => 0.0,
+            },
+            jet_color: jet.map_or([0.0; 3], |jet| jet.color()),
+            jet_width: match jet.map(|jet| jet.shape) {
+                Some(simulation::jet::JetShape::Parabolic { width }) => width,
+                Some(simulation::jet::JetShape::Conical { opening_angle }) => opening_angle.tan(),
+                None => 0.0,
+            },
+            jet_lorentz_factor: jet.map_or(1.0, |jet| jet.lorentz_factor),
+            jet_emissivity: jet.map_or(0.0, |jet| jet.emissivity),
+            jet_spectral_index: jet.map_or(0.0, |jet| jet.spectral_index),
+            jet_length: jet.map_or(0.0, |jet| jet.length),
+        }
+    }
+}
+
+/// Thin accretion disk around the hole out to `outer_radius` masses, neglecting its charge
+fn accretion_disk(black_hole: &simulation::kerr_newman::KerrNewmanBlackHole, outer_radius: f32) -> simulation::disk::ThinDisk {
+    let kerr = simulation::KerrBlackHole::new(black_hole.mass, black_hole.spin);
+    simulation::disk::ThinDisk::new(kerr, outer_radius * black_hole.mass, DISK_PEAK_TEMPERATURE)
+}
+
+/// Polish doughnut around the hole with its inner edge and pressure maximum at
+/// `TORUS_RADII`, or `None` if no closed torus fits there, neglecting the hole's charge
+fn accretion_torus(black_hole: &simulation::kerr_newman::KerrNewmanBlackHole) -> Option<simulation::torus::PolishDoughnut> {
+    let mass = black_hole.mass;
+    let kerr = simulation::KerrBlackHole::new(mass, black_hole.spin);
+    let mut torus = simulation::torus::PolishDoughnut::new(kerr, TORUS_RADII.0 * mass, TORUS_RADII.1 * mass)?;
+    torus.opacity = TORUS_OPACITY / mass;
+    torus.peak_temperature = DISK_PEAK_TEMPERATURE;
+    Some(torus)
+}
+
+/// Bipolar jet of the given shape (1: parabolic, 2: conical) and bulk Lorentz factor along
+/// the hole's spin axis, neglecting its charge
+fn relativistic_jet(black_hole: &simulation::kerr_newman::KerrNewmanBlackHole, jet_mode: u32, lorentz_factor: f32) -> simulation::jet::Jet {
+    let mass = black_hole.mass;
+    let kerr = simulation::KerrBlackHole::new(mass, black_hole.spin);
+    let shape = if jet_mode == 2 {
+        simulation::jet::JetShape::Conical { opening_angle: JET_FUNNEL.1 }
+    } else {
+        simulation::jet::JetShape::Parabolic { width: JET_FUNNEL.0 }
+    };
+    let mut jet = simulation::jet::Jet::new(kerr, shape, lorentz_factor);
+    jet.emissivity = JET_EMISSIVITY / mass;
+    jet
+}
+
+/// The hole as the camera's observers and free falls see it, neglecting its charge
+///
+/// The shader drags frames around +y whatever the sign of the spin, so that is the spin
+/// axis and the rotation sense.
+fn camera_black_hole(black_hole: &simulation::kerr_newman::KerrNewmanBlackHole) -> simulation::KerrBlackHole {
+    simulation::KerrBlackHole::new(black_hole.mass, black_hole.spin.abs())
+}
+
+/// Lorentz transformation from the camera's frame at world position `eye` to the frame
+/// rays are traced in, acting on (direction, frequency) in world axes, as columns
+///
+/// The shader treats space at the camera as flat and drags light around the axis itself,
+/// so its rays start out in the frame of a reference observer: the zero angular momentum
+/// observer for a camera held in place, and for a falling one the observer falling from
+/// rest at infinity, which unlike the first exists inside the horizon as well. `transform`
+/// gives the Lorentz transformation into that frame from the local one at (r, θ), with
+/// axes along r̂, θ̂ and φ̂. Where it gives `None`, where an observer cannot exist, so does
+/// this, and the camera falls back to the reference frame.
+fn observer_frame(eye: [f32; 3], transform: impl FnOnce(f32, f32) -> Option<[[f32; 4]; 4]>) -> Option<[[f32; 4]; 4]> {
+    let radius = (eye[0] * eye[0] + eye[1] * eye[1] + eye[2] * eye[2]).sqrt();
+    // Carter's frame is singular on the axis itself
+    let theta = (eye[1] / radius).acos().clamp(1e-3, std::f32::consts::PI - 1e-3);
+    let transform = transform(radius, theta)?;
+
+    // World directions of e_r̂, e_θ̂ and e_φ̂, with φ increasing along the frame dragging
+    let horizontal = (eye[0] * eye[0] + eye[2] * eye[2]).sqrt();
+    let outward = if horizontal > 0.0 { [eye[0] / horizontal, eye[2] / horizontal] } else { [1.0, 0.0] };
+    let (sin_theta, cos_theta) = theta.sin_cos();
+    let axes = [
+        [sin_theta * outward[0], cos_theta, sin_theta * outward[1]],
+        [cos_theta * outward[0], -sin_theta, cos_theta * outward[1]],
+        [-outward[1], 0.0, outward[0]],
+    ];
+
+    // Column j of the result is the image of world axis j, with time last
+    let mut columns = [[0.0; 4]; 4];
+    for (j, column) in columns.iter_mut().enumerate() {
+        let local: [f32; 4] = if j == 3 {
+            [1.0, 0.0, 0.0, 0.0]
+        } else {
+            [0.0, axes[0][j], axes[1][j], axes[2][j]]
+        };
+        let boosted = transform.map(|row| row.iter().zip(local).map(|(x, y)| x * y).sum::<f32>());
+        for (i, entry) in column[..3].iter_mut().enumerate() {
+            *entry = (0..3).map(|a| axes[a][i] * boosted[a + 1]).sum();
+        }
+        column[3] = boosted[0];
+    }
+    columns.iter().flatten().all(|entry| entry.is_finite()).then_some(columns)
 }
 
 #[repr(C)]
@@ -121,20 +369,34 @@ struct State<'a> {
     camera_buffer: wgpu::Buffer,
     camera_bind_group: wgpu::BindGroup,
     camera_controller: CameraController,
-    black_hole: simulation::KerrBlackHole,
+    black_hole: simulation::kerr_newman::KerrNewmanBlackHole,
+    wormhole: simulation::wormhole::EllisWormhole,
+    wormhole_mode: bool,
+    accretion_mode: u32,
+    jet_mode: u32,
+    observer: simulation::observer::Observer,
     last_help_state: bool,
     last_profiling_state: bool,
     black_hole_uniform: BlackHoleUniform,
     black_hole_buffer: wgpu::Buffer,
     black_hole_bind_group: wgpu::BindGroup,
     sky_texture: texture::Texture,
+    #[allow(dead_code)] // Kept alive alongside sky_bind_group
+    color_table: texture::Texture,
     sky_bind_group: wgpu::BindGroup,
+    #[allow(dead_code)] // Kept alive alongside wormhole_sky_bind_group
+    wormhole_sky_texture: texture::Texture,
+    wormhole_sky_bind_group: wgpu::BindGroup,
     background_mode: u32,
     // Debug parameters
     debug_fov: f32,
     debug_mass: f32,
     debug_spin: f32,
+    debug_charge: f32,
+    debug_throat_radius: f32,
     debug_ray_steps: f32,
+    debug_disk_outer_radius: f32,
+    debug_jet_lorentz_factor: f32,
     #[cfg(not(target_arch = "wasm32"))]
     last_render_time: std::time::Instant,
     #[cfg(target_arch = "wasm32")]
@@ -302,37 +564,27 @@ impl<'a> State<'a> {
             label: Some("camera_bind_group"),
         });
 
-        // Create default black hole for the simulation - SCHWARZSCHILD FOR TESTING
-        let black_hole = simulation::KerrBlackHole::new(1.0, 1.0); // Maximal spin for frame-dragging
+        // Create default black hole for the simulation - uncharged with maximal spin for frame-dragging
+        let black_hole = simulation::kerr_newman::KerrNewmanBlackHole::new(1.0, 1.0, 0.0);
+        let wormhole = simulation::wormhole::EllisWormhole::new(1.0);
+        let wormhole_mode = false;
+        let accretion_mode = 0; // 0: thin disk, 1: torus, 2: none
+        let jet_mode = 0; // 0: none, 1: parabolic, 2: conical
+        let observer = simulation::observer::Observer::ZeroAngularMomentum;
 
         // Initialize debug parameters
         let debug_fov = 80.0;
         let debug_mass = black_hole.mass;
-        let debug_spin = black_hole.spin;
+        let debug_spin = black_hole.spin / black_hole.mass;
+        let debug_charge = black_hole.charge / black_hole.mass;
+        let debug_throat_radius = wormhole.throat_radius;
         let debug_ray_steps = 250.0;
+        let debug_disk_outer_radius = 20.0;
+        let debug_jet_lorentz_factor = 3.0;
 
         // Create black hole uniform with precomputed constants
-        let schwarzschild_radius = 2.0 * debug_mass;
-        let a = debug_spin * debug_mass;
-        let effective_horizon = debug_mass + (debug_mass * debug_mass - a * a).max(0.0).sqrt();
-        let effective_horizon_sq = effective_horizon * effective_horizon;
-        let frame_drag_coefficient = (debug_spin * debug_spin) * schwarzschild_radius * schwarzschild_radius * 0.5;
-        let escape_distance = 200.0 * debug_mass;
-        let escape_distance_sq = escape_distance * escape_distance;
-        
-        let black_hole_uniform = BlackHoleUniform {
-            position: [0.0, 0.0, 0.0], // Centered at origin
-            _padding1: 0.0,
-            mass: debug_mass,
-            spin: debug_spin,
-            ray_steps: debug_ray_steps,
-            schwarzschild_radius,
-            effective_horizon,
-            effective_horizon_sq,
-            frame_drag_coefficient,
-            escape_distance_sq,
-            _padding2: [0.0; 4],
-        };
+        let disk = accretion_disk(&black_hole, debug_disk_outer_radius);
+        let black_hole_uniform = BlackHoleUniform::new(&black_hole, &wormhole, wormhole_mode, Some(&disk), None, None, debug_ray_steps);
 
         let black_hole_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
             label: Some("BlackHole Buffer"),
@@ -415,8 +667,46 @@ impl<'a> State<'a> {
                 label: Some("texture_bind_group_layout"),
             });
 
+        // Blackbody colors of observed temperatures, shared by all thermal emission
+        let color_entries = simulation::blackbody::lookup_table(COLOR_TABLE_RANGE.0, COLOR_TABLE_RANGE.1, COLOR_TABLE_SIZE);
+        let color_table = texture::Texture::create_lookup_table(&device, &queue, &color_entries, "color_table").unwrap();
+
+        // Our own sky, with the color lookup table bound next to it
+        let sky_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            multisampled: false,
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            multisampled: false,
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                        },
+                        count: None,
+                    },
+                ],
+                label: Some("sky_bind_group_layout"),
+            });
+
         let sky_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
-            layout: &texture_bind_group_layout,
+            layout: &sky_bind_group_layout,
             entries: &[
                 wgpu::BindGroupEntry {
                     binding: 0,
@@ -426,10 +716,33 @@ impl<'a> State<'a> {
                     binding: 1,
                     resource: wgpu::BindingResource::Sampler(&sky_texture.sampler),
                 },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::TextureView(&color_table.view),
+                },
             ],
             label: Some("sky_bind_group"),
         });
 
+        // Second sky for rays that pass through the wormhole throat
+        let wormhole_sky_texture =
+            texture::Texture::create_procedural_sky(&device, &queue, 1024, 512, "wormhole_sky").unwrap();
+
+        let wormhole_sky_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            layout: &texture_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&wormhole_sky_texture.view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&wormhole_sky_texture.sampler),
+                },
+            ],
+            label: Some("wormhole_sky_bind_group"),
+        });
+
         // Create the shader module
         let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
             label: Some("Shader"),
@@ -443,6 +756,7 @@ impl<'a> State<'a> {
                 bind_group_layouts: &[
                     &camera_bind_group_layout,
                     &black_hole_bind_group_layout,
+                    &sky_bind_group_layout,
                     &texture_bind_group_layout,
                 ],
                 push_constant_ranges: &[],
@@ -514,19 +828,31 @@ impl<'a> State<'a> {
             camera_bind_group,
             camera_controller,
             black_hole,
+            wormhole,
+            wormhole_mode,
+            accretion_mode,
+            jet_mode,
+            observer,
             last_help_state: false,  // Match camera_controller.show_help initial state
             last_profiling_state: false,  // Match camera_controller.show_profiling initial state
             black_hole_uniform,
             black_hole_buffer,
             black_hole_bind_group,
             sky_texture,
+            color_table,
             sky_bind_group,
+            wormhole_sky_texture,
+            wormhole_sky_bind_group,
             background_mode, // 0: texture, 1: procedural, 2: none
             // Initialize debug parameters
             debug_fov,
             debug_mass,
             debug_spin,
+            debug_charge,
+            debug_throat_radius,
             debug_ray_steps,
+            debug_disk_outer_radius,
+            debug_jet_lorentz_factor,
             #[cfg(not(target_arch = "wasm32"))]
             last_render_time: std::time::Instant::now(),
             #[cfg(target_arch = "wasm32")]
@@ -557,6 +883,42 @@ impl<'a> State<'a> {
                             self.background_mode = (self.background_mode + 1) % 3;
                             return true;
                         }
+                        PhysicalKey::Code(winit::keyboard::KeyCode::KeyM) => {
+                            self.wormhole_mode = !self.wormhole_mode;
+                            return true;
+                        }
+                        PhysicalKey::Code(winit::keyboard::KeyCode::KeyK) => {
+                            self.accretion_mode = (self.accretion_mode + 1) % 3;
+                            return true;
+                        }
+                        PhysicalKey::Code(winit::keyboard::KeyCode::KeyJ) => {
+                            self.jet_mode = (self.jet_mode + 1) % 3;
+                            return true;
+                        }
+                        PhysicalKey::Code(winit::keyboard::KeyCode::KeyO) => {
+                            use simulation::observer::Observer;
+                            self.observer = match self.observer {
+                                Observer::ZeroAngularMomentum => Observer::Static,
+                                Observer::Static => Observer::FreeFall,
+                                Observer::FreeFall => Observer::Circular { prograde: true },
+                                Observer::Circular { .. } => Observer::ZeroAngularMomentum,
+                            };
+                            return true;
+                        }
+                        PhysicalKey::Code(winit::keyboard::KeyCode::KeyV) => {
+                            if self.camera_controller.fall.is_some() {
+                                self.camera_controller.stop_fall();
+                            } else if !self.wormhole_mode {
+                                self.camera_controller.start_fall(&self.camera, camera_black_hole(&self.black_hole), None);
+                            }
+                            return true;
+                        }
+                        PhysicalKey::Code(winit::keyboard::KeyCode::KeyL) => {
+                            if !self.wormhole_mode {
+                                self.camera_controller.start_fall(&self.camera, camera_black_hole(&self.black_hole), Some(LAUNCH_SPEED));
+                            }
+                            return true;
+                        }
                         _ => {}
                     }
                 }
@@ -652,7 +1014,11 @@ impl<'a> State<'a> {
                         self.debug_fov = params.fov;
                         self.debug_mass = params.mass;
                         self.debug_spin = params.spin;
+                        self.debug_charge = params.charge;
+                        self.debug_throat_radius = params.throat_radius;
                         self.debug_ray_steps = params.ray_steps;
+                        self.debug_disk_outer_radius = params.disk_outer_radius;
+                        self.debug_jet_lorentz_factor = params.jet_lorentz_factor;
                         
                         // Update camera FOV if it changed
                         if (self.camera.fovy - self.debug_fov).abs() > 0.001 {
@@ -663,25 +1029,42 @@ impl<'a> State<'a> {
             }
         }
 
-        // Update black hole uniform with debug parameters and recompute constants
-        self.black_hole_uniform.mass = self.debug_mass;
-        self.black_hole_uniform.spin = self.debug_spin;
-        self.black_hole_uniform.ray_steps = self.debug_ray_steps;
-        
-        // Recompute precomputed constants when parameters change
-        let schwarzschild_radius = 2.0 * self.debug_mass;
-        let a = self.debug_spin * self.debug_mass;
-        let effective_horizon = self.debug_mass + (self.debug_mass * self.debug_mass - a * a).max(0.0).sqrt();
-        let effective_horizon_sq = effective_horizon * effective_horizon;
-        let frame_drag_coefficient = (self.debug_spin * self.debug_spin) * schwarzschild_radius * schwarzschild_radius * 0.5;
-        let escape_distance = 200.0 * self.debug_mass;
-        let escape_distance_sq = escape_distance * escape_distance;
-        
-        self.black_hole_uniform.schwarzschild_radius = schwarzschild_radius;
-        self.black_hole_uniform.effective_horizon = effective_horizon;
-        self.black_hole_uniform.effective_horizon_sq = effective_horizon_sq;
-        self.black_hole_uniform.frame_drag_coefficient = frame_drag_coefficient;
-        self.black_hole_uniform.escape_distance_sq = escape_distance_sq;
+        // Rebuild the black hole from debug parameters and recompute uniform constants.
+        // Spin and charge are dimensionless; the constructor clamps charge so a² + Q² ≤ M².
+        self.black_hole = simulation::kerr_newman::KerrNewmanBlackHole::new(
+            self.debug_mass,
+            self.debug_spin * self.debug_mass,
+            self.debug_charge * self.debug_mass,
+        );
+        self.wormhole = simulation::wormhole::EllisWormhole::new(self.debug_throat_radius);
+        let disk = (self.accretion_mode == 0).then(|| accretion_disk(&self.black_hole, self.debug_disk_outer_radius));
+        let torus = if self.accretion_mode == 1 { accretion_torus(&self.black_hole) } else { None };
+        let jet = (self.jet_mode != 0).then(|| relativistic_jet(&self.black_hole, self.jet_mode, self.debug_jet_lorentz_factor));
+        self.black_hole_uniform = BlackHoleUniform::new(
+            &self.black_hole,
+            &self.wormhole,
+            self.wormhole_mode,
+            disk.as_ref(),
+            torus.as_ref(),
+            jet.as_ref(),
+            self.debug_ray_steps,
+        );
+
+        // Aberrate the view into the camera's state of motion around the hole, falling or
+        // as the chosen observer, or leave it in the reference frame where that cannot exist
+        let frame = if self.wormhole_mode {
+            None
+        } else if let Some(fall) = &self.camera_controller.fall {
+            observer_frame(self.camera.eye.into(), |_, _| fall.lorentz_transform())
+        } else {
+            let kerr = camera_black_hole(&self.black_hole);
+            let reference = simulation::observer::Observer::ZeroAngularMomentum;
+            observer_frame(self.camera.eye.into(), |radius, theta| self.observer.lorentz_transform(&reference, &kerr, radius, theta))
+        };
+        self.camera_uniform.observer_frame = frame.unwrap_or_else(|| {
+            use cgmath::SquareMatrix;
+            cgmath::Matrix4::identity().into()
+        });
 
         // Update HTML help overlay for WASM
         #[cfg(target_arch = "wasm32")]
@@ -708,6 +1091,26 @@ impl<'a> State<'a> {
                     .unwrap_or_else(|| "None".to_string());
                 
                 js_update_debug_info(&position, &orientation, &last_key, self.camera_controller.fps, self.config.width as f32, self.config.height as f32, &velocity);
+                
+                // Tidal load felt in the falling body's own frame, regular inside the horizon
+                // too, or while hovering as a ZAMO, neglecting the hole's charge
+                let (kerr, radius, tensor) = match &self.camera_controller.fall {
+                    Some(fall) => (fall.black_hole, fall.geodesic.radius(), fall.tidal_tensor()),
+                    None => {
+                        let kerr = simulation::KerrBlackHole::new(self.black_hole.mass, self.black_hole.spin);
+                        let radius = (position[0] * position[0] + position[1] * position[1] + position[2] * position[2]).sqrt();
+                        // The shader drags frames around +y, so that is the spin axis in world space
+                        let theta = (position[1] / radius).acos();
+                        let observer = simulation::observer::Observer::ZeroAngularMomentum;
+                        (kerr, radius, simulation::tidal::tidal_tensor(&kerr, observer, radius, theta))
+                    }
+                };
+                let meters_per_unit = TIDAL_SOLAR_MASSES * simulation::constants::SOLAR_MASS_LENGTH as f32 / kerr.mass;
+                let load = tensor.map(|tensor| tensor.in_si_units(meters_per_unit).load(TIDAL_BODY.0, TIDAL_BODY.1));
+                match load {
+                    Some(load) => js_update_tidal_info(radius / kerr.mass, load.stretch_acceleration, load.compression_acceleration, load.tension),
+                    None => js_update_tidal_info(radius / kerr.mass, f32::NAN, f32::NAN, f32::NAN),
+                }
             }
             
             // Update profiling info independently when profiling is visible
@@ -723,6 +1126,41 @@ impl<'a> State<'a> {
                 }
             }
             
+            // Charge the hole carries once a² + Q² ≤ M² has clamped the slider's, in units of
+            // the mass
+            let mass = self.black_hole.mass;
+            js_update_charge_limit(self.black_hole.charge / mass, self.black_hole.max_charge() / mass);
+
+            // Observer the view is seen by, and the reference frame shown in its place where
+            // it cannot exist
+            use simulation::observer::Observer;
+            let (observer, reference) = match (&self.camera_controller.fall, self.observer) {
+                (Some(_), _) => ("falling camera", "falling from rest at infinity"),
+                (None, Observer::ZeroAngularMomentum) => ("zero angular momentum", "zero angular momentum"),
+                (None, Observer::Static) => ("static", "zero angular momentum"),
+                (None, Observer::FreeFall) => ("falling from rest at infinity", "zero angular momentum"),
+                (None, Observer::Circular { prograde: true }) => ("prograde circular orbit", "zero angular momentum"),
+                (None, Observer::Circular { prograde: false }) => ("retrograde circular orbit", "zero angular momentum"),
+            };
+            js_update_observer_info(!self.wormhole_mode, observer, if frame.is_some() { "" } else { reference });
+
+            // Radius and proper time left until the fall ends while falling, in seconds for a
+            // hole of TIDAL_SOLAR_MASSES, with where it ends
+            match &self.camera_controller.fall {
+                Some(fall) => {
+                    use simulation::infall::FallEnd;
+                    let seconds_per_mass = TIDAL_SOLAR_MASSES * (simulation::constants::SOLAR_MASS_LENGTH / simulation::constants::SPEED_OF_LIGHT) as f32;
+                    let (remaining, end) = match self.camera_controller.fall_time_remaining() {
+                        Some((tau, FallEnd::Singularity)) => (tau * seconds_per_mass, "singularity"),
+                        Some((tau, FallEnd::ThroughRing)) => (tau * seconds_per_mass, "ring"),
+                        Some((tau, FallEnd::InnerHorizon)) => (tau * seconds_per_mass, "inner-horizon"),
+                        None => (f32::NAN, ""),
+                    };
+                    js_update_fall_info(true, fall.geodesic.radius() / fall.black_hole.mass, remaining, end);
+                }
+                None => js_update_fall_info(false, f32::NAN, f32::NAN, ""),
+            }
+            
             // Update FPS counter
             js_update_fps_counter(self.camera_controller.fps, self.camera_controller.show_fps);
         }
@@ -794,6 +1232,7 @@ impl<'a> State<'a> {
             render_pass.set_bind_group(0, &self.camera_bind_group, &[]);
             render_pass.set_bind_group(1, &self.black_hole_bind_group, &[]);
             render_pass.set_bind_group(2, &self.sky_bind_group, &[]);
+            render_pass.set_bind_group(3, &self.wormhole_sky_bind_group, &[]);
             render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
             render_pass.set_index_buffer(self.index_buffer.slice(..), wgpu::IndexFormat::Uint16);
             render_pass.draw_indexed(0..self.num_indices, 0, 0..1);
@@ -951,7 +1390,11 @@ struct DebugParams {
     fov: f32,
     mass: f32,
     spin: f32,
+    charge: f32,
+    throat_radius: f32,
     ray_steps: f32,
+    disk_outer_radius: f32,
+    jet_lorentz_factor: f32,
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -990,6 +1433,29 @@ pub fn set_debug_spin(value: f32) {
     }
 }
 
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn set_debug_charge(value: f32) {
+    unsafe {
+        if let Some(params) = &DEBUG_PARAMS {
+            if let Ok(mut params) = params.lock() {
+                params.charge = value.clamp(-1.0, 1.0);
+            }
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn set_debug_throat_radius(value: f32) {
+    unsafe {
+        if let Some(params) = &DEBUG_PARAMS {
+            if let Ok(mut params) = params.lock() {
+                params.throat_radius = value.clamp(0.2, 5.0);
+            }
+        }
+    }
+}
 
 #[cfg(target_arch = "wasm32")]
 #[wasm_bindgen]
@@ -1003,6 +1469,30 @@ pub fn set_debug_ray_steps(value: f32) {
     }
 }
 
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn set_debug_disk_outer_radius(value: f32) {
+    unsafe {
+        if let Some(params) = &DEBUG_PARAMS {
+            if let Ok(mut params) = params.lock() {
+                params.disk_outer_radius = value.clamp(6.0, 50.0);
+            }
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn set_debug_jet_lorentz_factor(value: f32) {
+    unsafe {
+        if let Some(params) = &DEBUG_PARAMS {
+            if let Ok(mut params) = params.lock() {
+                params.jet_lorentz_factor = value.clamp(1.0, 10.0);
+            }
+        }
+    }
+}
+
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 pub fn run() {
     cfg_if! {
@@ -1016,7 +1506,11 @@ pub fn run() {
                     fov: 80.0,
                     mass: 1.0,
                     spin: 1.0,
+                    charge: 0.0,
+                    throat_radius: 1.0,
                     ray_steps: 250.0,
+                    disk_outer_radius: 20.0,
+                    jet_lorentz_factor: 3.0,
                 })));
             }
         } else {
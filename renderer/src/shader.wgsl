@@ -17,6 +17,7 @@ struct CameraUniform {
     render_width: f32,
     render_height: f32,
     _padding5: vec2<f32>,
+    observer_frame: mat4x4<f32>,
 };
 @group(0) @binding(0)
 var<uniform> camera: CameraUniform;
@@ -32,7 +33,32 @@ struct BlackHoleUniform {
     effective_horizon_sq: f32,
     frame_drag_coefficient: f32,
     escape_distance_sq: f32,
-    _padding2: vec4<f32>,
+    charge: f32,
+    charge_factor: f32,
+    throat_radius: f32,
+    scene_mode: f32,
+    disk_inner_radius: f32,
+    disk_outer_radius: f32,
+    disk_flux_scale: f32,
+    disk_temperature: f32,
+    disk_spin: f32,
+    color_table_min_temperature: f32,
+    color_table_max_temperature: f32,
+    torus_angular_momentum: f32,
+    torus_surface_potential: f32,
+    torus_peak_enthalpy: f32,
+    torus_inner_radius: f32,
+    torus_outer_radius: f32,
+    torus_polytropic_index: f32,
+    torus_opacity: f32,
+    torus_temperature: f32,
+    jet_shape: f32,
+    jet_color: vec3<f32>,
+    jet_width: f32,
+    jet_lorentz_factor: f32,
+    jet_emissivity: f32,
+    jet_spectral_index: f32,
+    jet_length: f32,
 };
 @group(1) @binding(0)
 var<uniform> black_hole: BlackHoleUniform;
@@ -41,6 +67,16 @@ var<uniform> black_hole: BlackHoleUniform;
 var t_sky: texture_2d<f32>;
 @group(2) @binding(1)
 var s_sky: sampler;
+// Blackbody colors: unit-luminance linear sRGB and luminous efficacy in lm/W, for
+// temperatures log-spaced across the table's range
+@group(2) @binding(2)
+var t_color_table: texture_2d<f32>;
+
+// Sky seen through the wormhole throat
+@group(3) @binding(0)
+var t_sky_far: texture_2d<f32>;
+@group(3) @binding(1)
+var s_sky_far: sampler;
 
 struct VertexInput {
     @location(0) position: vec3<f32>,
@@ -87,23 +123,43 @@ fn trace_ray(start_pos: vec3<f32>, ray_dir: vec3<f32>, mass: f32, max_steps: i32
     let escape_distance_sq = black_hole.escape_distance_sq;
     let frame_drag_coefficient = black_hole.frame_drag_coefficient;
     let rs_factor = 1.5 * black_hole.schwarzschild_radius;
+    let charge_factor = black_hole.charge_factor;
     let up_vector = vec3<f32>(0.0, 1.0, 0.0);
 
-    for (var i = 0; i < max_steps; i++) {
+    // λ = L/E of the light reaching the camera, about the axis the disk and torus orbit
+    let lambda = dot(cross(start_pos - bh_pos, dir), up_vector);
+
+    // Light gathered from the torus and jet, and the optical depth in front of whatever lies
+    // behind. The torus is crossed in finer steps, so it gets twice the step budget.
+    let torus_shown = black_hole.torus_outer_radius > 0.0;
+    let jet_shown = black_hole.jet_length > 0.0;
+    let step_budget = select(max_steps, 2 * max_steps, torus_shown);
+    var light = vec3<f32>(0.0);
+    var optical_depth = 0.0;
+    var background = vec3<f32>(0.0);
+    var ended = false;
+
+    for (var i = 0; i < step_budget; i++) {
         let to_bh = bh_pos - pos;
         let r_sq = dot(to_bh, to_bh);
-
-        let step_size = clamp(sqrt(r_sq) * 0.1, 0.005, 0.2);
         
-        // Efficient horizon check using squared distance
-        if (r_sq <= effective_horizon_sq) {
-            return vec3<f32>(0.0, 0.0, 0.0);
+        // Efficient horizon check using squared distance. Only light heading inward ends
+        // there, so a camera that has fallen inside still sees what follows it in.
+        if (r_sq <= effective_horizon_sq && dot(dir, to_bh) > 0.0) {
+            ended = true;
+            break;
         }
 
         // Optimize acceleration calculations with reduced sqrt calls
         let r = sqrt(r_sq);
         let r_cubed = r_sq * r;
-        let base_accel = to_bh * rs_factor / r_cubed;
+        var step_size = clamp(r * 0.1, 0.005, 0.2);
+        if (torus_shown && r <= black_hole.torus_outer_radius) {
+            step_size = min(step_size, 0.1);
+        }
+
+        // Photon orbit equation u'' + u = 3Mu² - 2Q²u³: charge repels at small radii
+        let base_accel = to_bh * (rs_factor - charge_factor / r) / r_cubed;
         
         let tangential = cross(up_vector, to_bh);
         let tangential_normalized = normalize(tangential);
@@ -112,20 +168,263 @@ fn trace_ray(start_pos: vec3<f32>, ray_dir: vec3<f32>, mass: f32, max_steps: i32
         let total_accel = base_accel + frame_drag_accel;
         
         dir = normalize(dir + total_accel * step_size);
+        let previous_pos = pos;
         pos += dir * step_size;
+
+        // Crossing the equatorial plane y = 0 inside the annulus ends on the opaque disk
+        let previous_height = previous_pos.y - bh_pos.y;
+        let height = pos.y - bh_pos.y;
+        if (black_hole.disk_outer_radius > 0.0 && previous_height * height <= 0.0 && previous_height != height) {
+            let crossing = mix(previous_pos, pos, previous_height / (previous_height - height));
+            let disk_r = length(crossing - bh_pos);
+            if (disk_r >= black_hole.disk_inner_radius && disk_r <= black_hole.disk_outer_radius) {
+                background = disk_color(disk_r, lambda);
+                ended = true;
+                break;
+            }
+        }
+
+        // Emission and absorption of the torus gas along this step
+        if (torus_shown) {
+            let sample = torus_sample(pos - bh_pos, lambda);
+            let depth = sample.a * step_size;
+            light += sample.rgb * exp(-optical_depth) * (1.0 - exp(-depth));
+            optical_depth += depth;
+        }
+
+        // Optically thin jet emission along this step
+        if (jet_shown) {
+            light += jet_emission(pos - bh_pos, dir) * exp(-optical_depth) * step_size;
+        }
         
         // Use squared distance comparison to avoid sqrt
         let new_r_sq = dot(bh_pos - pos, bh_pos - pos);
         if (new_r_sq > escape_distance_sq) {
-            return sample_environment(dir);
+            break;
         }
     }
 
-    return sample_environment(dir);
+    if (!ended) {
+        background = sample_environment(dir, false);
+    }
+    return tone_map(light) + exp(-optical_depth) * background;
+}
+
+// Compress linear sRGB of relative luminance L to 1 - exp(-2L), keeping its hue
+fn tone_map(light: vec3<f32>) -> vec3<f32> {
+    let luminance = dot(light, vec3<f32>(0.2126, 0.7152, 0.0722));
+    if (luminance <= 0.0) {
+        return vec3<f32>(0.0);
+    }
+    return light * (1.0 - exp(-2.0 * luminance)) / luminance;
+}
+
+// Polish doughnut gas at the given offset from the hole, seen by light with λ = L/E:
+// luminance-weighted color of its source function as received, relative to a blackbody
+// at the peak temperature, and absorption per unit step. Gas with constant ℓ = -u_φ/u_t
+// has W = ln(-u_t) from u_t⁻² = -(g^tt - 2ℓ g^tφ + ℓ² g^φφ), enthalpy h = exp(W_in - W),
+// temperature ∝ h - 1 and density ∝ (h - 1)ⁿ. The gas measures each step as 1/g long.
+fn torus_sample(offset: vec3<f32>, lambda: f32) -> vec4<f32> {
+    let r = length(offset);
+    if (r < black_hole.torus_inner_radius || r > black_hole.torus_outer_radius) {
+        return vec4<f32>(0.0);
+    }
+    let mass = black_hole.mass;
+    let a = abs(black_hole.spin) * mass;
+    let cos_theta = offset.y / r;
+    let sin_sq = max(1.0 - cos_theta * cos_theta, 1e-6);
+
+    // Inverse Boyer-Lindquist metric in the t-φ block
+    let sigma = r * r + a * a * cos_theta * cos_theta;
+    let delta = r * r - 2.0 * mass * r + a * a;
+    let big_a = (r * r + a * a) * (r * r + a * a) - a * a * delta * sin_sq;
+    let scale = 1.0 / (delta * sigma);
+    let inverse_tt = -big_a * scale;
+    let inverse_t_phi = -2.0 * mass * a * r * scale;
+    let inverse_phi_phi = (delta - a * a * sin_sq) * scale / sin_sq;
+
+    let ell = black_hole.torus_angular_momentum;
+    let norm = -(inverse_tt - 2.0 * ell * inverse_t_phi + ell * ell * inverse_phi_phi);
+    if (norm <= 0.0) {
+        return vec4<f32>(0.0);
+    }
+    let potential = -0.5 * log(norm);
+    if (potential >= black_hole.torus_surface_potential) {
+        return vec4<f32>(0.0);
+    }
+    let relative = (exp(black_hole.torus_surface_potential - potential) - 1.0) / black_hole.torus_peak_enthalpy;
+
+    // g = 1 / (u^t - u^φ λ) for the orbiting gas
+    let u_t = -1.0 / sqrt(norm);
+    let velocity_t = u_t * (inverse_tt - ell * inverse_t_phi);
+    let velocity_phi = u_t * (inverse_t_phi - ell * inverse_phi_phi);
+    let g = 1.0 / max(velocity_t - velocity_phi * lambda, 1e-4);
+
+    let absorption = black_hole.torus_opacity * pow(relative, black_hole.torus_polytropic_index);
+    let observed = thermal_color(g * black_hole.torus_temperature * relative);
+    let reference = thermal_color(black_hole.torus_temperature);
+    let boosted = g * relative;
+    let luminance = boosted * boosted * boosted * boosted * observed.a / reference.a;
+    return vec4<f32>(observed.rgb * luminance, absorption / g);
+}
+
+// Light per unit step from the jet at the given offset from the hole, for a ray heading
+// along `dir` from the camera. Plasma streams away from the equator along the funnel with
+// bulk Lorentz factor Γ and shines with j ∝ (M/r)² (R/R_wall)² ν^(-α) in its own frame.
+// The flat-space Doppler factor 1 / (Γ(1 - β·n)) for light leaving along n = -dir, times
+// the static redshift √(1 - 2M/r), boosts it by g^(3+α) per unit length the plasma
+// measures, and each step is 1/g long to the plasma. Its power-law spectrum keeps one color.
+fn jet_emission(offset: vec3<f32>, dir: vec3<f32>) -> vec3<f32> {
+    let r = length(offset);
+    let mass = black_hole.mass;
+    if (r <= black_hole.effective_horizon || r > black_hole.jet_length) {
+        return vec3<f32>(0.0);
+    }
+    let height = abs(offset.y);
+    let radius = length(offset.xz);
+    let wall = select(black_hole.jet_width * sqrt(mass * height), black_hole.jet_width * height, black_hole.jet_shape > 0.5);
+    if (radius > wall || wall <= 0.0) {
+        return vec3<f32>(0.0);
+    }
+
+    // Streamlines are radial in a cone and follow R² ∝ |z| in a paraboloid
+    let away = select(1.0, -1.0, offset.y < 0.0);
+    let outward = select(offset.xz / (2.0 * max(height, 1e-6)), offset.xz / r, black_hole.jet_shape > 0.5);
+    let along = select(away, offset.y / r, black_hole.jet_shape > 0.5);
+    let velocity = normalize(vec3<f32>(outward.x, along, outward.y));
+
+    let gamma = black_hole.jet_lorentz_factor;
+    let speed = sqrt(1.0 - 1.0 / (gamma * gamma));
+    let g = sqrt(max(1.0 - 2.0 * mass / r, 0.0)) / (gamma * (1.0 + speed * dot(velocity, dir)));
+    let edge = radius / wall;
+    let profile = (mass / r) * (mass / r) * edge * edge;
+    return black_hole.jet_color * black_hole.jet_emissivity * profile * pow(g, 2.0 + black_hole.jet_spectral_index);
 }
 
-// Sample environment (stars, etc.) based on ray direction
-fn sample_environment(dir: vec3<f32>) -> vec3<f32> {
+// Novikov–Thorne flux at radius r relative to the disk's peak (Page & Thorne 1974)
+fn disk_flux(r: f32) -> f32 {
+    let mass = black_hole.mass;
+    let a = black_hole.disk_spin;
+    let x = sqrt(r / mass);
+    let x0 = sqrt(black_hole.disk_inner_radius / mass);
+
+    // Roots of x³ - 3x + 2a
+    let angle = acos(a);
+    let roots = vec3<f32>(
+        2.0 * cos((angle - 3.14159265) / 3.0),
+        2.0 * cos((angle + 3.14159265) / 3.0),
+        -2.0 * cos(angle / 3.0)
+    );
+
+    var bracket = x - x0 - 1.5 * a * log(x / x0);
+    for (var i = 0; i < 3; i++) {
+        let root = roots[i];
+        let other = roots[(i + 1) % 3];
+        let third = roots[(i + 2) % 3];
+        // The coefficient vanishes with the root as a → 0
+        if (abs(root) > 1e-6) {
+            let coefficient = 3.0 * (root - a) * (root - a) / (root * (root - other) * (root - third));
+            bracket -= coefficient * log((x - root) / (x0 - root));
+        }
+    }
+
+    let flux = 3.0 * bracket / (8.0 * 3.14159265 * mass * mass * x * x * x * x * (x * x * x - 3.0 * x + 2.0 * a));
+    return max(flux * black_hole.disk_flux_scale, 0.0);
+}
+
+// Color and luminous efficacy of a blackbody at the given temperature, interpolated
+// from the lookup table and clamped to its range
+fn thermal_color(temperature: f32) -> vec4<f32> {
+    let size = textureDimensions(t_color_table).x;
+    let min_temperature = black_hole.color_table_min_temperature;
+    let span = log(black_hole.color_table_max_temperature / min_temperature);
+    let position = clamp(log(temperature / min_temperature) / span, 0.0, 1.0) * f32(size - 1u);
+    let index = u32(position);
+    let next = min(index + 1u, size - 1u);
+    let low = textureLoad(t_color_table, vec2<u32>(index, 0u), 0);
+    let high = textureLoad(t_color_table, vec2<u32>(next, 0u), 0);
+    return mix(low, high, position - f32(index));
+}
+
+// Light from the disk ring at radius r reaching the camera with λ = L/E. The gas orbits
+// with Ω = √M / (r^{3/2} + a√M) and g = 1 / (u^t (1 - Ωλ)); I_ν / ν³ invariance turns the
+// local blackbody at T into one at gT, with bolometric intensity scaled by g⁴. Brightness
+// is luminance relative to the hottest ring seen at rest.
+fn disk_color(r: f32, lambda: f32) -> vec3<f32> {
+    let mass = black_hole.mass;
+    let a = black_hole.disk_spin * mass;
+    let sqrt_m = sqrt(mass);
+    let omega = sqrt_m / (r * sqrt(r) + a * sqrt_m);
+    let drag = 1.0 - a * omega;
+    let norm = 1.0 - 2.0 * mass / r * drag * drag - (r * r + a * a) * omega * omega;
+    let g = sqrt(max(norm, 0.0)) / (1.0 - omega * lambda);
+
+    let flux = disk_flux(r);
+    let observed = thermal_color(g * black_hole.disk_temperature * sqrt(sqrt(flux)));
+    let reference = thermal_color(black_hole.disk_temperature);
+    let luminance = g * g * g * g * flux * observed.a / reference.a;
+    return observed.rgb * (1.0 - exp(-2.0 * luminance));
+}
+
+// Ellis wormhole ds² = -dt² + dℓ² + (ℓ² + b²)dΩ², traced in the plane of the camera
+// position and ray. Positive ℓ is our side; rays reaching ℓ < 0 see the far sky.
+fn trace_wormhole(start_pos: vec3<f32>, ray_dir: vec3<f32>, max_steps: i32) -> vec3<f32> {
+    let b = black_hole.throat_radius;
+    let offset = start_pos - black_hole.position;
+    var l = max(length(offset), 1e-4);
+    let radial = offset / l;
+    let dir = normalize(ray_dir);
+
+    // In-plane tangent, falling back to any perpendicular for purely radial rays
+    var tangent = dir - dot(dir, radial) * radial;
+    if (dot(tangent, tangent) < 1e-8) {
+        tangent = cross(radial, vec3<f32>(0.0, 1.0, 0.0));
+        if (dot(tangent, tangent) < 1e-8) {
+            tangent = cross(radial, vec3<f32>(1.0, 0.0, 0.0));
+        }
+    }
+    tangent = normalize(tangent);
+
+    // Conserved impact parameter h = √(ℓ² + b²) sin α, with dℓ/dλ = cos α initially
+    let cos_alpha = dot(dir, radial);
+    let sin_alpha = sqrt(max(1.0 - cos_alpha * cos_alpha, 0.0));
+    let h = sqrt(l * l + b * b) * sin_alpha;
+    let h_sq = h * h;
+    var l_dot = cos_alpha;
+    var phi = 0.0;
+
+    // Beyond this distance the remaining bending is negligible
+    let escape_l = max(l, 20.0 * b);
+
+    for (var i = 0; i < max_steps; i++) {
+        // Leapfrog on d²ℓ/dλ² = h²ℓ/(ℓ² + b²)², with dφ/dλ = h/(ℓ² + b²)
+        let r_sq = l * l + b * b;
+        let step_size = clamp(0.2 * sqrt(r_sq), 0.01, 5.0);
+        l_dot += 0.5 * step_size * h_sq * l / (r_sq * r_sq);
+        phi += 0.5 * step_size * h / r_sq;
+
+        l += step_size * l_dot;
+
+        let new_r_sq = l * l + b * b;
+        l_dot += 0.5 * step_size * h_sq * l / (new_r_sq * new_r_sq);
+        phi += 0.5 * step_size * h / new_r_sq;
+
+        if (abs(l) > escape_l && l * l_dot > 0.0) {
+            break;
+        }
+    }
+
+    // Outgoing direction in the local frame of whichever side the ray ended on
+    let e_r = cos(phi) * radial + sin(phi) * tangent;
+    let e_phi = -sin(phi) * radial + cos(phi) * tangent;
+    let outward_speed = sign(l) * l_dot;
+    let final_dir = normalize(outward_speed * e_r + (h / sqrt(l * l + b * b)) * e_phi);
+
+    return sample_environment(final_dir, l < 0.0);
+}
+
+// Sample environment (stars, etc.) based on ray direction, on our side or through the wormhole
+fn sample_environment(dir: vec3<f32>, far_side: bool) -> vec3<f32> {
     // Convert direction to spherical coordinates for equirectangular mapping.
     // The horizontal texture coordinate (u) is flipped to correctly map the panoramic skybox.
     let uv = vec2<f32>(
@@ -139,6 +438,9 @@ fn sample_environment(dir: vec3<f32>) -> vec3<f32> {
         if (camera.background_mode < 0.5) {
             // Mode 0: Skybox texture
             color = textureSample(t_sky, s_sky, uv).rgb;
+            if (far_side) {
+                color = textureSampleLevel(t_sky_far, s_sky_far, uv, 0.0).rgb;
+            }
         } else {
             // Mode 1: Procedural stars
             let star_density = 2000.0; // Lower density for "bigger" stars
@@ -169,6 +471,11 @@ fn sample_environment(dir: vec3<f32>) -> vec3<f32> {
         let gradient = 0.3 * (1.0 - abs(dir.y));
         color = vec3<f32>(gradient * 0.2, gradient * 0.3, gradient * 0.6);
     }
+
+    // Warm tint marks the far side when no far-side texture is shown
+    if (far_side && !(camera.show_stars > 0.5 && camera.background_mode < 0.5)) {
+        color = color.bgr;
+    }
     
     // Add lat/long grid lines if enabled
     if (camera.show_grid > 0.5) {
@@ -217,9 +524,17 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
         -1.0
     );
     
-    let ray_dir = normalize(camera_rotation * ray_dir_camera);
+    // The light arriving against the pixel direction, as the camera's observer sees it,
+    // aberrated into the frame the rays are traced in
+    let arriving = camera.observer_frame * vec4<f32>(-normalize(camera_rotation * ray_dir_camera), 1.0);
+    let ray_dir = -normalize(arriving.xyz);
     
-    let color = trace_ray(camera.camera_pos, ray_dir, black_hole.mass, i32(black_hole.ray_steps));
+    var color: vec3<f32>;
+    if (black_hole.scene_mode > 0.5) {
+        color = trace_wormhole(camera.camera_pos, ray_dir, i32(black_hole.ray_steps));
+    } else {
+        color = trace_ray(camera.camera_pos, ray_dir, black_hole.mass, i32(black_hole.ray_steps));
+    }
     
     return vec4<f32>(color, 1.0);
 }
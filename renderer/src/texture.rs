@@ -78,6 +78,94 @@ impl Texture {
         })
     }
 
+    /// Generate an equirectangular sky for the far side of the wormhole: a warm
+    /// nebula gradient with hashed stars, so no second image asset has to be shipped.
+    pub fn create_procedural_sky(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        width: u32,
+        height: u32,
+        label: &str,
+    ) -> Result<Self> {
+        let sky = image::RgbaImage::from_fn(width, height, |x, y| {
+            let u = x as f32 / width as f32;
+            let v = y as f32 / height as f32;
+
+            // Glowing band tilted across the sky, fading toward the poles
+            let band_center = 0.5 + 0.15 * (u * std::f32::consts::TAU).sin();
+            let band = (-((v - band_center) * 8.0).powi(2)).exp();
+            let swirl = 0.5 + 0.5 * (u * 23.0 + v * 17.0).sin() * (u * 7.0 - v * 31.0).cos();
+            let nebula = band * (0.6 + 0.4 * swirl);
+            let mut color = [0.05 + 0.9 * nebula, 0.02 + 0.45 * nebula, 0.06 + 0.25 * nebula * swirl];
+
+            // Sparse stars from a hash of the pixel coordinates
+            let hash = ((x as f32 * 12.9898 + y as f32 * 78.233).sin() * 43758.547).fract().abs();
+            if hash > 0.998 {
+                let brightness = 0.7 + 150.0 * (hash - 0.998);
+                color = [brightness, brightness * 0.9, brightness * 0.8];
+            }
+
+            let to_byte = |c: f32| (c.clamp(0.0, 1.0) * 255.0) as u8;
+            image::Rgba([to_byte(color[0]), to_byte(color[1]), to_byte(color[2]), 255])
+        });
+
+        Self::from_image(device, queue, &image::DynamicImage::ImageRgba8(sky), Some(label))
+    }
+
+    /// Upload a row of RGBA values as a 32-bit float texture, read with `textureLoad`
+    /// since such textures cannot be filtered everywhere.
+    pub fn create_lookup_table(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        entries: &[[f32; 4]],
+        label: &str,
+    ) -> Result<Self> {
+        let size = wgpu::Extent3d {
+            width: entries.len() as u32,
+            height: 1,
+            depth_or_array_layers: 1,
+        };
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some(label),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba32Float,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+
+        queue.write_texture(
+            wgpu::ImageCopyTexture {
+                texture: &texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            bytemuck::cast_slice(entries),
+            wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(16 * size.width),
+                rows_per_image: Some(1),
+            },
+            size,
+        );
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Nearest,
+            min_filter: wgpu::FilterMode::Nearest,
+            mipmap_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+
+        Ok(Self { texture, view, sampler })
+    }
+
     pub fn create_1x1_black_pixel(
         device: &wgpu::Device,
         queue: &wgpu::Queue,
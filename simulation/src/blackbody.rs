@@ -0,0 +1,223 @@
+//! Colors of thermal emission as a camera would record them.
+//!
+//! A blackbody at temperature T has spectral radiance given by Planck's law,
+//! B_λ(T) = 2hc² / λ⁵ / (exp(hc / λkT) - 1). Integrating it against the CIE 1931 2°
+//! color-matching functions x̄, ȳ, z̄ gives tristimulus values XYZ, with Y = 683 lm/W ∫ B_λ ȳ dλ
+//! the luminance; the standard sRGB matrix then takes XYZ to linear sRGB.
+//!
+//! Because I_ν / ν³ is invariant along a ray, a blackbody at T seen with redshift factor g
+//! arrives as an exact blackbody at gT, so a single temperature indexes every observed color.
+
+use crate::constants::{BOLTZMANN, PLANCK, SPEED_OF_LIGHT, STEFAN_BOLTZMANN};
+use crate::float::Float;
+
+/// Luminous efficacy of monochromatic 555 nm light in lm/W, which scales ȳ to luminance
+pub const MAX_LUMINOUS_EFFICACY: f64 = 683.0;
+
+/// Visible range, in nanometers, that the color-matching integrals cover
+const VISIBLE_RANGE: (f64, f64) = (360.0, 830.0);
+
+/// Simpson intervals across the visible range, one per nanometer
+const INTERVALS: usize = 470;
+
+/// Linear sRGB color and luminance of a blackbody as observed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThermalColor<T: Float = f32> {
+    /// Linear sRGB chromaticity scaled to unit luminance, clipped to the sRGB gamut
+    pub rgb: [T; 3],
+    /// Luminance in cd/m²
+    pub luminance: T,
+}
+
+/// Planck spectral radiance B_λ(T) in W/(sr·m³) at a wavelength in meters
+pub fn spectral_radiance<T: Float>(wavelength: T, temperature: T) -> T {
+    let c = T::from_f64(SPEED_OF_LIGHT);
+    let h = T::from_f64(PLANCK);
+    let exponent = h * c / (wavelength * T::from_f64(BOLTZMANN) * temperature);
+    T::from_f64(2.0) * h * c * c / wavelength.powi(5) / (exponent.exp() - T::ONE)
+}
+
+/// CIE 1931 2° color-matching functions (x̄, ȳ, z̄) at a wavelength in nanometers
+///
+/// Uses the multi-lobe piecewise Gaussian fit of Wyman, Sloan & Shirley (2013). At the
+/// tabulated 10 nm samples it is off by at most 0.015, about 1.5% of the ȳ peak, with
+/// the largest misses around the x̄ lobes near 440 nm and 630 nm.
+pub fn color_matching<T: Float>(wavelength: T) -> [T; 3] {
+    let lobe = |center: f64, below: f64, above: f64| {
+        let offset = wavelength - T::from_f64(center);
+        let width = T::from_f64(if offset < T::ZERO { below } else { above });
+        (-(offset / width).powi(2) * T::from_f64(0.5)).exp()
+    };
+    [
+        T::from_f64(1.056) * lobe(599.8, 37.9, 31.0) + T::from_f64(0.362) * lobe(442.0, 16.0, 26.7)
+            - T::from_f64(0.065) * lobe(501.1, 20.4, 26.2),
+        T::from_f64(0.821) * lobe(568.8, 46.9, 40.5) + T::from_f64(0.286) * lobe(530.9, 16.3, 31.1),
+        T::from_f64(1.217) * lobe(437.0, 11.8, 36.0) + T::from_f64(0.681) * lobe(459.0, 26.0, 13.8),
+    ]
+}
+
+/// CIE XYZ tristimulus values of a blackbody at the given temperature in kelvin, with Y the
+/// luminance in cd/m²
+pub fn blackbody_xyz<T: Float>(temperature: T) -> [T; 3] {
+    spectrum_xyz(|wavelength| spectral_radiance(wavelength, temperature))
+}
+
+/// CIE XYZ tristimulus values of light with the given spectral radiance in W/(sr·m³) as a
+/// function of wavelength in meters, with Y the luminance in cd/m²
+pub fn spectrum_xyz<T: Float>(radiance: impl Fn(T) -> T) -> [T; 3] {
+    let (start, end) = VISIBLE_RANGE;
+    let step = (end - start) / INTERVALS as f64;
+    let mut xyz = [T::ZERO; 3];
+    for i in 0..=INTERVALS {
+        let wavelength = start + step * i as f64;
+        let weight = if i == 0 || i == INTERVALS {
+            1.0
+        } else if i % 2 == 1 {
+            4.0
+        } else {
+            2.0
+        };
+        let radiance = radiance(T::from_f64(wavelength * 1e-9));
+        let matching = color_matching(T::from_f64(wavelength));
+        for (total, value) in xyz.iter_mut().zip(matching) {
+            *total += T::from_f64(weight) * radiance * value;
+        }
+    }
+    // Simpson's rule in nanometers, and the radiance is per meter of wavelength
+    let scale = T::from_f64(MAX_LUMINOUS_EFFICACY * step / 3.0 * 1e-9);
+    xyz.map(|value| value * scale)
+}
+
+/// Linear sRGB from CIE XYZ, with the D65 white point
+pub fn xyz_to_linear_srgb<T: Float>(xyz: [T; 3]) -> [T; 3] {
+    let [x, y, z] = xyz;
+    let row = |a: f64, b: f64, c: f64| T::from_f64(a) * x + T::from_f64(b) * y + T::from_f64(c) * z;
+    [
+        row(3.240_454_2, -1.537_138_5, -0.498_531_4),
+        row(-0.969_266_0, 1.876_010_8, 0.041_556_0),
+        row(0.055_643_4, -0.204_025_9, 1.057_225_2),
+    ]
+}
+
+/// Luminous efficacy in lm/W of a blackbody: its luminance per unit radiance σT⁴/π
+pub fn luminous_efficacy<T: Float>(temperature: T) -> T {
+    let radiance = T::from_f64(STEFAN_BOLTZMANN) * temperature.powi(4) / T::PI;
+    blackbody_xyz(temperature)[1] / radiance
+}
+
+/// Color and luminance of a blackbody at `temperature` kelvin seen with redshift factor g
+///
+/// The observed light is a blackbody at gT. Temperatures too low to give any visible
+/// light come out black.
+pub fn thermal_color<T: Float>(temperature: T, redshift: T) -> ThermalColor<T> {
+    let xyz = blackbody_xyz(redshift * temperature);
+    let luminance = xyz[1];
+    if luminance.is_nan() || luminance <= T::ZERO {
+        return ThermalColor { rgb: [T::ZERO; 3], luminance: T::ZERO };
+    }
+    let rgb = xyz_to_linear_srgb(xyz).map(|value| (value / luminance).max(T::ZERO));
+    ThermalColor { rgb, luminance }
+}
+
+/// Lookup table of observed temperatures, log-spaced from `min_temperature` to
+/// `max_temperature`, for texture upload
+///
+/// Entry i describes T = T_min (T_max / T_min)^(i / (samples - 1)) as the unit-luminance
+/// linear sRGB of `thermal_color` followed by the luminous efficacy in lm/W, so that
+/// a bolometric radiance I becomes luminance I times the last component.
+///
+/// # Panics
+///
+/// Panics if `samples` is less than 2.
+pub fn lookup_table<T: Float>(min_temperature: T, max_temperature: T, samples: usize) -> Vec<[T; 4]> {
+    assert!(samples >= 2, "a lookup table needs at least two samples");
+    let ratio = max_temperature / min_temperature;
+    (0..samples)
+        .map(|i| {
+            let fraction = T::from_f64(i as f64 / (samples - 1) as f64);
+            let temperature = min_temperature * ratio.powf(fraction);
+            let [r, g, b] = thermal_color(temperature, T::ONE).rgb;
+            [r, g, b, luminous_efficacy(temperature)]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_planck_spectrum() {
+        // Integrated over all wavelengths, B_λ gives the Stefan-Boltzmann radiance σT⁴/π
+        let temperature = 5772.0_f64;
+        let mut total = 0.0;
+        let step = 1e-9;
+        for i in 1..200_000 {
+            total += spectral_radiance(i as f64 * step, temperature) * step;
+        }
+        let expected = STEFAN_BOLTZMANN * temperature.powi(4) / std::f64::consts::PI;
+        assert!((total - expected).abs() < 1e-6 * expected, "{} vs {}", total, expected);
+
+        // Wien's displacement law puts the peak at b / T
+        let peak = 2.897_771_955e-3 / temperature;
+        let at_peak = spectral_radiance(peak, temperature);
+        assert!(at_peak > spectral_radiance(peak * 0.999, temperature) && at_peak > spectral_radiance(peak * 1.001, temperature));
+    }
+
+    #[test]
+    fn test_color_matching_fit() {
+        // CIE 1931 2° tabulated values
+        let table = [
+            (440.0_f64, [0.34828, 0.023, 1.74706]),
+            (500.0, [0.0049, 0.323, 0.272]),
+            (555.0, [0.51205, 1.0, 0.00575]),
+            (600.0, [1.0622, 0.631, 0.0008]),
+            (630.0, [0.6424, 0.265, 0.00005]),
+            (700.0, [0.011359, 0.004102, 0.0]),
+        ];
+        for (wavelength, expected) in table {
+            let fit = color_matching(wavelength);
+            for (actual, expected) in fit.iter().zip(expected) {
+                assert!((actual - expected).abs() < 0.015, "{} nm: {:?}", wavelength, fit);
+            }
+        }
+    }
+
+    #[test]
+    fn test_blackbody_chromaticity() {
+        // Points on the Planckian locus: CIE illuminant A and near D65
+        for (temperature, expected) in [(2856.0_f64, [0.4476, 0.4074]), (6504.0, [0.3135, 0.3237])] {
+            let [x, y, z] = blackbody_xyz(temperature);
+            let chromaticity = [x / (x + y + z), y / (x + y + z)];
+            for (actual, expected) in chromaticity.iter().zip(expected) {
+                assert!((actual - expected).abs() < 2e-3, "{} K: {:?}", temperature, chromaticity);
+            }
+        }
+
+        // Near 6500 K the color is close to white, cooler is red and hotter is blue
+        let white = thermal_color(6504.0_f64, 1.0).rgb;
+        assert!(white.iter().all(|channel| (channel - 1.0).abs() < 0.1), "{:?}", white);
+        let cool = thermal_color(3000.0_f64, 1.0).rgb;
+        let hot = thermal_color(20000.0_f64, 1.0).rgb;
+        assert!(cool[0] > cool[1] && cool[1] > cool[2] && hot[2] > hot[1] && hot[1] > hot[0]);
+        assert_eq!(thermal_color(0.0_f64, 1.0).luminance, 0.0);
+    }
+
+    #[test]
+    fn test_redshift_and_lookup_table() {
+        // A blackbody at T seen with factor g is one at gT
+        let shifted = thermal_color(8000.0_f64, 0.6);
+        let direct = thermal_color(4800.0_f64, 1.0);
+        assert_eq!(shifted, direct);
+
+        // The Sun's surface has a luminous efficacy of about 93 lm/W
+        assert!((luminous_efficacy(5772.0_f64) - 93.0).abs() < 2.0);
+
+        let table = lookup_table(1000.0_f64, 40000.0, 5);
+        let sample = (1000.0_f64 * 40.0_f64.powf(0.25), table[1]);
+        let color = thermal_color(sample.0, 1.0);
+        assert_eq!(sample.1[..3], color.rgb);
+        let radiance = STEFAN_BOLTZMANN * sample.0.powi(4) / std::f64::consts::PI;
+        assert!((sample.1[3] * radiance - color.luminance).abs() < 1e-9 * color.luminance);
+    }
+}
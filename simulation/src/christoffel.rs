@@ -0,0 +1,140 @@
+//! Christoffel symbols computed automatically from a metric function.
+//!
+//! The metric is evaluated once per coordinate with that coordinate seeded as a dual
+//! number, which yields every ∂_α g_μν exactly. The symbols then follow from
+//! Γ^μ_αβ = ½ g^μν (∂_α g_νβ + ∂_β g_να - ∂_ν g_αβ).
+
+use crate::dual::Dual;
+use crate::float::Float;
+use crate::metric::invert;
+
+/// Christoffel symbols indexed as `gamma[mu][alpha][beta]` = Γ^μ_αβ
+pub type Christoffel = [[[f32; 4]; 4]; 4];
+
+/// Evaluate the metric and all of its first partial derivatives at a position
+///
+/// Returns (g_μν, ∂_α g_μν) with the derivative indexed as `dg[alpha][mu][nu]`.
+pub fn metric_derivatives<T: Float, F>(metric: F, position: [T; 4]) -> ([[T; 4]; 4], [[[T; 4]; 4]; 4])
+where
+    F: Fn([Dual<T>; 4]) -> [[Dual<T>; 4]; 4],
+{
+    let mut g = [[T::ZERO; 4]; 4];
+    let mut dg = [[[T::ZERO; 4]; 4]; 4];
+
+    for (alpha, dg_alpha) in dg.iter_mut().enumerate() {
+        // Seed the derivative direction along coordinate alpha
+        let mut x = position.map(Dual::constant);
+        x[alpha] = Dual::variable(position[alpha]);
+        let g_dual = metric(x);
+
+        for mu in 0..4 {
+            for nu in 0..4 {
+                g[mu][nu] = g_dual[mu][nu].re;
+                dg_alpha[mu][nu] = g_dual[mu][nu].eps;
+            }
+        }
+    }
+
+    (g, dg)
+}
+
+/// Compute every Christoffel symbol Γ^μ_αβ of a metric function at a position
+///
+/// The metric function must be written generically enough to accept dual numbers,
+/// for example by implementing it against `Float`.
+pub fn christoffel_symbols<F>(metric: F, position: [f32; 4]) -> Christoffel
+where
+    F: Fn([Dual; 4]) -> [[Dual; 4]; 4],
+{
+    let (g, dg) = metric_derivatives(metric, position);
+    let g_inv = invert(&g);
+
+    // Christoffel symbols of the first kind: Γ_ναβ = ½ (∂_α g_νβ + ∂_β g_να - ∂_ν g_αβ)
+    let mut first_kind = [[[0.0; 4]; 4]; 4];
+    for (nu, gamma_nu) in first_kind.iter_mut().enumerate() {
+        for alpha in 0..4 {
+            for beta in 0..4 {
+                gamma_nu[alpha][beta] = 0.5 * (dg[alpha][nu][beta] + dg[beta][nu][alpha] - dg[nu][alpha][beta]);
+            }
+        }
+    }
+
+    // Raise the first index with the inverse metric
+    let mut gamma = [[[0.0; 4]; 4]; 4];
+    for (gamma_mu, g_inv_mu) in gamma.iter_mut().zip(&g_inv) {
+        for (g_inv_mu_nu, gamma_nu) in g_inv_mu.iter().zip(&first_kind) {
+            for alpha in 0..4 {
+                for beta in 0..4 {
+                    gamma_mu[alpha][beta] += g_inv_mu_nu * gamma_nu[alpha][beta];
+                }
+            }
+        }
+    }
+
+    gamma
+}
+
+/// Geodesic acceleration d²x^μ/dλ² = -Γ^μ_αβ u^α u^β for a four-velocity u
+pub fn geodesic_acceleration(gamma: &Christoffel, velocity: [f32; 4]) -> [f32; 4] {
+    let mut acceleration = [0.0; 4];
+    for (a_mu, gamma_mu) in acceleration.iter_mut().zip(gamma) {
+        for (alpha, gamma_mu_alpha) in gamma_mu.iter().enumerate() {
+            for (beta, gamma_mu_alpha_beta) in gamma_mu_alpha.iter().enumerate() {
+                *a_mu -= gamma_mu_alpha_beta * velocity[alpha] * velocity[beta];
+            }
+        }
+    }
+    acceleration
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schwarzschild;
+
+    #[test]
+    fn test_schwarzschild_christoffel_symbols() {
+        let mass = 1.0;
+        let (r, theta) = (5.0_f32, 0.8_f32);
+        let gamma = christoffel_symbols(|x| schwarzschild::metric(mass, x), [0.0, r, theta, 0.3]);
+
+        // Closed-form non-zero symbols of the Schwarzschild metric
+        let f = 1.0 - 2.0 * mass / r;
+        let mut expected = [[[0.0_f32; 4]; 4]; 4];
+        expected[0][0][1] = mass / (r * r * f);
+        expected[0][1][0] = expected[0][0][1];
+        expected[1][0][0] = mass * f / (r * r);
+        expected[1][1][1] = -mass / (r * r * f);
+        expected[1][2][2] = -r * f;
+        expected[1][3][3] = -r * f * theta.sin().powi(2);
+        expected[2][1][2] = 1.0 / r;
+        expected[2][2][1] = 1.0 / r;
+        expected[2][3][3] = -theta.sin() * theta.cos();
+        expected[3][1][3] = 1.0 / r;
+        expected[3][3][1] = 1.0 / r;
+        expected[3][2][3] = theta.cos() / theta.sin();
+        expected[3][3][2] = expected[3][2][3];
+
+        for mu in 0..4 {
+            for alpha in 0..4 {
+                for beta in 0..4 {
+                    let diff = (gamma[mu][alpha][beta] - expected[mu][alpha][beta]).abs();
+                    assert!(diff < 1e-5, "Γ^{}_{}{} = {}, expected {}",
+                            mu, alpha, beta, gamma[mu][alpha][beta], expected[mu][alpha][beta]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_flat_space_has_no_acceleration_along_straight_line() {
+        // Minkowski in spherical coordinates: a radial ray has no coordinate acceleration
+        let flat = |x: [Dual; 4]| schwarzschild::metric(0.0, x);
+        let gamma = christoffel_symbols(flat, [0.0, 3.0, 1.2, 0.0]);
+        let acceleration = geodesic_acceleration(&gamma, [1.0, 1.0, 0.0, 0.0]);
+
+        for a in acceleration {
+            assert!(a.abs() < 1e-6);
+        }
+    }
+}
@@ -0,0 +1,19 @@
+//! Physical constants in SI units.
+//!
+//! The simulation itself works in geometric units with G = c = 1 and lengths in units of
+//! M; these constants convert its results into meters, seconds, kelvin and watts.
+
+/// Speed of light in meters per second
+pub const SPEED_OF_LIGHT: f64 = 299_792_458.0;
+
+/// GM☉/c², the length in meters of one solar mass in geometric units
+pub const SOLAR_MASS_LENGTH: f64 = 1476.625;
+
+/// Planck constant in J·s
+pub const PLANCK: f64 = 6.626_070_15e-34;
+
+/// Boltzmann constant in J/K
+pub const BOLTZMANN: f64 = 1.380_649e-23;
+
+/// Stefan–Boltzmann constant in W/(m²·K⁴)
+pub const STEFAN_BOLTZMANN: f64 = 5.670_374_419e-8;
@@ -0,0 +1,351 @@
+//! Geometrically thin, optically thick accretion disk in the equatorial plane.
+//!
+//! Gas on prograde circular orbits spirals in from `outer_radius` to the ISCO and
+//! radiates the binding energy it loses locally as a blackbody. The Novikov–Thorne flux
+//! follows from conservation of energy and angular momentum with zero torque at the
+//! ISCO, in the closed form of Page & Thorne (1974) with x = √(r/M):
+//! F = 3Ṁ / (8πM² x⁴ (x³ - 3x + 2a)) [x - x₀ - (3a/2) ln(x/x₀) - Σᵢ cᵢ ln((x - xᵢ)/(x₀ - xᵢ))],
+//! where x₀ marks the ISCO, xᵢ are the roots of x³ - 3x + 2a and
+//! cᵢ = 3(xᵢ - a)² / (xᵢ (xᵢ - xⱼ)(xᵢ - xₖ)), all with a in units of M.
+//!
+//! A ray traced back from the camera that crosses θ = π/2 inside the disk ends there. The
+//! ray is the past-directed photon -p_μ of the light the camera receives, so that light
+//! has λ = L/E of the ray itself, and because I_ν / ν³ is invariant the blackbody at T
+//! arrives as a blackbody at gT.
+
+use crate::float::Float;
+use crate::metric::hamilton_equations;
+use crate::outcome::RayOutcome;
+use crate::redshift::circular_emitter_redshift;
+use crate::{Geodesic, Integrator, KerrBlackHole, KerrLightRay};
+
+/// Largest spin, in units of M, a disk is built for: Thorne's (1974) limit on spin-up by
+/// accretion, short of the extremal hole whose ISCO meets the horizon and radiates nothing
+pub const MAX_SPIN: f64 = 0.998;
+
+/// Geometrically thin Novikov–Thorne disk co-rotating with the hole
+#[derive(Debug, Clone, Copy)]
+pub struct ThinDisk<T: Float = f32> {
+    /// Black hole the disk orbits, with its spin clamped to `MAX_SPIN`
+    pub black_hole: KerrBlackHole<T>,
+    /// Inner edge, at the prograde ISCO
+    pub inner_radius: T,
+    /// Outer edge
+    pub outer_radius: T,
+    /// Temperature in kelvin of the hottest ring, where the flux peaks
+    pub peak_temperature: T,
+    /// Flux at that ring, for Ṁ = 1
+    peak_flux: T,
+}
+
+/// Light a disk ring sends towards the camera, after redshift
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DiskEmission<T: Float = f32> {
+    /// Redshift factor g = ν_obs / ν_emit from the orbiting gas to a distant observer
+    pub redshift: T,
+    /// Observed color temperature gT in kelvin, to color with `blackbody::thermal_color`
+    pub temperature: T,
+    /// Observed bolometric intensity g⁴ F / F_peak, relative to the hottest ring seen at rest
+    pub intensity: T,
+}
+
+/// Where a ray traced from the camera met the disk
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DiskHit<T: Float = f32> {
+    /// Radius of the crossing
+    pub radius: T,
+    /// Azimuth φ of the crossing
+    pub phi: T,
+    /// Number of earlier equator crossings outside the disk, 0 for the direct image
+    pub order: u32,
+    /// Light emitted there towards the camera
+    pub emission: DiskEmission<T>,
+}
+
+/// How a ray traced towards a disk ended
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DiskTrace<T: Float = f32> {
+    /// The ray met the disk
+    Hit(DiskHit<T>),
+    /// The ray stopped without meeting the disk, for the given reason
+    Missed(RayOutcome<T>),
+}
+
+impl<T: Float> ThinDisk<T> {
+    /// Disk from the prograde ISCO out to `outer_radius`, with its hottest ring at
+    /// `peak_temperature` kelvin
+    ///
+    /// Spins beyond `MAX_SPIN` are clamped to it. The outer radius is raised to the ISCO if
+    /// it lies inside it, leaving an empty disk.
+    pub fn new(black_hole: KerrBlackHole<T>, outer_radius: T, peak_temperature: T) -> Self {
+        let limit = T::from_f64(MAX_SPIN) * black_hole.mass;
+        let black_hole = KerrBlackHole::new(black_hole.mass, black_hole.spin.clamp(-limit, limit));
+        let inner_radius = black_hole.isco_radius();
+        let mut disk = Self {
+            black_hole,
+            inner_radius,
+            outer_radius: outer_radius.max(inner_radius),
+            peak_temperature,
+            peak_flux: T::ONE,
+        };
+
+        // The profile rises from zero at the ISCO to a single maximum near 1.4 r_isco;
+        // golden-section search brackets it well inside 4 r_isco
+        let ratio = T::from_f64((5.0_f64.sqrt() - 1.0) / 2.0);
+        let (mut low, mut high) = (inner_radius, T::from_f64(4.0) * inner_radius);
+        for _ in 0..80 {
+            let left = high - ratio * (high - low);
+            let right = low + ratio * (high - low);
+            if disk.profile(left) < disk.profile(right) {
+                low = left;
+            } else {
+                high = right;
+            }
+        }
+        disk.peak_flux = disk.profile((low + high) * T::from_f64(0.5));
+        disk
+    }
+
+    /// Whether radius r lies on the disk
+    pub fn contains(&self, r: T) -> bool {
+        r >= self.inner_radius && r <= self.outer_radius
+    }
+
+    /// Novikov–Thorne flux F(r) emitted from each face, per unit accretion rate Ṁ, or zero
+    /// off the disk
+    pub fn flux(&self, r: T) -> T {
+        if self.contains(r) {
+            self.profile(r)
+        } else {
+            T::ZERO
+        }
+    }
+
+    /// Flux of the hottest ring, per unit accretion rate Ṁ
+    pub fn peak_flux(&self) -> T {
+        self.peak_flux
+    }
+
+    /// Local blackbody temperature, T_peak (F / F_peak)^¼
+    pub fn temperature(&self, r: T) -> T {
+        self.peak_temperature * (self.flux(r) / self.peak_flux).max(T::ZERO).sqrt().sqrt()
+    }
+
+    /// Redshift, color and brightness of the light a ring at radius r sends to a distant
+    /// observer with λ = L/E, or `None` off the disk
+    pub fn emission(&self, r: T, lambda: T) -> Option<DiskEmission<T>> {
+        if !self.contains(r) {
+            return None;
+        }
+        let redshift = circular_emitter_redshift(&self.black_hole, r, true, lambda)?;
+        Some(DiskEmission {
+            redshift,
+            temperature: redshift * self.temperature(r),
+            intensity: redshift.powi(4) * self.flux(r) / self.peak_flux,
+        })
+    }
+
+    /// Page–Thorne flux at any radius outside the ISCO
+    fn profile(&self, r: T) -> T {
+        let mass = self.black_hole.mass;
+        let a = self.black_hole.spin.abs() / mass;
+        let (x, x0) = ((r / mass).sqrt(), (self.inner_radius / mass).sqrt());
+        let three = T::from_f64(3.0);
+
+        // Roots of x³ - 3x + 2a, with x₁ at the prograde photon orbit
+        let angle = a.acos();
+        let roots = [
+            T::from_f64(2.0) * ((angle - T::PI) / three).cos(),
+            T::from_f64(2.0) * ((angle + T::PI) / three).cos(),
+            -T::from_f64(2.0) * (angle / three).cos(),
+        ];
+        let mut bracket = x - x0 - T::from_f64(1.5) * a * (x / x0).ln();
+        for (i, &root) in roots.iter().enumerate() {
+            // The coefficient vanishes with the root as a → 0
+            if root == T::ZERO {
+                continue;
+            }
+            let (other, third) = (roots[(i + 1) % 3], roots[(i + 2) % 3]);
+            let coefficient = three * (root - a) * (root - a) / (root * (root - other) * (root - third));
+            bracket -= coefficient * ((x - root) / (x0 - root)).ln();
+        }
+
+        let denominator = T::from_f64(8.0) * T::PI * mass * mass * x.powi(4) * (x * x * x - three * x + T::from_f64(2.0) * a);
+        (three * bracket / denominator).max(T::ZERO)
+    }
+}
+
+/// Step `ray`, made past-directed, until it meets `disk` or stops
+///
+/// Each step that changes the sign of cos θ is searched for the crossing on the cubic
+/// Hermite interpolant between its endpoints, which is fourth-order accurate like the
+/// integrator itself. The disk's redshift there comes from the ray's λ = L/E.
+pub(crate) fn trace<T: Float, I: Integrator<T>>(ray: &mut KerrLightRay<T, I>, disk: &ThinDisk<T>) -> DiskTrace<T> {
+    ray.make_past_directed();
+    let metric = ray.tracer.metric;
+    let derivatives = |state| hamilton_equations(&metric, state);
+    let lambda = ray.conserved.angular_momentum_z / ray.conserved.energy;
+    let mut order = 0;
+    loop {
+        let (before, start) = (ray.tracer.geodesic, ray.tracer.affine_parameter);
+        let outcome = ray.step();
+        let (after, step) = (ray.tracer.geodesic, ray.tracer.affine_parameter - start);
+
+        let equator = T::PI * T::from_f64(0.5);
+        let (below, above) = (before.position[2] - equator, after.position[2] - equator);
+        if step > T::ZERO && (below < T::ZERO) != (above < T::ZERO) {
+            let interpolate = hermite(before, derivatives(before), after, derivatives(after), step);
+            let crossing = crossing_fraction(|s| interpolate(s, 2) - equator, below < T::ZERO);
+            let radius = interpolate(crossing, 1);
+            if let Some(emission) = disk.emission(radius, lambda) {
+                return DiskTrace::Hit(DiskHit { radius, phi: interpolate(crossing, 3), order, emission });
+            }
+            order += 1;
+        }
+        if !outcome.is_continuing() {
+            return DiskTrace::Missed(outcome);
+        }
+    }
+}
+
+/// Cubic Hermite interpolant of each position coordinate across a step of length h,
+/// as a function of (fraction of the step, coordinate index)
+fn hermite<T: Float>(start: Geodesic<T>, start_rate: Geodesic<T>, end: Geodesic<T>, end_rate: Geodesic<T>, h: T) -> impl Fn(T, usize) -> T {
+    move |s: T, index: usize| {
+        let (two, three) = (T::from_f64(2.0), T::from_f64(3.0));
+        let (s2, s3) = (s * s, s * s * s);
+        let h00 = two * s3 - three * s2 + T::ONE;
+        let h10 = s3 - two * s2 + s;
+        let h01 = three * s2 - two * s3;
+        let h11 = s3 - s2;
+        h00 * start.position[index] + h10 * h * start_rate.position[index]
+            + h01 * end.position[index] + h11 * h * end_rate.position[index]
+    }
+}
+
+/// Bisect [0, 1] for the root of `f`, which is negative at 0 if `rising`
+fn crossing_fraction<T: Float>(f: impl Fn(T) -> T, rising: bool) -> T {
+    let (mut low, mut high) = (T::ZERO, T::ONE);
+    for _ in 0..60 {
+        let mid = (low + high) * T::from_f64(0.5);
+        if (f(mid) < T::ZERO) == rising {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    (low + high) * T::from_f64(0.5)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dormand_prince::DormandPrince54;
+    use crate::timelike::circular_orbit;
+
+    /// Page–Thorne flux straight from its defining integral,
+    /// F = -Ω' / (4πr (E - ΩL)²) ∫ (E - ΩL) L' dr from the ISCO, per unit mass and Ṁ
+    fn flux_from_orbits(bh: &KerrBlackHole<f64>, r: f64) -> f64 {
+        let orbit = |r: f64| {
+            let conserved = circular_orbit(bh, r, true, 1.0).unwrap();
+            let omega = bh.mass.sqrt() / (r.powf(1.5) + bh.spin * bh.mass.sqrt());
+            (conserved.energy, conserved.angular_momentum_z, omega)
+        };
+        let derivative = |f: &dyn Fn(f64) -> f64, r: f64| (f(r + 1e-5) - f(r - 1e-5)) / 2e-5;
+        let integrand = |r: f64| {
+            let (e, l, omega) = orbit(r);
+            (e - omega * l) * derivative(&|r| orbit(r).1, r)
+        };
+
+        // Simpson's rule from the ISCO
+        let isco = bh.isco_radius();
+        let n = 2000;
+        let h = (r - isco) / n as f64;
+        let mut integral = integrand(isco + 1e-9) + integrand(r);
+        for k in 1..n {
+            integral += integrand(isco + k as f64 * h) * if k % 2 == 1 { 4.0 } else { 2.0 };
+        }
+        integral *= h / 3.0;
+
+        let (e, l, omega) = orbit(r);
+        -derivative(&|r| orbit(r).2, r) / (4.0 * std::f64::consts::PI * r * (e - omega * l).powi(2)) * integral
+    }
+
+    #[test]
+    fn test_novikov_thorne_profile() {
+        for spin in [0.0, 0.5, 0.95] {
+            let bh = KerrBlackHole::<f64>::new(1.0, spin);
+            let disk = ThinDisk::new(bh, 1000.0, 1e4);
+            assert_eq!(disk.flux(disk.inner_radius), 0.0);
+            for factor in [1.1, 1.5, 3.0, 10.0] {
+                let r = factor * disk.inner_radius;
+                let expected = flux_from_orbits(&bh, r);
+                assert!((disk.flux(r) - expected).abs() < 1e-6 * expected, "a = {}, r = {}: {} vs {}", spin, r, disk.flux(r), expected);
+            }
+        }
+
+        // Newtonian far field 3M/(8πr³)(1 - √(r_in/r)), up to corrections of order √(M/r),
+        // and the peak is the hottest ring
+        let disk = ThinDisk::new(KerrBlackHole::<f64>::schwarzschild(1.0), 1e9, 1e4);
+        let r = 1e8;
+        let newtonian = 3.0 / (8.0 * std::f64::consts::PI * r * r * r) * (1.0 - (6.0 / r).sqrt());
+        assert!((disk.flux(r) - newtonian).abs() < 1e-3 * newtonian, "{} vs {}", disk.flux(r), newtonian);
+        let hottest = (600..1200).map(|k| disk.temperature(k as f64 * 0.01)).fold(0.0, f64::max);
+        assert!((hottest - 1e4).abs() < 1e-6 * 1e4 && disk.temperature(8.0) < 1e4);
+        assert_eq!(disk.flux(3.0), 0.0);
+    }
+
+    #[test]
+    fn test_extremal_spin_is_clamped() {
+        let extremal = ThinDisk::new(KerrBlackHole::<f64>::new(1.0, 1.0), 30.0, 1e4);
+        let limit = ThinDisk::new(KerrBlackHole::<f64>::new(1.0, MAX_SPIN), 30.0, 1e4);
+        assert_eq!(extremal.black_hole.spin, MAX_SPIN);
+        assert_eq!(extremal.inner_radius, limit.inner_radius);
+        assert!(extremal.peak_flux() > 0.0);
+
+        let r = 2.0 * extremal.inner_radius;
+        assert!(extremal.temperature(r) > 0.0 && extremal.temperature(r) <= 1e4);
+        let emission = extremal.emission(r, 0.0).unwrap();
+        assert!(emission.intensity.is_finite() && emission.intensity > 0.0);
+    }
+
+    #[test]
+    fn test_disk_crossings_and_emission() {
+        let bh = KerrBlackHole::<f64>::new(1.0, 0.9);
+        let disk = ThinDisk::new(bh, 30.0, 1e4);
+        let camera = [40.0 * 1.2_f64.sin(), 0.0, 40.0 * 1.2_f64.cos()];
+        for target in [[12.0, 0.0, 0.0], [0.0, -15.0, 0.0], [-20.0, 3.0, 0.0]] {
+            let direction = [0, 1, 2].map(|i| target[i] - camera[i]);
+            let mut ray = KerrLightRay::new(camera, direction, bh).with_integrator(DormandPrince54::default());
+            ray.tracer.step_size = 0.01;
+            ray.make_past_directed();
+            let mut reference = ray.clone();
+            let DiskTrace::Hit(hit) = ray.trace_disk(&disk) else {
+                panic!("ray along {:?} missed the disk", direction);
+            };
+
+            // Dense output of the same steps pins down the crossing independently
+            let expected = loop {
+                assert!(reference.step().is_continuing());
+                let dense = reference.tracer.integrator.dense_output().unwrap();
+                if let Some((_, state)) = dense.find_crossing(|s| s.position[2] - std::f64::consts::FRAC_PI_2) {
+                    if disk.contains(state.radius()) {
+                        break state;
+                    }
+                }
+            };
+            assert!((hit.radius - expected.radius()).abs() < 1e-4 * hit.radius, "{} vs {}", hit.radius, expected.radius());
+            assert!((hit.phi - expected.position[3]).abs() < 1e-4 * hit.phi.abs().max(1.0), "{} vs {}", hit.phi, expected.position[3]);
+
+            // The camera receives the ray's own λ = L/E and sees the ring's blackbody at gT
+            let lambda = ray.conserved.angular_momentum_z / ray.conserved.energy;
+            let g = circular_emitter_redshift(&bh, hit.radius, true, lambda).unwrap();
+            assert_eq!(hit.emission.redshift, g);
+            assert!((hit.emission.temperature - g * disk.temperature(hit.radius)).abs() < 1e-9);
+        }
+
+        // Straight out, away from the hole, nothing is hit
+        let mut ray = KerrLightRay::new(camera, [1.0, 0.0, 0.0], bh);
+        assert!(matches!(ray.trace_disk(&disk), DiskTrace::Missed(RayOutcome::Escaped(_))));
+    }
+}
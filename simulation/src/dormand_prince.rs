@@ -0,0 +1,317 @@
+//! Dormand–Prince 5(4) integrator with first-same-as-last reuse and dense output.
+//!
+//! The seventh stage of an accepted step is the derivative at the new state, so it is
+//! kept and reused as the first stage of the next step (six evaluations per step rather
+//! than seven). The stages also define a fourth-order interpolant over the whole step,
+//! which locates events such as a disk-plane crossing without shrinking the step.
+
+use crate::float::Float;
+use crate::{AdaptiveRK45, Geodesic, Integrator};
+
+/// Number of bisection steps used to refine a crossing on the interpolant
+const CROSSING_REFINEMENT_STEPS: usize = 60;
+
+// Dormand–Prince 5(4) tableau; the geodesic equations are autonomous, so the nodes c_i
+// are not needed, and the last row of `A` doubles as the fifth-order weights
+const A: [[f64; 6]; 7] = [
+    [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [1.0 / 5.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [3.0 / 40.0, 9.0 / 40.0, 0.0, 0.0, 0.0, 0.0],
+    [44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0, 0.0, 0.0, 0.0],
+    [19372.0 / 6561.0, -25360.0 / 2187.0, 64448.0 / 6561.0, -212.0 / 729.0, 0.0, 0.0],
+    [9017.0 / 3168.0, -355.0 / 33.0, 46732.0 / 5247.0, 49.0 / 176.0, -5103.0 / 18656.0, 0.0],
+    [35.0 / 384.0, 0.0, 500.0 / 1113.0, 125.0 / 192.0, -2187.0 / 6784.0, 11.0 / 84.0],
+];
+/// Difference between the fifth- and fourth-order weights
+const E: [f64; 7] = [
+    71.0 / 57600.0, 0.0, -71.0 / 16695.0, 71.0 / 1920.0, -17253.0 / 339200.0, 22.0 / 525.0, -1.0 / 40.0,
+];
+/// Weights of the fourth-order dense-output correction (Hairer, Nørsett & Wanner)
+const D: [f64; 7] = [
+    -12715105075.0 / 11282082432.0,
+    0.0,
+    87487479700.0 / 32700410799.0,
+    -10690763975.0 / 1880347072.0,
+    701980252875.0 / 199316789632.0,
+    -1453857185.0 / 822651844.0,
+    69997945.0 / 29380423.0,
+];
+
+/// Adaptive Dormand–Prince 5(4) integrator for geodesic equations
+#[derive(Debug, Clone)]
+pub struct DormandPrince54<T: Float = f32> {
+    /// Absolute tolerance for error control
+    pub abs_tolerance: T,
+    /// Relative tolerance for error control
+    pub rel_tolerance: T,
+    /// Minimum step size
+    pub min_step: T,
+    /// Maximum step size
+    pub max_step: T,
+    /// Safety factor for step size adjustment
+    pub safety_factor: T,
+    /// Last accepted state and the derivative there, reused as the next first stage
+    fsal: Option<(Geodesic<T>, Geodesic<T>)>,
+    /// Interpolant over the last accepted step
+    dense_output: Option<DenseOutput<T>>,
+}
+
+impl<T: Float> Default for DormandPrince54<T> {
+    fn default() -> Self {
+        let rk45 = AdaptiveRK45::<T>::default();
+        Self {
+            abs_tolerance: rk45.abs_tolerance,
+            rel_tolerance: rk45.rel_tolerance,
+            min_step: rk45.min_step,
+            max_step: rk45.max_step,
+            safety_factor: rk45.safety_factor,
+            fsal: None,
+            dense_output: None,
+        }
+    }
+}
+
+impl<T: Float> DormandPrince54<T> {
+    /// Interpolant over the most recently accepted step, if any
+    pub fn dense_output(&self) -> Option<&DenseOutput<T>> {
+        self.dense_output.as_ref()
+    }
+
+    /// Forget the cached derivative and interpolant, e.g. after editing the state by hand
+    pub fn reset(&mut self) {
+        self.fsal = None;
+        self.dense_output = None;
+    }
+}
+
+impl<T: Float> Integrator<T> for DormandPrince54<T> {
+    fn step(
+        &mut self,
+        state: Geodesic<T>,
+        step_size: T,
+        derivatives_fn: impl Fn(Geodesic<T>) -> Geodesic<T>,
+    ) -> (Geodesic<T>, T, T) {
+        let a = A.map(|row| row.map(T::from_f64));
+        let e = E.map(T::from_f64);
+
+        // First same as last: the derivative at the end of the previous step
+        let k1 = match self.fsal {
+            Some((cached, derivative)) if same_state(&cached, &state) => derivative,
+            _ => derivatives_fn(state),
+        };
+
+        let mut h = step_size.clamp(self.min_step, self.max_step);
+        loop {
+            let mut k = [k1; 7];
+            for stage in 1..7 {
+                let mut temp_state = state;
+                for (k_j, &a_ij) in k.iter().zip(&a[stage]).take(stage) {
+                    temp_state.add_scaled(k_j, h * a_ij);
+                }
+                k[stage] = derivatives_fn(temp_state);
+            }
+
+            // The seventh stage was evaluated at the fifth-order solution itself
+            let mut y5 = state;
+            for (k_j, &b_j) in k.iter().zip(&a[6]).take(6) {
+                y5.add_scaled(k_j, h * b_j);
+            }
+
+            let mut error_state = Geodesic::new([T::ZERO; 4], [T::ZERO; 4]);
+            for (k_j, &e_j) in k.iter().zip(&e) {
+                error_state.add_scaled(k_j, h * e_j);
+            }
+            let error = error_state.max_abs();
+            let tolerance = self.abs_tolerance + self.rel_tolerance * state_norm(&state);
+
+            let ratio = tolerance / error.max(T::from_f64(1e-14));
+            let new_step = h * self.safety_factor * ratio.powf(T::from_f64(0.2));
+            let suggested_step = new_step.clamp(self.min_step, self.max_step);
+
+            // Accept, or give up shrinking once the minimum step is reached
+            if error <= tolerance || h <= self.min_step {
+                self.fsal = Some((y5, k[6]));
+                self.dense_output = Some(DenseOutput::new(state, y5, h, &k));
+                return (y5, h, suggested_step);
+            }
+
+            h = suggested_step;
+        }
+    }
+}
+
+/// Fourth-order continuous extension of one accepted Dormand–Prince step
+#[derive(Debug, Clone, Copy)]
+pub struct DenseOutput<T: Float = f32> {
+    /// Affine step length covered by the interpolant
+    pub step: T,
+    coefficients: [Geodesic<T>; 5],
+}
+
+impl<T: Float> DenseOutput<T> {
+    fn new(start: Geodesic<T>, end: Geodesic<T>, h: T, k: &[Geodesic<T>; 7]) -> Self {
+        let zero = Geodesic::new([T::ZERO; 4], [T::ZERO; 4]);
+
+        let mut r2 = end;
+        r2.add_scaled(&start, -T::ONE);
+
+        let mut r3 = zero;
+        r3.add_scaled(&k[0], h);
+        r3.add_scaled(&r2, -T::ONE);
+
+        let mut r4 = r2;
+        r4.add_scaled(&k[6], -h);
+        r4.add_scaled(&r3, -T::ONE);
+
+        let mut r5 = zero;
+        for (k_j, &d_j) in k.iter().zip(&D) {
+            r5.add_scaled(k_j, h * T::from_f64(d_j));
+        }
+
+        Self { step: h, coefficients: [start, r2, r3, r4, r5] }
+    }
+
+    /// State at fraction `fraction` ∈ [0, 1] of the step
+    pub fn evaluate(&self, fraction: T) -> Geodesic<T> {
+        let [r1, r2, r3, r4, r5] = &self.coefficients;
+        let s = fraction;
+        let s1 = T::ONE - s;
+
+        let mut state = *r1;
+        for i in 0..4 {
+            state.position[i] += s * (r2.position[i] + s1 * (r3.position[i]
+                + s * (r4.position[i] + s1 * r5.position[i])));
+            state.momentum[i] += s * (r2.momentum[i] + s1 * (r3.momentum[i]
+                + s * (r4.momentum[i] + s1 * r5.momentum[i])));
+        }
+        state
+    }
+
+    /// Locate where `event` changes sign within the step
+    ///
+    /// Returns the affine offset from the start of the step and the interpolated state
+    /// there, or `None` if `event` has the same sign at both ends.
+    pub fn find_crossing(&self, event: impl Fn(&Geodesic<T>) -> T) -> Option<(T, Geodesic<T>)> {
+        let mut low = T::ZERO;
+        let mut high = T::ONE;
+        let low_value = event(&self.evaluate(low));
+        let high_value = event(&self.evaluate(high));
+        if low_value == T::ZERO {
+            return Some((T::ZERO, self.evaluate(low)));
+        }
+        if (low_value < T::ZERO) == (high_value < T::ZERO) {
+            return None;
+        }
+
+        let half = T::from_f64(0.5);
+        for _ in 0..CROSSING_REFINEMENT_STEPS {
+            let mid = half * (low + high);
+            if (event(&self.evaluate(mid)) < T::ZERO) == (low_value < T::ZERO) {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+
+        let fraction = half * (low + high);
+        Some((fraction * self.step, self.evaluate(fraction)))
+    }
+}
+
+/// Euclidean norm of a state, used to scale the relative tolerance
+fn state_norm<T: Float>(state: &Geodesic<T>) -> T {
+    state.position.iter().chain(&state.momentum).map(|&x| x * x).sum::<T>().sqrt()
+}
+
+/// Whether two states are bit-for-bit the same point in phase space
+fn same_state<T: Float>(a: &Geodesic<T>, b: &Geodesic<T>) -> bool {
+    a.position.iter().zip(&b.position).chain(a.momentum.iter().zip(&b.momentum)).all(|(x, y)| x == y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{KerrBlackHole, KerrLightRay};
+    use std::cell::Cell;
+
+    /// Harmonic oscillator x'' = -x stored as x = position[1], v = momentum[2]
+    fn oscillator(state: Geodesic<f64>) -> Geodesic<f64> {
+        Geodesic::new([0.0, state.momentum[2], 0.0, 0.0], [0.0, 0.0, -state.position[1], 0.0])
+    }
+
+    #[test]
+    fn test_oscillator_period_with_fsal() {
+        let mut integrator = DormandPrince54::<f64>::default();
+        let evaluations = Cell::new(0);
+        let counted = |state| {
+            evaluations.set(evaluations.get() + 1);
+            oscillator(state)
+        };
+
+        let mut state = Geodesic::new([0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 0.0, 0.0]);
+        let mut lambda = 0.0;
+        let mut h = 0.1;
+        let mut steps = 0;
+        let period = 2.0 * std::f64::consts::PI;
+        while lambda < period {
+            let (next, used, suggested) = integrator.step(state, h.min(period - lambda), counted);
+            state = next;
+            lambda += used;
+            h = suggested;
+            steps += 1;
+        }
+
+        assert!((state.position[1] - 1.0).abs() < 1e-5, "x = {}", state.position[1]);
+        assert!(state.momentum[2].abs() < 1e-5, "v = {}", state.momentum[2]);
+        // One evaluation to start, then six per step unless a step was rejected
+        assert!(evaluations.get() <= 1 + 6 * steps + 6, "{} evaluations for {} steps", evaluations.get(), steps);
+    }
+
+    #[test]
+    fn test_dense_output_matches_exact_solution() {
+        let mut integrator = DormandPrince54::<f64>::default();
+        let start = Geodesic::new([0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 0.0, 0.0]);
+        let (_, used, _) = integrator.step(start, 0.5, oscillator);
+        let dense = integrator.dense_output().unwrap();
+
+        for fraction in [0.0, 0.25, 0.5, 0.9, 1.0] {
+            let lambda = fraction * used;
+            let state = dense.evaluate(fraction);
+            assert!((state.position[1] - lambda.cos()).abs() < 1e-6);
+            assert!((state.momentum[2] + lambda.sin()).abs() < 1e-6);
+        }
+
+        // x = cos λ first vanishes at λ = π/2
+        let mut state = start;
+        let mut h = 0.3;
+        let crossing = loop {
+            let (next, _, suggested) = integrator.step(state, h, oscillator);
+            if let Some(found) = integrator.dense_output().unwrap().find_crossing(|s| s.position[1]) {
+                break found;
+            }
+            state = next;
+            h = suggested;
+        };
+        assert!(crossing.1.position[1].abs() < 1e-9);
+        assert!((crossing.1.momentum[2] + 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_kerr_ray_equatorial_crossing() {
+        // A ray launched from above the equator crosses θ = π/2 on its way in
+        let bh = KerrBlackHole::<f64>::new(1.0, 0.9);
+        let mut ray = KerrLightRay::new([15.0, 0.0, 3.0], [-1.0, 0.3, -0.4], bh)
+            .with_integrator(DormandPrince54::default());
+
+        let equator = |state: &Geodesic<f64>| state.position[2] - std::f64::consts::FRAC_PI_2;
+        let mut crossing = None;
+        while crossing.is_none() && ray.step().is_continuing() {
+            crossing = ray.tracer.integrator.dense_output().unwrap().find_crossing(equator);
+        }
+
+        let (offset, state) = crossing.expect("ray never reached the equator");
+        assert!(offset >= 0.0);
+        assert!(equator(&state).abs() < 1e-9);
+        assert!(state.radius() > bh.outer_horizon() && state.radius() < 15.5);
+    }
+}
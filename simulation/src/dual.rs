@@ -0,0 +1,269 @@
+//! Forward-mode automatic differentiation with dual numbers.
+//!
+//! A dual number a + bε with ε² = 0 carries a value together with its derivative
+//! along one seeded direction. `Dual` implements `Float`, so evaluating any function
+//! written against `Float` with dual inputs yields exact first derivatives, with no
+//! finite-difference error.
+
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::float::Float;
+
+/// Dual number holding a value and its derivative
+///
+/// Comparisons look at the value only, so branches in the differentiated code take
+/// the same path they would for plain floats.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Dual<T: Float = f32> {
+    /// Real part (the function value)
+    pub re: T,
+    /// Infinitesimal part (the derivative along the seeded direction)
+    pub eps: T,
+}
+
+impl<T: Float> Dual<T> {
+    /// Create a dual number from its value and derivative parts
+    pub fn new(re: T, eps: T) -> Self {
+        Self { re, eps }
+    }
+
+    /// A constant, whose derivative is zero
+    pub fn constant(re: T) -> Self {
+        Self { re, eps: T::ZERO }
+    }
+
+    /// The independent variable, whose derivative with respect to itself is one
+    pub fn variable(re: T) -> Self {
+        Self { re, eps: T::ONE }
+    }
+
+    /// Apply a function with value `value` and derivative `slope` at `self.re`
+    fn chain(self, value: T, slope: T) -> Self {
+        Self::new(value, self.eps * slope)
+    }
+}
+
+impl<T: Float> Display for Dual<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} + {}ε", self.re, self.eps)
+    }
+}
+
+impl<T: Float> PartialOrd for Dual<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.re.partial_cmp(&other.re)
+    }
+}
+
+impl<T: Float> Add for Dual<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.re + rhs.re, self.eps + rhs.eps)
+    }
+}
+
+impl<T: Float> Sub for Dual<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.re - rhs.re, self.eps - rhs.eps)
+    }
+}
+
+impl<T: Float> Mul for Dual<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(self.re * rhs.re, self.re * rhs.eps + self.eps * rhs.re)
+    }
+}
+
+impl<T: Float> Div for Dual<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Self::new(
+            self.re / rhs.re,
+            (self.eps * rhs.re - self.re * rhs.eps) / (rhs.re * rhs.re),
+        )
+    }
+}
+
+impl<T: Float> Neg for Dual<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.eps)
+    }
+}
+
+impl<T: Float> AddAssign for Dual<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Float> SubAssign for Dual<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Float> MulAssign for Dual<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Float> DivAssign for Dual<T> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T: Float> std::iter::Sum for Dual<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<T: Float> Float for Dual<T> {
+    const ZERO: Self = Self { re: T::ZERO, eps: T::ZERO };
+    const ONE: Self = Self { re: T::ONE, eps: T::ZERO };
+    const PI: Self = Self { re: T::PI, eps: T::ZERO };
+    const EPSILON: Self = Self { re: T::EPSILON, eps: T::ZERO };
+    const NEG_INFINITY: Self = Self { re: T::NEG_INFINITY, eps: T::ZERO };
+
+    fn from_f64(value: f64) -> Self {
+        Self::constant(T::from_f64(value))
+    }
+
+    fn to_f64(self) -> f64 {
+        self.re.to_f64()
+    }
+
+    fn abs(self) -> Self {
+        if self.re < T::ZERO { -self } else { self }
+    }
+
+    fn sqrt(self) -> Self {
+        let root = self.re.sqrt();
+        self.chain(root, T::ONE / (T::from_f64(2.0) * root))
+    }
+
+    fn sin(self) -> Self {
+        self.chain(self.re.sin(), self.re.cos())
+    }
+
+    fn cos(self) -> Self {
+        self.chain(self.re.cos(), -self.re.sin())
+    }
+
+    fn acos(self) -> Self {
+        self.chain(self.re.acos(), -T::ONE / (T::ONE - self.re * self.re).sqrt())
+    }
+
+    fn asin(self) -> Self {
+        self.chain(self.re.asin(), T::ONE / (T::ONE - self.re * self.re).sqrt())
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        let norm_sq = self.re * self.re + other.re * other.re;
+        Self::new(
+            self.re.atan2(other.re),
+            (other.re * self.eps - self.re * other.eps) / norm_sq,
+        )
+    }
+
+    fn powi(self, n: i32) -> Self {
+        if n == 0 {
+            return Self::ONE;
+        }
+        self.chain(self.re.powi(n), T::from_f64(n as f64) * self.re.powi(n - 1))
+    }
+
+    fn powf(self, n: Self) -> Self {
+        let value = self.re.powf(n.re);
+        let mut eps = n.re * self.re.powf(n.re - T::ONE) * self.eps;
+        // Only a varying exponent needs ln(x), which keeps negative bases finite
+        if n.eps != T::ZERO {
+            eps += value * self.re.ln() * n.eps;
+        }
+        Self::new(value, eps)
+    }
+
+    fn ln(self) -> Self {
+        self.chain(self.re.ln(), T::ONE / self.re)
+    }
+
+    fn exp(self) -> Self {
+        let value = self.re.exp();
+        self.chain(value, value)
+    }
+
+    fn max(self, other: Self) -> Self {
+        if other.re.is_nan() || self.re >= other.re { self } else { other }
+    }
+
+    fn min(self, other: Self) -> Self {
+        if other.re.is_nan() || self.re <= other.re { self } else { other }
+    }
+
+    fn clamp(self, min: Self, max: Self) -> Self {
+        self.max(min).min(max)
+    }
+
+    fn copysign(self, sign: Self) -> Self {
+        if self.re.copysign(sign.re) == self.re { self } else { -self }
+    }
+
+    fn signum(self) -> Self {
+        Self::constant(self.re.signum())
+    }
+
+    fn is_finite(self) -> bool {
+        self.re.is_finite() && self.eps.is_finite()
+    }
+
+    fn is_nan(self) -> bool {
+        self.re.is_nan() || self.eps.is_nan()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dual_derivatives() {
+        // f(x) = x² sin(x) / sqrt(x), f'(x) = 1.5 sqrt(x) sin(x) + x^1.5 cos(x)
+        let f = |x: Dual| x.powi(2) * x.sin() / x.sqrt();
+        let x = 1.3_f32;
+        let result = f(Dual::variable(x));
+
+        let expected_value = x.powf(1.5) * x.sin();
+        let expected_derivative = 1.5 * x.sqrt() * x.sin() + x.powf(1.5) * x.cos();
+        assert!((result.re - expected_value).abs() < 1e-6);
+        assert!((result.eps - expected_derivative).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_constants_have_no_derivative() {
+        let c = Dual::<f32>::from_f64(3.0);
+        let y = c * Dual::variable(2.0).cos() - c;
+        assert_eq!(Dual::constant(5.0_f32).powi(0), Dual::ONE);
+        assert!((y.eps + 3.0 * 2.0_f32.sin()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_inverse_functions_in_double_precision() {
+        // d/dx acos(x) = -1/√(1 - x²) and d/dx atan2(x, 2) = 2 / (x² + 4)
+        let x = Dual::variable(0.3_f64);
+        assert!((x.acos().eps + 1.0 / (1.0 - 0.09_f64).sqrt()).abs() < 1e-14);
+        assert!((x.atan2(Dual::constant(2.0)).eps - 2.0 / 4.09).abs() < 1e-14);
+        assert!((x.ln().exp().eps - 1.0).abs() < 1e-14);
+    }
+}
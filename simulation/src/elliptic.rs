@@ -0,0 +1,159 @@
+//! Elliptic integrals for closed-form geodesics.
+//!
+//! Everything is built on Carlson's symmetric integral R_F, evaluated by the duplication
+//! theorem, which converges for every argument the orbit equations produce. The Jacobi
+//! functions that invert F come from the arithmetic-geometric mean. Everything takes the
+//! parameter m = k² rather than the modulus k, and allows m < 0.
+
+use crate::float::Float;
+
+/// Carlson's symmetric elliptic integral R_F(x, y, z) = ½ ∫₀^∞ dt / √((t+x)(t+y)(t+z))
+///
+/// # Panics
+///
+/// Panics if any argument is negative or more than one is zero.
+pub fn carlson_rf<T: Float>(x: T, y: T, z: T) -> T {
+    assert!(x >= T::ZERO && y >= T::ZERO && z >= T::ZERO, "R_F arguments must be non-negative");
+    let zeros = [x, y, z].iter().filter(|&&v| v == T::ZERO).count();
+    assert!(zeros <= 1, "R_F diverges when two arguments vanish");
+
+    // The truncation error after stopping is about tolerance⁶ / 4
+    let tolerance = (T::from_f64(4.0) * T::EPSILON).powf(T::from_f64(1.0 / 6.0));
+    let quarter = T::from_f64(0.25);
+    let third = T::from_f64(1.0 / 3.0);
+
+    let (mut x, mut y, mut z) = (x, y, z);
+    loop {
+        let mean = (x + y + z) * third;
+        let dx = T::ONE - x / mean;
+        let dy = T::ONE - y / mean;
+        let dz = -(dx + dy);
+        if dx.abs().max(dy.abs()).max(dz.abs()) < tolerance {
+            let e2 = dx * dy - dz * dz;
+            let e3 = dx * dy * dz;
+            let series = T::ONE - e2 / T::from_f64(10.0) + e3 / T::from_f64(14.0)
+                + e2 * e2 / T::from_f64(24.0) - T::from_f64(3.0) * e2 * e3 / T::from_f64(44.0);
+            return series / mean.sqrt();
+        }
+
+        let (sx, sy, sz) = (x.sqrt(), y.sqrt(), z.sqrt());
+        let lambda = sx * sy + sy * sz + sz * sx;
+        x = (x + lambda) * quarter;
+        y = (y + lambda) * quarter;
+        z = (z + lambda) * quarter;
+    }
+}
+
+/// Complete elliptic integral of the first kind K(m) = F(π/2 | m)
+///
+/// # Panics
+///
+/// Panics unless m < 1.
+pub fn complete_first_kind<T: Float>(m: T) -> T {
+    assert!(m < T::ONE, "K(m) diverges for m >= 1");
+    carlson_rf(T::ZERO, T::ONE - m, T::ONE)
+}
+
+/// Incomplete elliptic integral of the first kind F(φ | m) = ∫₀^φ dθ / √(1 - m sin²θ)
+///
+/// # Panics
+///
+/// Panics unless m < 1, or m = 1 with |φ| < π/2.
+pub fn incomplete_first_kind<T: Float>(phi: T, m: T) -> T {
+    // Reduce to |φ| ≤ π/2 with F(φ + nπ | m) = F(φ | m) + 2n K(m)
+    let half_periods = (phi / T::PI + T::from_f64(0.5)).to_f64().floor();
+    if half_periods != 0.0 {
+        let n = T::from_f64(half_periods);
+        return T::from_f64(2.0) * n * complete_first_kind(m) + incomplete_first_kind(phi - n * T::PI, m);
+    }
+
+    let (sin_phi, cos_phi) = (phi.sin(), phi.cos());
+    sin_phi * carlson_rf(cos_phi * cos_phi, T::ONE - m * sin_phi * sin_phi, T::ONE)
+}
+
+/// Jacobi elliptic functions (sn, cn, dn) of u with parameter m
+///
+/// For 0 ≤ m < 1 the amplitude comes from the descending arithmetic-geometric mean.
+/// Negative parameters go through the imaginary-modulus transformation, with
+/// μ = -m / (1 - m) and v = u √(1 - m): sn(u | m) = sd(v | μ) / √(1 - m),
+/// cn(u | m) = cd(v | μ) and dn(u | m) = nd(v | μ).
+///
+/// # Panics
+///
+/// Panics unless m < 1.
+pub fn jacobi_elliptic<T: Float>(u: T, m: T) -> (T, T, T) {
+    assert!(m < T::ONE, "Jacobi functions degenerate for m >= 1");
+    if m < T::ZERO {
+        let root = (T::ONE - m).sqrt();
+        let (sn, cn, dn) = jacobi_elliptic(u * root, -m / (T::ONE - m));
+        return (sn / (dn * root), cn / dn, T::ONE / dn);
+    }
+    if m == T::ZERO {
+        return (u.sin(), u.cos(), T::ONE);
+    }
+
+    // Descend until the AGM has converged, keeping c_n / a_n for the way back up
+    let mut a = T::ONE;
+    let mut b = (T::ONE - m).sqrt();
+    let mut ratios = Vec::new();
+    let mut scale = T::ONE;
+    loop {
+        let c = (a - b) * T::from_f64(0.5);
+        if c.abs() <= T::EPSILON * a {
+            break;
+        }
+        let next_a = (a + b) * T::from_f64(0.5);
+        b = (a * b).sqrt();
+        a = next_a;
+        ratios.push(c / a);
+        scale *= T::from_f64(2.0);
+    }
+
+    let mut phi = scale * a * u;
+    for &ratio in ratios.iter().rev() {
+        phi = (phi + (ratio * phi.sin()).asin()) * T::from_f64(0.5);
+    }
+
+    let sn = phi.sin();
+    (sn, phi.cos(), (T::ONE - m * sn * sn).sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_complete_integral_values() {
+        // K(0) = π/2 and K(½) = Γ(¼)² / (4√π)
+        assert!((complete_first_kind(0.0_f64) - std::f64::consts::FRAC_PI_2).abs() < 1e-15);
+        assert!((complete_first_kind(0.5_f64) - 1.854_074_677_301_372).abs() < 1e-14);
+        assert!((complete_first_kind(0.5_f32) - 1.854_074_7).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_incomplete_integral_limits() {
+        // F(φ | 0) = φ, F(π/2 | m) = K(m) and F(φ | 1) = artanh(sin φ)
+        assert!((incomplete_first_kind(0.7_f64, 0.0) - 0.7).abs() < 1e-15);
+        let m = 0.9_f64;
+        assert!((incomplete_first_kind(std::f64::consts::FRAC_PI_2, m) - complete_first_kind(m)).abs() < 1e-14);
+        let phi = 1.2_f64;
+        assert!((incomplete_first_kind(phi, 1.0) - phi.sin().atanh()).abs() < 1e-13);
+
+        // Beyond π/2 the integral keeps growing by 2K per half period
+        let k = complete_first_kind(m);
+        assert!((incomplete_first_kind(2.5, m) - (2.0 * k - incomplete_first_kind(std::f64::consts::PI - 2.5, m))).abs() < 1e-13);
+    }
+
+    #[test]
+    fn test_jacobi_functions_invert_first_kind() {
+        // sn(F(φ | m) | m) = sin φ, cn = cos φ and dn = √(1 - m sin²φ), for either sign of m
+        for m in [0.0_f64, 0.3, 0.97, -0.8, -6.0] {
+            for phi in [0.2_f64, 1.1, 2.9, -4.0] {
+                let (sn, cn, dn) = jacobi_elliptic(incomplete_first_kind(phi, m), m);
+                assert!((sn - phi.sin()).abs() < 1e-12, "sn at m = {}, φ = {}", m, phi);
+                assert!((cn - phi.cos()).abs() < 1e-12, "cn at m = {}, φ = {}", m, phi);
+                assert!((dn - (1.0 - m * phi.sin().powi(2)).sqrt()).abs() < 1e-12, "dn at m = {}, φ = {}", m, phi);
+            }
+        }
+    }
+}
@@ -0,0 +1,199 @@
+//! Floating-point abstraction so the simulation can run in single or double precision.
+//!
+//! The GPU-facing path stays in f32, while CPU reference tracing can use f64 where
+//! f32 round-off (about 5e-6 at r ≈ 40M) would swamp the integrator tolerances.
+
+use std::fmt::{Debug, Display};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Floating-point number type the simulation types are generic over
+pub trait Float:
+    Copy
+    + Debug
+    + Display
+    + Default
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + std::iter::Sum
+{
+    /// Zero
+    const ZERO: Self;
+    /// One
+    const ONE: Self;
+    /// Archimedes' constant π
+    const PI: Self;
+    /// Machine epsilon
+    const EPSILON: Self;
+    /// Negative infinity
+    const NEG_INFINITY: Self;
+
+    /// Convert a constant into this precision
+    fn from_f64(value: f64) -> Self;
+    /// Widen to f64
+    fn to_f64(self) -> f64;
+
+    /// Absolute value
+    fn abs(self) -> Self;
+    /// Square root
+    fn sqrt(self) -> Self;
+    /// Sine
+    fn sin(self) -> Self;
+    /// Cosine
+    fn cos(self) -> Self;
+    /// Arccosine
+    fn acos(self) -> Self;
+    /// Arcsine
+    fn asin(self) -> Self;
+    /// Four-quadrant arctangent of self / other
+    fn atan2(self, other: Self) -> Self;
+    /// Integer power
+    fn powi(self, n: i32) -> Self;
+    /// Floating-point power
+    fn powf(self, n: Self) -> Self;
+    /// Natural logarithm
+    fn ln(self) -> Self;
+    /// Exponential e^self
+    fn exp(self) -> Self;
+    /// Larger of two numbers, ignoring NaN
+    fn max(self, other: Self) -> Self;
+    /// Smaller of two numbers, ignoring NaN
+    fn min(self, other: Self) -> Self;
+    /// Restrict to the interval [min, max]
+    fn clamp(self, min: Self, max: Self) -> Self;
+    /// Magnitude of self with the sign of `sign`
+    fn copysign(self, sign: Self) -> Self;
+    /// Sign of the number (±1, or NaN)
+    fn signum(self) -> Self;
+    /// True if neither infinite nor NaN
+    fn is_finite(self) -> bool;
+    /// True if NaN
+    fn is_nan(self) -> bool;
+
+    /// Convert into another number type, e.g. to lift a parameter into dual numbers or
+    /// change precision; any derivative part is dropped
+    fn cast<U: Float>(self) -> U {
+        U::from_f64(self.to_f64())
+    }
+}
+
+macro_rules! impl_float {
+    ($t:ident) => {
+        impl Float for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const PI: Self = std::$t::consts::PI;
+            const EPSILON: Self = $t::EPSILON;
+            const NEG_INFINITY: Self = $t::NEG_INFINITY;
+
+            fn from_f64(value: f64) -> Self {
+                value as $t
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn abs(self) -> Self {
+                $t::abs(self)
+            }
+
+            fn sqrt(self) -> Self {
+                $t::sqrt(self)
+            }
+
+            fn sin(self) -> Self {
+                $t::sin(self)
+            }
+
+            fn cos(self) -> Self {
+                $t::cos(self)
+            }
+
+            fn acos(self) -> Self {
+                $t::acos(self)
+            }
+
+            fn asin(self) -> Self {
+                $t::asin(self)
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                $t::atan2(self, other)
+            }
+
+            fn powi(self, n: i32) -> Self {
+                $t::powi(self, n)
+            }
+
+            fn powf(self, n: Self) -> Self {
+                $t::powf(self, n)
+            }
+
+            fn ln(self) -> Self {
+                $t::ln(self)
+            }
+
+            fn exp(self) -> Self {
+                $t::exp(self)
+            }
+
+            fn max(self, other: Self) -> Self {
+                $t::max(self, other)
+            }
+
+            fn min(self, other: Self) -> Self {
+                $t::min(self, other)
+            }
+
+            fn clamp(self, min: Self, max: Self) -> Self {
+                $t::clamp(self, min, max)
+            }
+
+            fn copysign(self, sign: Self) -> Self {
+                $t::copysign(self, sign)
+            }
+
+            fn signum(self) -> Self {
+                $t::signum(self)
+            }
+
+            fn is_finite(self) -> bool {
+                $t::is_finite(self)
+            }
+
+            fn is_nan(self) -> bool {
+                $t::is_nan(self)
+            }
+        }
+    };
+}
+
+impl_float!(f32);
+impl_float!(f64);
+
+/// Bisect for the point where `above` switches from false (at `low`) to true (at `high`)
+///
+/// `low` may lie on either side of `high`. Halving continues until the midpoint can no
+/// longer be told apart from an endpoint, so the result is as precise as `T` allows.
+pub(crate) fn bisect<T: Float>(low: T, high: T, above: impl Fn(T) -> bool) -> T {
+    let (mut low, mut high) = (low, high);
+    loop {
+        let mid = (low + high) * T::from_f64(0.5);
+        if mid <= low.min(high) || mid >= low.max(high) {
+            return mid;
+        }
+        if above(mid) {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+}
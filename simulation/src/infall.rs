@@ -0,0 +1,656 @@
+//! Free fall into a Kerr black hole, through the horizon.
+//!
+//! Boyer-Lindquist coordinates break down at the horizon, so a falling body is followed in
+//! ingoing Kerr-Schild coordinates instead: t and φ are shifted along infalling light,
+//! dt_KS = dt_BL + 2Mr/Δ dr and dφ_KS = dφ_BL + a/Δ dr, and the metric stays regular all the
+//! way down to the ring singularity. Its inverse is
+//! g^tt = -(1 + 2Mr/Σ), g^tr = 2Mr/Σ, g^rr = Δ/Σ, g^rφ = a/Σ, g^θθ = 1/Σ, g^φφ = 1/(Σ sin²θ),
+//! and with unit rest mass the affine parameter of H = ½ g^μν u_μ u_ν = -½ is proper time.
+
+use crate::float::Float;
+use crate::observer::Observer;
+use crate::outcome::{RayEnd, RayOutcome};
+use crate::tidal::{self, TidalTensor};
+use crate::{kerr_schild, AdaptiveRK45, Geodesic, Integrator, KerrBlackHole};
+
+/// Radius √Σ, in units of M, within which the fall counts as having hit the singularity
+const SINGULARITY_RADIUS: f64 = 1e-2;
+
+/// Distance from the inner horizon, in units of M, at which a fall the coordinates cannot
+/// follow through it is stopped
+const INNER_HORIZON_MARGIN: f64 = 1e-2;
+
+/// Where a fall inside the hole comes to an end
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FallEnd {
+    /// Within `SINGULARITY_RADIUS` of the ring singularity
+    Singularity,
+    /// Through the disk r = 0 the ring bounds, into the region of negative r
+    ThroughRing,
+    /// At the inner horizon, on a crossing the coordinates cannot follow
+    InnerHorizon,
+}
+
+/// Body falling freely along a timelike Kerr geodesic, followed across the horizon
+///
+/// The fall ends with `RayOutcome::Captured` at the ring singularity, on passing through
+/// the disk r = 0 the ring bounds into the region of negative r, or on reaching the inner
+/// horizon on a crossing the coordinates cannot follow, such as on the way back out after
+/// turning around at that disk; `fall_end` tells these apart.
+#[derive(Debug, Clone)]
+pub struct FreeFall<T: Float = f32, I: Integrator<T> = AdaptiveRK45<T>> {
+    /// Current state in Kerr-Schild coordinates (t, r, θ, φ), with covariant four-velocity u_μ
+    pub geodesic: Geodesic<T>,
+    /// Black hole parameters
+    pub black_hole: KerrBlackHole<T>,
+    /// Stepping method, Fehlberg RK45 unless replaced with `with_integrator`
+    pub integrator: I,
+    /// Current step size in proper time
+    pub step_size: T,
+    /// Maximum number of integration steps
+    pub max_steps: u32,
+    /// Current step count
+    pub step_count: u32,
+    /// Proper time τ elapsed along the fall
+    pub proper_time: T,
+    /// Radius beyond which the body counts as escaped
+    pub escape_radius: T,
+}
+
+impl<T: Float> FreeFall<T> {
+    /// Drop a body at (r, θ, φ) that fell from rest at infinity with zero angular momentum
+    /// and Carter constant, as `Observer::FreeFall` does
+    ///
+    /// It keeps its θ all the way in, with u_t = -1 and
+    /// u_r = -√(2Mr) / (√(2Mr) + √(r² + a²)), which is finite at and inside the horizon.
+    ///
+    /// # Panics
+    ///
+    /// Panics if r is not positive.
+    pub fn from_rest_at_infinity(black_hole: KerrBlackHole<T>, position: [T; 3]) -> Self {
+        let [r, theta, phi] = position;
+        assert!(r > T::ZERO, "the fall must start at a positive radius");
+        Self::new(black_hole, Geodesic::new([T::ZERO, r, theta, phi], rain_velocity(&black_hole, r)))
+    }
+
+    /// Launch a body from (r, θ, φ) outside the horizon at `velocity`, the components of its
+    /// velocity along the radial, polar and azimuthal axes of the zero angular momentum
+    /// observer there, in units of c
+    ///
+    /// Returns `None` at or inside the outer horizon and for speeds of c or more.
+    pub fn launch(black_hole: KerrBlackHole<T>, position: [T; 3], velocity: [T; 3]) -> Option<Self> {
+        let [r, theta, phi] = position;
+        let speed_sq = velocity.iter().map(|&v| v * v).sum::<T>();
+        if speed_sq >= T::ONE {
+            return None;
+        }
+        let tetrad = Observer::ZeroAngularMomentum.tetrad(&black_hole, r, theta)?;
+        let gamma = T::ONE / (T::ONE - speed_sq).sqrt();
+        let mut boyer_lindquist = tetrad[0].map(|component| gamma * component);
+        for (axis, &v) in tetrad[1..].iter().zip(&velocity) {
+            for (component, &value) in boyer_lindquist.iter_mut().zip(axis) {
+                *component += gamma * v * value;
+            }
+        }
+
+        // Shift to Kerr-Schild components and lower the index there
+        let (mass, a) = (black_hole.mass, black_hole.spin);
+        let delta = kerr_schild::delta(r, mass, a);
+        let [u_t, u_r, u_theta, u_phi] = boyer_lindquist;
+        let contravariant = [u_t + T::from_f64(2.0) * mass * r / delta * u_r, u_r, u_theta, u_phi + a / delta * u_r];
+        let covariant = lower(&black_hole, r, theta, contravariant);
+        Some(Self::new(black_hole, Geodesic::new([T::ZERO, r, theta, phi], covariant)))
+    }
+
+    fn new(black_hole: KerrBlackHole<T>, geodesic: Geodesic<T>) -> Self {
+        Self {
+            geodesic,
+            black_hole,
+            integrator: AdaptiveRK45::default(),
+            step_size: T::from_f64(0.1) * black_hole.mass,
+            max_steps: 100_000,
+            step_count: 0,
+            proper_time: T::ZERO,
+            escape_radius: T::from_f64(1000.0) * black_hole.mass,
+        }
+    }
+}
+
+impl<T: Float, I: Integrator<T>> FreeFall<T, I> {
+    /// Swap in a different stepping method, such as `DormandPrince54`
+    pub fn with_integrator<J: Integrator<T>>(self, integrator: J) -> FreeFall<T, J> {
+        FreeFall {
+            geodesic: self.geodesic,
+            black_hole: self.black_hole,
+            integrator,
+            step_size: self.step_size,
+            max_steps: self.max_steps,
+            step_count: self.step_count,
+            proper_time: self.proper_time,
+            escape_radius: self.escape_radius,
+        }
+    }
+
+    /// Take one integration step, reporting `RayOutcome::Continuing` while still falling
+    ///
+    /// Each step ends by resetting u_r to the nearest root of u·u = -1. Drift off that
+    /// shell grows exponentially near the inner horizon and would otherwise carry the fall
+    /// over to the other root, which diverges there.
+    pub fn step(&mut self) -> RayOutcome<T> {
+        let current = self.outcome();
+        if !current.is_continuing() {
+            return current;
+        }
+
+        // Close at most half the distance √Σ to the ring in one step, so as not to jump it
+        let black_hole = self.black_hole;
+        let [_, r, theta, _] = self.geodesic.position;
+        let velocity = derivatives(&black_hole, self.geodesic).position;
+        let a_sq = black_hole.spin * black_hole.spin;
+        let approach = (r * velocity[1] - a_sq * theta.sin() * theta.cos() * velocity[2]).abs();
+        let sigma = kerr_schild::sigma(r, theta, black_hole.spin);
+        if approach > T::ZERO {
+            self.step_size = self.step_size.min(T::from_f64(0.5) * sigma / approach);
+        }
+
+        let (new_state, actual_step, next_step) =
+            self.integrator.step(self.geodesic, self.step_size, |state| derivatives(&black_hole, state));
+        if !new_state.is_finite() || !next_step.is_finite() {
+            return RayOutcome::NumericalFailure(self.end());
+        }
+
+        self.geodesic = project_onto_shell(&black_hole, new_state);
+        self.step_size = next_step;
+        self.step_count += 1;
+        self.proper_time += actual_step;
+        self.outcome()
+    }
+
+    /// Step until the fall ends, escapes, runs out of steps or fails
+    pub fn trace(&mut self) -> RayOutcome<T> {
+        loop {
+            let outcome = self.step();
+            if !outcome.is_continuing() {
+                return outcome;
+            }
+        }
+    }
+
+    /// Fall on for `duration` of proper time, shortening the last step to land on it
+    pub fn advance(&mut self, duration: T) -> RayOutcome<T> {
+        let target = self.proper_time + duration;
+        loop {
+            let remaining = target - self.proper_time;
+            if remaining <= T::ZERO {
+                return self.outcome();
+            }
+            self.step_size = self.step_size.min(remaining);
+            let outcome = self.step();
+            if !outcome.is_continuing() {
+                return outcome;
+            }
+        }
+    }
+
+    /// Proper time left until the fall ends inside the hole, and how it ends, or `None` if
+    /// it escapes or cannot be followed to its end
+    ///
+    /// Follows a copy of the fall to its end, so it costs as much as the rest of the fall.
+    pub fn time_to_end(&self) -> Option<(T, FallEnd)>
+    where
+        I: Clone,
+    {
+        let mut copy = self.clone();
+        match copy.trace() {
+            RayOutcome::Captured(end) => Some((end.affine_parameter - self.proper_time, copy.fall_end()?)),
+            _ => None,
+        }
+    }
+
+    /// How the fall has ended, or `None` while it goes on
+    pub fn fall_end(&self) -> Option<FallEnd> {
+        if self.has_reached_singularity() {
+            Some(FallEnd::Singularity)
+        } else if self.geodesic.radius() <= T::ZERO {
+            Some(FallEnd::ThroughRing)
+        } else if self.is_leaving_coordinates() {
+            Some(FallEnd::InnerHorizon)
+        } else {
+            None
+        }
+    }
+
+    /// Check if the body has come within `SINGULARITY_RADIUS` of the ring singularity
+    ///
+    /// A step that carries it through r = 0 crossed the disk the ring bounds at a distance
+    /// √Σ = a|cos θ| from it, which counts the same.
+    pub fn has_reached_singularity(&self) -> bool {
+        let [_, r, theta, _] = self.geodesic.position;
+        let a = self.black_hole.spin;
+        let distance = if r > T::ZERO { kerr_schild::sigma(r, theta, a).sqrt() } else { (a * theta.cos()).abs() };
+        distance <= T::from_f64(SINGULARITY_RADIUS) * self.black_hole.mass
+    }
+
+    /// Check if the body is inside the outer horizon
+    pub fn is_inside_horizon(&self) -> bool {
+        kerr_schild::is_inside_horizon(self.geodesic.radius(), &self.black_hole)
+    }
+
+    /// Four-velocity u^μ in Kerr-Schild coordinates
+    pub fn four_velocity(&self) -> [T; 4] {
+        derivatives(&self.black_hole, self.geodesic).position
+    }
+
+    /// Lorentz transformation Λ^a_b from the body's frame to that of the observer falling
+    /// from rest at infinity at the same place, as in `Observer::lorentz_transform`, or
+    /// `None` once the fall has reached r = 0
+    ///
+    /// Unlike the zero angular momentum observer, that observer exists inside the horizon
+    /// too, so the transformation stays regular all the way down. See `kerr_schild_frame`
+    /// for how the axes are built.
+    pub fn lorentz_transform(&self) -> Option<[[T; 4]; 4]> {
+        let [_, r, theta, _] = self.geodesic.position;
+        if r <= T::ZERO {
+            return None;
+        }
+        let rain = Geodesic::new(self.geodesic.position, rain_velocity(&self.black_hole, r));
+        let own = kerr_schild_frame(&self.black_hole, r, theta, self.four_velocity());
+        let other = kerr_schild_frame(&self.black_hole, r, theta, derivatives(&self.black_hole, rain).position);
+        let transform = relative_transform(&self.black_hole, r, theta, own, other);
+        transform.iter().flatten().all(|entry| entry.is_finite()).then_some(transform)
+    }
+
+    /// Tidal tensor in the body's own frame, along the axes of `lorentz_transform`, or
+    /// `None` once the fall has reached r = 0
+    ///
+    /// The rain observer's four-velocity with its motion along φ̂ taken out lies in the plane
+    /// of the principal null directions, where the curvature takes its Carter-frame form
+    /// from Ψ alone; the body's frame is a Lorentz transformation away from that one, and
+    /// both stay regular through the horizons.
+    pub fn tidal_tensor(&self) -> Option<TidalTensor<T>> {
+        let [_, r, theta, _] = self.geodesic.position;
+        if r <= T::ZERO {
+            return None;
+        }
+        let rain = Geodesic::new(self.geodesic.position, rain_velocity(&self.black_hole, r));
+        let principal = kerr_schild_frame(&self.black_hole, r, theta, principal_velocity(&self.black_hole, r, theta, rain));
+        let own = kerr_schild_frame(&self.black_hole, r, theta, self.four_velocity());
+        let transform = relative_transform(&self.black_hole, r, theta, own, principal);
+
+        // Column b of the transformation is the body's axis b in the principal frame
+        let frame = [0, 1, 2, 3].map(|b| transform.map(|row| row[b]));
+        let tensor = tidal::electric_part(&tidal::carter_riemann(&self.black_hole, r, theta), frame);
+        tensor.components.iter().flatten().all(|entry| entry.is_finite()).then_some(tensor)
+    }
+
+    /// Classify the current state without stepping
+    pub fn outcome(&self) -> RayOutcome<T> {
+        let captured = self.fall_end().is_some();
+        let escaped = self.geodesic.radius() > self.escape_radius;
+        RayOutcome::classify(self.end(), captured, escaped, self.max_steps)
+    }
+
+    /// Check if the body is at the inner horizon on a crossing these coordinates do not cover
+    ///
+    /// Ingoing Kerr-Schild coordinates only continue through the inner horizon for a body
+    /// moving inward with P = -u_t(r₋² + a²) - a u_φ > 0 there. Any other crossing leads
+    /// into a different region, and u_r diverges on the way.
+    fn is_leaving_coordinates(&self) -> bool {
+        let (mass, a) = (self.black_hole.mass, self.black_hole.spin);
+        let inner = mass - (mass * mass - a * a).sqrt();
+        let r = self.geodesic.radius();
+        if inner <= T::ZERO || (r - inner).abs() > T::from_f64(INNER_HORIZON_MARGIN) * mass {
+            return false;
+        }
+        let [u_t, _, _, u_phi] = self.geodesic.momentum;
+        let p = -u_t * (inner * inner + a * a) - a * u_phi;
+        p <= T::ZERO || self.four_velocity()[1] > T::ZERO
+    }
+
+    /// Snapshot of the current state, with the proper time as the affine parameter
+    fn end(&self) -> RayEnd<T> {
+        RayEnd {
+            geodesic: self.geodesic,
+            step_count: self.step_count,
+            affine_parameter: self.proper_time,
+        }
+    }
+}
+
+/// Covariant Kerr-Schild four-velocity (-1, u_r, 0, 0) of the body that fell from rest at
+/// infinity to radius r, with u_r = -√(2Mr) / (√(2Mr) + √(r² + a²))
+fn rain_velocity<T: Float>(black_hole: &KerrBlackHole<T>, r: T) -> [T; 4] {
+    let infall = (T::from_f64(2.0) * black_hole.mass * r).sqrt();
+    let u_r = -infall / (infall + (r * r + black_hole.spin * black_hole.spin).sqrt());
+    [-T::ONE, u_r, T::ZERO, T::ZERO]
+}
+
+/// Unit Kerr-Schild four-velocity in the plane of the principal null directions, taken
+/// from `rain`, the covariant state of the rain observer, by removing its part along
+/// (a sin²θ, 0, 0, 1)
+///
+/// That axis is orthogonal to the plane and has norm Σ sin²θ, and the rain observer's
+/// u_φ = 0 gives it the component -a sin²θ along it, so the part removed is a/Σ times the
+/// axis, finite on the spin axis too.
+fn principal_velocity<T: Float>(black_hole: &KerrBlackHole<T>, r: T, theta: T, rain: Geodesic<T>) -> [T; 4] {
+    let a = black_hole.spin;
+    let sigma = kerr_schild::sigma(r, theta, a);
+    let shift = a / sigma;
+    let mut velocity = derivatives(black_hole, rain).position;
+    velocity[0] += shift * a * theta.sin().powi(2);
+    velocity[3] += shift;
+    let norm = (T::ONE + a * a * theta.sin().powi(2) / sigma).sqrt();
+    velocity.map(|component| component / norm)
+}
+
+/// Lorentz transformation Λ^a_b taking components in the tetrad `own` to those in `other`,
+/// both at (r, θ)
+fn relative_transform<T: Float>(black_hole: &KerrBlackHole<T>, r: T, theta: T, own: [[T; 4]; 4], other: [[T; 4]; 4]) -> [[T; 4]; 4] {
+    let mut transform = [[T::ZERO; 4]; 4];
+    for (a, row) in transform.iter_mut().enumerate() {
+        let sign = if a == 0 { -T::ONE } else { T::ONE };
+        let lowered = lower(black_hole, r, theta, other[a]);
+        for (b, entry) in row.iter_mut().enumerate() {
+            *entry = sign * dot(lowered, own[b]);
+        }
+    }
+    transform
+}
+
+/// Orthonormal tetrad [u, e_r̂, e_θ̂, e_φ̂] with Kerr-Schild components of a body moving at
+/// four-velocity u^μ, regular at and inside the horizon
+///
+/// The spatial axes are (2Mr, Δ, 0, a), ∂_θ and (a sin²θ, 0, 0, 1) made orthogonal to u in
+/// that order. The first is Δ ∂_r of Boyer-Lindquist coordinates, and the others are
+/// multiples of Carter's e₂ and e₃, so outside the horizon these are the axes `Observer`
+/// builds; at the horizon the first turns null but stays finite.
+fn kerr_schild_frame<T: Float>(black_hole: &KerrBlackHole<T>, r: T, theta: T, velocity: [T; 4]) -> [[T; 4]; 4] {
+    let (mass, a, zero) = (black_hole.mass, black_hole.spin, T::ZERO);
+    let delta = kerr_schild::delta(r, mass, a);
+    let axes = [
+        [T::from_f64(2.0) * mass * r, delta, zero, a],
+        [zero, zero, T::ONE, zero],
+        [a * theta.sin().powi(2), zero, zero, T::ONE],
+    ];
+
+    let mut frame = [velocity, [zero; 4], [zero; 4], [zero; 4]];
+    for (index, axis) in axes.into_iter().enumerate() {
+        let mut vector = axis;
+        for (previous_index, previous) in frame[..=index].iter().enumerate() {
+            let sign = if previous_index == 0 { T::ONE } else { -T::ONE };
+            let projection = sign * dot(lower(black_hole, r, theta, axis), *previous);
+            for (component, &value) in vector.iter_mut().zip(previous) {
+                *component += projection * value;
+            }
+        }
+        let norm = dot(lower(black_hole, r, theta, vector), vector).sqrt();
+        frame[index + 1] = vector.map(|component| component / norm);
+    }
+    frame
+}
+
+/// Contraction x_μ y^μ of a covector with a vector
+fn dot<T: Float>(covector: [T; 4], vector: [T; 4]) -> T {
+    covector.iter().zip(vector).fold(T::ZERO, |sum, (&x, y)| sum + x * y)
+}
+
+/// Lower the index of a Kerr-Schild vector with the metric g_μν
+fn lower<T: Float>(black_hole: &KerrBlackHole<T>, r: T, theta: T, vector: [T; 4]) -> [T; 4] {
+    let (two_m, a) = (T::from_f64(2.0) * black_hole.mass, black_hole.spin);
+    let sigma = kerr_schild::sigma(r, theta, a);
+    let sin_sq = theta.sin().powi(2);
+    let f = two_m * r / sigma;
+    let [v_t, v_r, v_theta, v_phi] = vector;
+    [
+        -(T::ONE - f) * v_t + f * v_r - f * a * sin_sq * v_phi,
+        f * v_t + (T::ONE + f) * v_r - a * sin_sq * (T::ONE + f) * v_phi,
+        sigma * v_theta,
+        sin_sq * (-f * a * v_t - a * (T::ONE + f) * v_r + (r * r + a * a + f * a * a * sin_sq) * v_phi),
+    ]
+}
+
+/// Replace u_r with the root of u·u = -1 nearest to it, keeping the other components
+///
+/// The normalization is Δu_r² + 2b u_r + c = 0 with b = 2Mr u_t + a u_φ and
+/// c = -2Mr u_t² + u_θ² + u_φ²/sin²θ - Σ(u_t² - 1). The state is left alone where there is
+/// no real root, as happens through round-off at a turning point.
+fn project_onto_shell<T: Float>(black_hole: &KerrBlackHole<T>, state: Geodesic<T>) -> Geodesic<T> {
+    let [_, r, theta, _] = state.position;
+    let [u_t, u_r, u_theta, u_phi] = state.momentum;
+    let (mass, a) = (black_hole.mass, black_hole.spin);
+    let two_mr = T::from_f64(2.0) * mass * r;
+    let sigma = kerr_schild::sigma(r, theta, a);
+    let delta = kerr_schild::delta(r, mass, a);
+    let sin_sq = theta.sin().powi(2).max(T::from_f64(1e-12));
+
+    let b = two_mr * u_t + a * u_phi;
+    let c = -two_mr * u_t * u_t + u_theta * u_theta + u_phi * u_phi / sin_sq - sigma * (u_t * u_t - T::ONE);
+    let discriminant = b * b - delta * c;
+    if discriminant < T::ZERO {
+        return state;
+    }
+
+    // Numerically stable roots, one of which is infinite on a horizon
+    let q = -(b + b.signum() * discriminant.sqrt());
+    let nearest = [q / delta, c / q]
+        .into_iter()
+        .filter(|root| root.is_finite())
+        .fold(None, |best: Option<T>, root| match best {
+            Some(best) if (best - u_r).abs() <= (root - u_r).abs() => Some(best),
+            _ => Some(root),
+        });
+    match nearest {
+        Some(root) => Geodesic::new(state.position, [u_t, root, u_theta, u_phi]),
+        None => state,
+    }
+}
+
+/// Hamilton's equations ẋ^μ = ∂H/∂u_μ and u̇_μ = -∂H/∂x^μ in Kerr-Schild coordinates
+///
+/// Writing 2H = -u_t² + N/Σ with
+/// N = -2Mr u_t² + 4Mr u_t u_r + Δu_r² + 2a u_r u_φ + u_θ² + u_φ²/sin²θ
+/// leaves only N and Σ depending on r and θ.
+fn derivatives<T: Float>(black_hole: &KerrBlackHole<T>, state: Geodesic<T>) -> Geodesic<T> {
+    let [_, r, theta, _] = state.position;
+    let [u_t, u_r, u_theta, u_phi] = state.momentum;
+    let (mass, a) = (black_hole.mass, black_hole.spin);
+    let two = T::from_f64(2.0);
+    let two_m = two * mass;
+
+    let sigma = kerr_schild::sigma(r, theta, a);
+    let delta = kerr_schild::delta(r, mass, a);
+    let cos_theta = theta.cos();
+    // Keep sin θ away from zero so falls along the axis stay finite
+    let min_sin = T::from_f64(1e-6);
+    let sin_theta = {
+        let s = theta.sin();
+        if s.abs() < min_sin { min_sin.copysign(s) } else { s }
+    };
+    let sin_sq = sin_theta * sin_theta;
+
+    let n = -two_m * r * u_t * u_t + two * two_m * r * u_t * u_r + delta * u_r * u_r + two * a * u_r * u_phi
+        + u_theta * u_theta + u_phi * u_phi / sin_sq;
+    let n_r = -two_m * u_t * u_t + two * two_m * u_t * u_r + (two * r - two_m) * u_r * u_r;
+    let n_theta = -two * u_phi * u_phi * cos_theta / (sin_sq * sin_theta);
+    let sigma_theta = -two * a * a * sin_theta * cos_theta;
+    let sigma_sq = sigma * sigma;
+
+    let velocity = [
+        -(T::ONE + two_m * r / sigma) * u_t + two_m * r / sigma * u_r,
+        (two_m * r * u_t + delta * u_r + a * u_phi) / sigma,
+        u_theta / sigma,
+        (a * u_r + u_phi / sin_sq) / sigma,
+    ];
+    let force = [
+        T::ZERO,
+        -(n_r * sigma - n * two * r) / (two * sigma_sq),
+        -(n_theta * sigma - n * sigma_theta) / (two * sigma_sq),
+        T::ZERO,
+    ];
+    Geodesic::new(velocity, force)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::observer::{comoving_frame, frame_transform};
+
+    /// Norm g^μν u_μ u_ν of the current four-velocity
+    fn norm(fall: &FreeFall<f64>) -> f64 {
+        fall.four_velocity().iter().zip(fall.geodesic.momentum).map(|(x, y)| x * y).sum()
+    }
+
+    #[test]
+    fn test_radial_drop_reaches_the_singularity() {
+        // From rest at infinity in Schwarzschild, r = (r₀^{3/2} - 3√(2M) τ / 2)^{2/3}
+        // right through the horizon, so the singularity is (2/3) r₀^{3/2} / √(2M) away
+        let bh = KerrBlackHole::<f64>::schwarzschild(1.0);
+        let r0: f64 = 10.0;
+        let mut fall = FreeFall::from_rest_at_infinity(bh, [r0, 1.0, 0.0]);
+        let expected = 2.0 / 3.0 * r0.powf(1.5) / 2.0_f64.sqrt();
+        let (remaining, end) = fall.time_to_end().unwrap();
+        assert_eq!(end, FallEnd::Singularity);
+        assert!((remaining - expected).abs() < 2e-3, "{} vs {}", remaining, expected);
+
+        let tau = 0.95 * expected;
+        assert!(fall.advance(tau).is_continuing());
+        assert!(fall.is_inside_horizon());
+        assert!((fall.proper_time - tau).abs() < 1e-12);
+        let radius = (r0.powf(1.5) - 1.5 * 2.0_f64.sqrt() * tau).powf(2.0 / 3.0);
+        assert!((fall.geodesic.radius() - radius).abs() < 1e-4, "{} vs {}", fall.geodesic.radius(), radius);
+        assert!((norm(&fall) + 1.0).abs() < 1e-4);
+        assert!((fall.time_to_end().unwrap().0 - (expected - tau)).abs() < 2e-3);
+
+        // Any radial fall feels M/r³ diag(-2, 1, 1) in Schwarzschild, inside the horizon too
+        let tides = fall.tidal_tensor().unwrap().components;
+        let scale = 1.0 / fall.geodesic.radius().powi(3);
+        for (i, row) in tides.iter().enumerate() {
+            for (j, entry) in row.iter().enumerate() {
+                let expected = if i != j { 0.0 } else if i == 0 { -2.0 * scale } else { scale };
+                assert!((entry - expected).abs() < 1e-9 * scale, "{:?}", tides);
+            }
+        }
+
+        // Inside the horizon the body still is the observer falling from rest at infinity
+        let identity = fall.lorentz_transform().unwrap();
+        for (a, row) in identity.iter().enumerate() {
+            for (b, entry) in row.iter().enumerate() {
+                assert!((entry - if a == b { 1.0 } else { 0.0 }).abs() < 1e-3, "{:?}", identity);
+            }
+        }
+
+        // On the equator of a spinning hole the fall ends on the ring; off it, it turns
+        // around on the disk the ring bounds and ends back at the inner horizon
+        let bh = KerrBlackHole::<f64>::new(1.0, 0.9);
+        let mut fall = FreeFall::from_rest_at_infinity(bh, [8.0, std::f64::consts::FRAC_PI_2, 0.0]);
+        let (remaining, end) = fall.time_to_end().unwrap();
+        assert_eq!(end, FallEnd::Singularity);
+        assert!(matches!(fall.trace(), RayOutcome::Captured(_)));
+        assert!(fall.has_reached_singularity());
+        assert!((fall.proper_time - remaining).abs() < 1e-12);
+        let mut fall = FreeFall::from_rest_at_infinity(bh, [8.0, 0.6, 0.0]);
+        let (remaining, end) = fall.time_to_end().unwrap();
+        assert_eq!(end, FallEnd::InnerHorizon);
+        assert!(remaining > 0.0);
+        assert!(matches!(fall.trace(), RayOutcome::Captured(_)));
+        assert_eq!(fall.fall_end(), Some(FallEnd::InnerHorizon));
+    }
+
+    #[test]
+    fn test_frames_match_observers_outside() {
+        // Dropped from rest at infinity, the body is the free-fall observer
+        let bh = KerrBlackHole::<f64>::new(1.0, 0.7);
+        let (r, theta) = (6.0, 1.1);
+        let fall = FreeFall::from_rest_at_infinity(bh, [r, theta, 0.3]);
+        let identity = fall.lorentz_transform().unwrap();
+        for (a, row) in identity.iter().enumerate() {
+            for (b, entry) in row.iter().enumerate() {
+                assert!((entry - if a == b { 1.0 } else { 0.0 }).abs() < 1e-12, "{:?}", identity);
+            }
+        }
+
+        // Launched past the ZAMO at v, the ZAMO sees it move at γv along v, and the
+        // Kerr-Schild axes are the ones `Observer` builds from Carter's frame
+        let velocity = [-0.3, 0.2, 0.5];
+        let launched = FreeFall::launch(bh, [r, theta, 0.3], velocity).unwrap();
+        assert!((norm(&launched) + 1.0).abs() < 1e-12);
+        let delta = kerr_schild::delta(r, 1.0, 0.7);
+        let [u_t, u_r, u_theta, u_phi] = launched.four_velocity();
+        let boyer_lindquist = [u_t - 2.0 * r / delta * u_r, u_r, u_theta, u_phi - 0.7 / delta * u_r];
+        let rain = Observer::FreeFall.carter_frame(&bh, r, theta).unwrap();
+        let expected = frame_transform(comoving_frame(&bh, r, theta, boyer_lindquist), rain);
+        let to_rain = launched.lorentz_transform().unwrap();
+        for (x, y) in to_rain.iter().flatten().zip(expected.iter().flatten()) {
+            assert!((x - y).abs() < 1e-12, "{:?} vs {:?}", to_rain, expected);
+        }
+
+        // Both feel the tides `tidal` finds in their frames
+        let riemann = tidal::carter_riemann(&bh, r, theta);
+        let tides = [
+            (fall.tidal_tensor().unwrap(), tidal::tidal_tensor(&bh, Observer::FreeFall, r, theta).unwrap()),
+            (launched.tidal_tensor().unwrap(), tidal::electric_part(&riemann, comoving_frame(&bh, r, theta, boyer_lindquist))),
+        ];
+        for (tensor, expected) in tides {
+            for (x, y) in tensor.components.iter().flatten().zip(expected.components.iter().flatten()) {
+                assert!((x - y).abs() < 1e-12, "{:?} vs {:?}", tensor, expected);
+            }
+        }
+        let rain_to_zamo = Observer::FreeFall.lorentz_transform(&Observer::ZeroAngularMomentum, &bh, r, theta).unwrap();
+        let transform: Vec<[f64; 4]> = rain_to_zamo
+            .iter()
+            .map(|row| [0, 1, 2, 3].map(|b| (0..4).map(|c| row[c] * to_rain[c][b]).sum()))
+            .collect();
+        let gamma = 1.0 / (1.0 - 0.38_f64).sqrt();
+        assert!((transform[0][0] - gamma).abs() < 1e-12);
+        for (row, v) in transform[1..].iter().zip(velocity) {
+            assert!((row[0] - gamma * v).abs() < 1e-12);
+        }
+
+        assert!(FreeFall::launch(bh, [r, theta, 0.0], [0.6, 0.0, 0.8]).is_none());
+        assert!(FreeFall::launch(bh, [bh.outer_horizon(), theta, 0.0], [0.0; 3]).is_none());
+    }
+
+    /// Largest deviation of Λᵀ η Λ from η
+    fn lorentz_defect(transform: &[[f64; 4]; 4]) -> f64 {
+        let eta = |a: usize| if a == 0 { -1.0 } else { 1.0 };
+        let mut defect: f64 = 0.0;
+        for b in 0..4 {
+            for c in 0..4 {
+                let product: f64 = (0..4).map(|a| eta(a) * transform[a][b] * transform[a][c]).sum();
+                defect = defect.max((product - if b == c { eta(b) } else { 0.0 }).abs());
+            }
+        }
+        defect
+    }
+
+    #[test]
+    fn test_launched_fall_stays_timelike_across_the_horizon() {
+        // Launched with some angular momentum against the spin, the body crosses both
+        // horizons, turns around short of the ring and ends coming back out to the inner one
+        let bh = KerrBlackHole::<f64>::new(1.0, 0.9);
+        let inner = 1.0 - (1.0 - 0.81_f64).sqrt();
+        let mut fall = FreeFall::launch(bh, [5.0, 1.2, 0.0], [-0.2, 0.1, -0.2]).unwrap();
+        fall.integrator.abs_tolerance = 1e-10;
+        fall.integrator.rel_tolerance = 1e-10;
+        let (mut crossed, mut deepest) = (false, f64::INFINITY);
+        while fall.step().is_continuing() {
+            crossed |= fall.is_inside_horizon();
+            deepest = deepest.min(fall.geodesic.radius());
+            // Off the inner horizon the body stays on shell, and the tides in its frame stay
+            // symmetric and, in vacuum, traceless
+            let tides = fall.tidal_tensor().unwrap().components;
+            if (fall.geodesic.radius() - inner).abs() > 0.1 {
+                assert!((norm(&fall) + 1.0).abs() < 1e-6, "u·u = {} at r = {}", norm(&fall), fall.geodesic.radius());
+                let size = tides.iter().flatten().fold(0.0_f64, |size, entry| size.max(entry.abs()));
+                assert!((tides[0][0] + tides[1][1] + tides[2][2]).abs() < 1e-6 * size, "{:?} at r = {}", tides, fall.geodesic.radius());
+                assert!((0..3).all(|i| (0..3).all(|j| (tides[i][j] - tides[j][i]).abs() < 1e-6 * size)), "{:?}", tides);
+            }
+
+            // The frame stays a Lorentz transformation through both horizons
+            let transform = fall.lorentz_transform().unwrap();
+            assert!(lorentz_defect(&transform) < 1e-6, "{:?} at r = {}", transform, fall.geodesic.radius());
+        }
+        assert!(crossed && deepest < 0.5 * inner);
+        assert!((fall.geodesic.radius() - inner).abs() < 2e-2 && !fall.has_reached_singularity());
+    }
+}
@@ -0,0 +1,328 @@
+//! Bipolar relativistic jet along the spin axis.
+//!
+//! Plasma streams away from the equator inside a funnel around the axis, either a cone or
+//! a paraboloid R² ∝ |z| like the collimating base of the M87 jet, with cylindrical radius
+//! R = r sin θ and height z = r cos θ. Relative to the local zero angular momentum
+//! observer it moves along the funnel's streamlines with bulk Lorentz factor Γ.
+//!
+//! The plasma emits optically thin synchrotron-like light with a power-law spectrum
+//! j_ν ∝ ν^(-α) in its own frame, and may absorb with α_ν ∝ ν^(-(α + 5/2)) as in
+//! synchrotron self-absorption. Since j_ν / ν² is invariant, light received at a fixed
+//! frequency gains g^(2 + α) per unit path length in the frame it is received in, or
+//! g^(3 + α) per unit comoving path length, the Doppler boosting that makes the
+//! approaching side outshine the receding one.
+
+use crate::blackbody::{spectrum_xyz, xyz_to_linear_srgb};
+use crate::float::Float;
+use crate::observer::Observer;
+use crate::outcome::RayOutcome;
+use crate::redshift::redshift_factor;
+use crate::{Geodesic, Integrator, KerrBlackHole, KerrLightRay};
+
+/// Shape of the funnel the jet fills
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JetShape<T: Float = f32> {
+    /// Cone of the given half-opening angle about the axis, R = |z| tan θ_j
+    Conical { opening_angle: T },
+    /// Paraboloid R = w √(M|z|), `width` w times the mass wide one mass above the hole
+    Parabolic { width: T },
+}
+
+/// Jet launched along both directions of the spin axis
+#[derive(Debug, Clone, Copy)]
+pub struct Jet<T: Float = f32> {
+    /// Black hole launching the jet
+    pub black_hole: KerrBlackHole<T>,
+    /// Funnel the plasma fills
+    pub shape: JetShape<T>,
+    /// Bulk Lorentz factor Γ relative to zero angular momentum observers
+    pub lorentz_factor: T,
+    /// Comoving emissivity at r = M on the funnel wall, 1 by default
+    pub emissivity: T,
+    /// Comoving absorption coefficient at r = M on the funnel wall, 0 by default
+    pub absorption: T,
+    /// Spectral index α of j_ν ∝ ν^(-α), 0.7 by default
+    pub spectral_index: T,
+    /// Radius beyond which the jet no longer shines, 100 M by default
+    pub length: T,
+    /// Longest affine step a ray takes within `length`, 0.1 by default
+    pub max_step: T,
+}
+
+/// What a ray traced through a jet collected
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JetImage<T: Float = f32> {
+    /// Specific intensity received at unit frequency
+    pub intensity: T,
+    /// Optical depth accumulated along the ray
+    pub optical_depth: T,
+    /// How the ray ended once past the jet
+    pub outcome: RayOutcome<T>,
+}
+
+impl<T: Float> Jet<T> {
+    /// Jet of the given shape and bulk Lorentz factor, with default emission
+    ///
+    /// # Panics
+    ///
+    /// Panics if the Lorentz factor is less than 1.
+    pub fn new(black_hole: KerrBlackHole<T>, shape: JetShape<T>, lorentz_factor: T) -> Self {
+        assert!(lorentz_factor >= T::ONE, "Lorentz factor must be at least 1");
+        Self {
+            black_hole,
+            shape,
+            lorentz_factor,
+            emissivity: T::ONE,
+            absorption: T::ZERO,
+            spectral_index: T::from_f64(0.7),
+            length: T::from_f64(100.0) * black_hole.mass,
+            max_step: T::from_f64(0.1),
+        }
+    }
+
+    /// Cylindrical radius of the funnel wall at height z
+    pub fn funnel_radius(&self, z: T) -> T {
+        match self.shape {
+            JetShape::Conical { opening_angle } => z.abs() * opening_angle.sin() / opening_angle.cos(),
+            JetShape::Parabolic { width } => width * (self.black_hole.mass * z.abs()).sqrt(),
+        }
+    }
+
+    /// Whether (r, θ) lies inside the jet
+    pub fn contains(&self, r: T, theta: T) -> bool {
+        r > self.black_hole.outer_horizon() && r <= self.length && r * theta.sin().abs() <= self.funnel_radius(r * theta.cos())
+    }
+
+    /// Emission and absorption profile (M/r)² (R/R_wall)² at (r, θ), brightest along the
+    /// funnel wall as in the limb-brightened M87 jet, and zero outside the jet
+    pub fn profile(&self, r: T, theta: T) -> T {
+        if !self.contains(r, theta) {
+            return T::ZERO;
+        }
+        let wall = self.funnel_radius(r * theta.cos());
+        let edge = (r * theta.sin() / wall).min(T::ONE);
+        (self.black_hole.mass / r).powi(2) * edge * edge
+    }
+
+    /// Linear sRGB color of the jet's power-law spectrum, scaled to unit luminance and
+    /// clipped to the sRGB gamut
+    ///
+    /// A power law keeps its shape under any Doppler shift, so the jet shows this one color
+    /// everywhere and only its brightness changes.
+    pub fn color(&self) -> [T; 3] {
+        // F_ν ∝ ν^(-α) is F_λ ∝ λ^(α - 2)
+        let xyz = spectrum_xyz(|wavelength: T| wavelength.powf(self.spectral_index - T::from_f64(2.0)));
+        xyz_to_linear_srgb(xyz).map(|value| (value / xyz[1]).max(T::ZERO))
+    }
+
+    /// Four-velocity u^μ of the plasma at (r, θ) in Boyer-Lindquist coordinates, or
+    /// `None` at or inside the horizon
+    ///
+    /// The ZAMO sees it move along the streamline through that point, taken from the flat
+    /// geometry of the funnel.
+    pub fn four_velocity(&self, r: T, theta: T) -> Option<[T; 4]> {
+        let tetrad = Observer::ZeroAngularMomentum.tetrad(&self.black_hole, r, theta)?;
+        let (sin_theta, cos_theta) = (theta.sin(), theta.cos());
+        let z = r * cos_theta;
+        let away = if z < T::ZERO { -T::ONE } else { T::ONE };
+
+        // Streamline direction in cylindrical (R, z), then along e_r̂ and e_θ̂
+        let (along_r, along_z) = match self.shape {
+            JetShape::Conical { .. } => (sin_theta, cos_theta),
+            JetShape::Parabolic { .. } => (r * sin_theta / (T::from_f64(2.0) * z.abs()), away),
+        };
+        let norm = (along_r * along_r + along_z * along_z).sqrt();
+        let radial = (along_r * sin_theta + along_z * cos_theta) / norm;
+        let polar = (along_r * cos_theta - along_z * sin_theta) / norm;
+
+        let gamma = self.lorentz_factor;
+        let speed = (T::ONE - T::ONE / (gamma * gamma)).sqrt();
+        let mut velocity = [T::ZERO; 4];
+        for (i, component) in velocity.iter_mut().enumerate() {
+            *component = gamma * (tetrad[0][i] + speed * (radial * tetrad[1][i] + polar * tetrad[2][i]));
+        }
+        Some(velocity)
+    }
+}
+
+/// Step `ray`, made past-directed, through `jet`, collecting its boosted light, until it
+/// stops
+///
+/// The plasma sees the light as -p_μ. Steps are capped at `jet.max_step` within
+/// `jet.length`, and each is integrated with the trapezoidal rule in affine parameter.
+pub(crate) fn trace<T: Float, I: Integrator<T>>(ray: &mut KerrLightRay<T, I>, jet: &Jet<T>) -> JetImage<T> {
+    ray.make_past_directed();
+    let alpha = jet.spectral_index;
+    // Emissivity and absorption per unit affine parameter at unit received frequency
+    let sample = |state: &Geodesic<T>| {
+        let (r, theta) = (state.position[1], state.position[2]);
+        let profile = jet.profile(r, theta);
+        let velocity = jet.four_velocity(r, theta).filter(|_| profile > T::ZERO);
+        let Some(velocity) = velocity else {
+            return (T::ZERO, T::ZERO);
+        };
+        let emitted = state.momentum.map(|p| -p);
+        let received = [emitted[0], T::ZERO, T::ZERO, emitted[3]];
+        let g = redshift_factor(emitted, velocity, received, [T::ONE, T::ZERO, T::ZERO, T::ZERO]);
+        if !g.is_finite() || g <= T::ZERO {
+            return (T::ZERO, T::ZERO);
+        }
+
+        // Comoving path length ds = ν dλ, at comoving frequency ν = E/g
+        let path = -emitted[0] / g;
+        let emission = jet.emissivity * profile * g.powf(T::from_f64(3.0) + alpha) * path;
+        let absorption = jet.absorption * profile * g.powf(alpha + T::from_f64(2.5)) * path;
+        (emission, absorption)
+    };
+
+    let mut image = JetImage { intensity: T::ZERO, optical_depth: T::ZERO, outcome: RayOutcome::Continuing };
+    let mut previous = sample(&ray.tracer.geodesic);
+    loop {
+        if ray.tracer.geodesic.radius() <= jet.length {
+            ray.tracer.step_size = ray.tracer.step_size.min(jet.max_step);
+        }
+        let start = ray.tracer.affine_parameter;
+        let outcome = ray.step();
+        let step = ray.tracer.affine_parameter - start;
+        let current = sample(&ray.tracer.geodesic);
+
+        // Emission over the step, attenuated by what lies between it and the camera
+        let half = T::from_f64(0.5);
+        let depth = (previous.1 + current.1) * half * step;
+        let transmitted = (-image.optical_depth).exp();
+        let emitted = (previous.0 + current.0) * half * step;
+        image.intensity += if depth > T::from_f64(1e-8) {
+            transmitted * emitted * (T::ONE - (-depth).exp()) / depth
+        } else {
+            transmitted * emitted
+        };
+        image.optical_depth += depth;
+        previous = current;
+
+        if !outcome.is_continuing() {
+            image.outcome = outcome;
+            return image;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kerr_schild;
+
+    #[test]
+    fn test_funnel_and_flow() {
+        let bh = KerrBlackHole::<f64>::new(1.0, 0.9);
+        let parabolic = Jet::new(bh, JetShape::Parabolic { width: 1.0 }, 5.0);
+        assert!((parabolic.funnel_radius(-16.0) - 4.0).abs() < 1e-12);
+        assert!(parabolic.contains(20.0, 0.1) && parabolic.contains(20.0, std::f64::consts::PI - 0.1));
+        assert!(!parabolic.contains(20.0, 0.5) && !parabolic.contains(200.0, 0.05));
+        assert_eq!(parabolic.profile(20.0, 0.5), 0.0);
+
+        // The plasma is a unit timelike vector with Lorentz factor Γ against the ZAMO
+        for (jet, theta) in [(parabolic, 0.1), (parabolic, 3.0), (Jet::new(bh, JetShape::Conical { opening_angle: 0.2 }, 5.0), 0.15)] {
+            let r = 10.0;
+            let u = jet.four_velocity(r, theta).unwrap();
+            let g = kerr_schild::metric_components(r, theta, &bh);
+            // Kerr-Schild and Boyer-Lindquist share g_tt, g_tφ, g_θθ, g_φφ; only u^r needs g_rr
+            let g_rr = kerr_schild::sigma(r, theta, 0.9) / kerr_schild::delta(r, 1.0, 0.9);
+            let norm = g[0][0] * u[0] * u[0] + 2.0 * g[0][3] * u[0] * u[3] + g[3][3] * u[3] * u[3] + g_rr * u[1] * u[1] + g[2][2] * u[2] * u[2];
+            assert!((norm + 1.0).abs() < 1e-10, "{}", norm);
+            let zamo = Observer::ZeroAngularMomentum.four_velocity(&bh, r, theta).unwrap();
+            let lorentz = -(g[0][0] * u[0] * zamo[0] + g[0][3] * (u[0] * zamo[3] + u[3] * zamo[0]) + g[3][3] * u[3] * zamo[3]);
+            assert!((lorentz - 5.0).abs() < 1e-10);
+
+            // Both lobes stream away from the equator
+            let dz = u[1] * theta.cos() - r * u[2] * theta.sin();
+            assert!(dz * theta.cos() > 0.0);
+        }
+
+        // Steeper spectra put more of their light at long wavelengths
+        let mut steep = parabolic;
+        steep.spectral_index = 2.0;
+        let (color, redder) = (parabolic.color(), steep.color());
+        assert!(redder[0] / redder[2] > color[0] / color[2]);
+        assert!((0.2126 * color[0] + 0.7152 * color[1] + 0.0722 * color[2] - 1.0).abs() < 0.05, "{:?}", color);
+    }
+
+    #[test]
+    fn test_doppler_boosting() {
+        // Viewed 20° off the axis, against a jet at rest, the approaching lobe brightens and
+        // the receding one fades, the more so the faster the flow
+        let bh = KerrBlackHole::<f64>::schwarzschild(1.0);
+        let inclination = 20.0_f64.to_radians();
+        let camera = [80.0 * inclination.sin(), 0.0, 80.0 * inclination.cos()];
+        let image = |lorentz_factor: f64, target: [f64; 3]| {
+            let jet = Jet::new(bh, JetShape::Conical { opening_angle: 0.15 }, lorentz_factor);
+            let direction = [0, 1, 2].map(|i| target[i] - camera[i]);
+            KerrLightRay::new(camera, direction, bh).trace_jet(&jet)
+        };
+        let boost = |lorentz_factor: f64, target: [f64; 3]| image(lorentz_factor, target).intensity / image(1.0, target).intensity;
+        let (near, far) = ([0.0, 0.0, 20.0], [0.0, 0.0, -20.0]);
+        assert!(image(1.0, near).intensity > 0.0 && image(1.0, far).intensity > 0.0);
+        assert!(boost(3.0, near) > boost(1.5, near) && boost(1.5, near) > 1.0);
+        assert!(boost(3.0, far) < boost(1.5, far) && boost(1.5, far) < 1.0);
+        assert!(boost(3.0, near) / boost(3.0, far) > 10.0);
+        assert_eq!(image(3.0, near).optical_depth, 0.0);
+    }
+
+    #[test]
+    fn test_thin_jet_boost() {
+        // Crossing a thin stretch of jet 80 masses out, where space is nearly flat, the light
+        // is boosted by δ^(2 + α) with δ = 1 / (Γ (1 - β cos ϑ)) for the angle ϑ between the
+        // flow and the light in the static frame
+        let bh = KerrBlackHole::<f64>::schwarzschild(1.0);
+        let target = [0.0, 0.0, 80.0];
+        let view = 30.0_f64.to_radians();
+        let camera = [2.0 * view.sin(), 0.0, 80.0 + 2.0 * view.cos()];
+        let direction = [0, 1, 2].map(|i| target[i] - camera[i]);
+        let jet = |lorentz_factor: f64| {
+            let mut jet = Jet::new(bh, JetShape::Conical { opening_angle: 0.01 }, lorentz_factor);
+            jet.max_step = 0.02;
+            jet
+        };
+        let boost = KerrLightRay::new(camera, direction, bh).trace_jet(&jet(2.0)).intensity
+            / KerrLightRay::new(camera, direction, bh).trace_jet(&jet(1.0)).intensity;
+
+        // The conical flow is radial, so cos ϑ is the light's radial direction where the ray
+        // passes closest to the axis
+        let mut ray = KerrLightRay::new(camera, direction, bh);
+        ray.make_past_directed();
+        let mut closest = ray.tracer.geodesic;
+        while ray.step().is_continuing() && ray.tracer.geodesic.radius() > 70.0 {
+            ray.tracer.step_size = ray.tracer.step_size.min(0.02);
+            let [_, r, theta, _] = ray.tracer.geodesic.position;
+            if (r * theta.sin()).abs() < (closest.position[1] * closest.position[2].sin()).abs() {
+                closest = ray.tracer.geodesic;
+            }
+        }
+        let [_, r, _, _] = closest.position;
+        let lapse = (1.0 - 2.0 / r).sqrt();
+        // The light is -p of the past-directed ray
+        let cos_angle = -closest.momentum[1] * lapse / (closest.momentum[0] / lapse);
+        let speed = (1.0 - 1.0 / 4.0_f64).sqrt();
+        let doppler = 1.0 / (2.0 * (1.0 - speed * cos_angle));
+        let expected = doppler.powf(2.0 + jet(2.0).spectral_index);
+        assert!(doppler > 1.0);
+        assert!((boost / expected - 1.0).abs() < 1e-3, "{} vs {}", boost, expected);
+    }
+
+    #[test]
+    fn test_self_absorption() {
+        let bh = KerrBlackHole::<f64>::schwarzschild(1.0);
+        let camera = [60.0, 0.0, 40.0];
+        let image = |absorption: f64| {
+            let mut jet = Jet::new(bh, JetShape::Parabolic { width: 1.0 }, 2.0);
+            jet.absorption = absorption;
+            let direction = [0, 1, 2].map(|i| [0.0, 0.0, 20.0][i] - camera[i]);
+            KerrLightRay::new(camera, direction, bh).trace_jet(&jet)
+        };
+
+        // Emission and absorption share a profile, so a thick jet shows the source function
+        // S = j/α of its near side, and ten times the absorption gives a tenth of the light
+        let (thin, thick, thicker) = (image(0.0), image(1e4), image(1e5));
+        assert!(thick.optical_depth > 20.0 && thin.intensity > thick.intensity);
+        assert!((thick.intensity / thicker.intensity - 10.0).abs() < 0.5);
+    }
+}
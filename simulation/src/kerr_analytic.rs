@@ -0,0 +1,408 @@
+//! Closed-form Kerr null geodesics for an observer at infinity.
+//!
+//! A ray leaving the image plane at (α, β) of an observer at inclination θ_o carries
+//! λ = L/E = -α sin θ_o and η = Q/E² = (α² - a²) cos²θ_o + β². In Mino time τ, with
+//! dx^μ/dτ = Σ dx^μ/dλ, the radial and polar motions separate and both invert to Jacobi
+//! elliptic functions of τ. That takes (α, β, θ_o) straight to the Mino time of every
+//! equatorial crossing and the radius it happens at, without integrating anything.
+//!
+//! Rays are followed backwards from the observer, so τ = 0 is the observer at infinity.
+
+use crate::elliptic::{complete_first_kind, incomplete_first_kind, jacobi_elliptic};
+use crate::float::Float;
+use crate::KerrBlackHole;
+
+/// Where a ray crosses the equatorial plane θ = π/2
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EquatorialCrossing<T: Float = f32> {
+    /// Number of earlier crossings: 0 for the direct image, 1 for the first lensed one
+    pub order: u32,
+    /// Mino time elapsed since leaving the observer
+    pub mino_time: T,
+    /// Boyer-Lindquist radius of the crossing, i.e. the source radius on a thin disk
+    pub radius: T,
+}
+
+/// Radial motion of a ray coming in from infinity, by the roots of
+/// R(r) = (r² + a² - aλ)² - Δ (η + (λ - a)²)
+#[derive(Debug, Clone, Copy)]
+enum RadialMotion<T: Float> {
+    /// Four real roots r1 < r2 < r3 < r4; the ray stays at r ≥ r4
+    RealRoots { r1: T, r2: T, r3: T, r4: T },
+    /// Real roots r1 < r2 and a complex pair at distance `a` from r2 and `b` from r1;
+    /// the ray stays at r ≥ r2
+    ComplexPair { r1: T, r2: T, a: T, b: T },
+}
+
+impl<T: Float> RadialMotion<T> {
+    /// Factor the depressed quartic r⁴ + p r² + q r + s, which has q > 0 and s ≤ 0 for
+    /// every ray that can reach the equator
+    fn from_quartic(p: T, q: T, s: T) -> Self {
+        let two = T::from_f64(2.0);
+        let half = T::from_f64(0.5);
+
+        // Ferrari: (r² + y)² = (2y - p) r² - q r + y² - s is a perfect square once
+        // 8y³ - 4py² - 8sy + 4ps - q² = 0, which has a root above p/2 since the cubic
+        // equals -q² there
+        let resolvent = |y: T| {
+            T::from_f64(8.0) * y * y * y - T::from_f64(4.0) * p * y * y - T::from_f64(8.0) * s * y
+                + T::from_f64(4.0) * p * s
+                - q * q
+        };
+        let mut low = p * half;
+        let mut width = T::ONE + p.abs();
+        while resolvent(low + width) < T::ZERO {
+            width *= two;
+        }
+        let mut high = low + width;
+        loop {
+            let mid = (low + high) * half;
+            if mid <= low || mid >= high {
+                break;
+            }
+            if resolvent(mid) < T::ZERO {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        let y = high;
+        let m = (two * y - p).sqrt();
+
+        // r² - mr + y + q/2m holds the outer pair of roots, r² + mr + y - q/2m the inner
+        let quarter_m_sq = m * m * T::from_f64(0.25);
+        let outer = quarter_m_sq - y - q / (two * m);
+        let inner = (quarter_m_sq - y + q / (two * m)).max(T::ZERO).sqrt();
+        let (r1, r2) = (-m * half - inner, -m * half + inner);
+
+        if outer >= T::ZERO {
+            let (r3, r4) = (m * half - outer.sqrt(), m * half + outer.sqrt());
+            RadialMotion::RealRoots { r1, r2, r3, r4 }
+        } else {
+            let distance = |root: T| ((root - m * half) * (root - m * half) - outer).sqrt();
+            RadialMotion::ComplexPair { r1, r2, a: distance(r2), b: distance(r1) }
+        }
+    }
+
+    /// Innermost radius the motion reaches
+    fn turning_radius(&self) -> T {
+        match *self {
+            RadialMotion::RealRoots { r4, .. } => r4,
+            RadialMotion::ComplexPair { r2, .. } => r2,
+        }
+    }
+
+    /// Mino time per unit of elliptic argument, and the parameter of the Jacobi functions
+    fn scale_and_parameter(&self) -> (T, T) {
+        match *self {
+            RadialMotion::RealRoots { r1, r2, r3, r4 } => {
+                let (r31, r42) = (r3 - r1, r4 - r2);
+                let scale = T::from_f64(2.0) / (r31 * r42).sqrt();
+                (scale, (r3 - r2) * (r4 - r1) / (r31 * r42))
+            }
+            RadialMotion::ComplexPair { r1, r2, a, b } => {
+                let r21 = r2 - r1;
+                let parameter = ((a + b) * (a + b) - r21 * r21) / (T::from_f64(4.0) * a * b);
+                (T::ONE / (a * b).sqrt(), parameter)
+            }
+        }
+    }
+
+    /// Elliptic argument u with ∫_turning^r dr / √R = scale · u; infinite r is allowed
+    fn argument(&self, r: T, parameter: T) -> T {
+        match *self {
+            RadialMotion::RealRoots { r1, r3, r4, .. } => {
+                let ratio = if r.is_finite() { (r - r4) / (r - r3) } else { T::ONE };
+                let sn_sq = (r3 - r1) * ratio / (r4 - r1);
+                incomplete_first_kind(sn_sq.clamp(T::ZERO, T::ONE).sqrt().asin(), parameter)
+            }
+            RadialMotion::ComplexPair { r1, r2, a, b } => {
+                let cn = if r.is_finite() {
+                    ((a - b) * r + r2 * b - r1 * a) / ((a + b) * r - r2 * b - r1 * a)
+                } else {
+                    (a - b) / (a + b)
+                };
+                incomplete_first_kind(cn.clamp(-T::ONE, T::ONE).acos(), parameter)
+            }
+        }
+    }
+
+    /// Radius at elliptic argument u, the inverse of `argument` on both sides of the turn
+    fn radius(&self, u: T, parameter: T) -> T {
+        let (sn, cn, _) = jacobi_elliptic(u, parameter);
+        match *self {
+            RadialMotion::RealRoots { r1, r3, r4, .. } => {
+                let (r31, r41) = (r3 - r1, r4 - r1);
+                (r4 * r31 - r3 * r41 * sn * sn) / (r31 - r41 * sn * sn)
+            }
+            RadialMotion::ComplexPair { r1, r2, a, b } => {
+                ((r2 * b - r1 * a) + (r2 * b + r1 * a) * cn) / ((a + b) * cn - (a - b))
+            }
+        }
+    }
+}
+
+/// A null geodesic of a Kerr black hole, solved in closed form from the image plane of a
+/// distant observer
+#[derive(Debug, Clone, Copy)]
+pub struct AnalyticRay<T: Float = f32> {
+    /// Black hole parameters
+    pub black_hole: KerrBlackHole<T>,
+    /// Energy-rescaled angular momentum λ = L/E
+    pub lambda: T,
+    /// Energy-rescaled Carter constant η = Q/E²
+    pub eta: T,
+    /// Observer inclination θ_o from the spin axis
+    pub inclination: T,
+    /// +1 if the ray leaves the observer heading for the north pole (β ≥ 0), else -1
+    pub polar_sign: T,
+    radial: RadialMotion<T>,
+    radial_scale: T,
+    radial_parameter: T,
+    radial_infinity: T,
+    polar_amplitude: T,
+    polar_rate: T,
+    polar_parameter: T,
+    polar_quarter: T,
+    observer_phase: T,
+}
+
+impl<T: Float> AnalyticRay<T> {
+    /// Ray through the image-plane point (α, β) of an observer at inclination θ_o
+    ///
+    /// α runs along the projected equator and β along the projected spin axis, both in
+    /// the same length units as the mass. Returns `None` for vortical rays (η ≤ 0), which
+    /// never reach the equatorial plane.
+    pub fn from_image_plane(black_hole: KerrBlackHole<T>, alpha: T, beta: T, inclination: T) -> Option<Self> {
+        let a = black_hole.spin;
+        let cos_o = inclination.cos();
+        let lambda = -alpha * inclination.sin();
+        let eta = (alpha * alpha - a * a) * cos_o * cos_o + beta * beta;
+        let polar_sign = if beta >= T::ZERO { T::ONE } else { -T::ONE };
+        Self::from_constants(black_hole, lambda, eta, inclination, polar_sign)
+    }
+
+    /// Ray with conserved λ and η arriving at an observer at inclination θ_o
+    ///
+    /// Returns `None` if η ≤ 0, since such rays never cross the equatorial plane.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the polar potential Θ(θ_o) is negative, i.e. no such ray reaches the
+    /// observer.
+    pub fn from_constants(black_hole: KerrBlackHole<T>, lambda: T, eta: T, inclination: T, polar_sign: T) -> Option<Self> {
+        if eta <= T::ZERO {
+            return None;
+        }
+        let (mass, a) = (black_hole.mass, black_hole.spin);
+        let half = T::from_f64(0.5);
+
+        // R(r) = r⁴ + (a² - η - λ²) r² + 2M (η + (λ - a)²) r - a² η
+        let radial = RadialMotion::from_quartic(
+            a * a - eta - lambda * lambda,
+            T::from_f64(2.0) * mass * (eta + (lambda - a) * (lambda - a)),
+            -a * a * eta,
+        );
+        let (radial_scale, radial_parameter) = radial.scale_and_parameter();
+        let radial_infinity = radial.argument(T::from_f64(f64::INFINITY), radial_parameter);
+
+        // With x = cos θ, (dx/dτ)² = a² (u₊ - x²)(x² - u₋). Work with a²u₋ and
+        // u₊ = -η / (a²u₋) so the Schwarzschild limit a → 0 stays finite.
+        let shifted = half * (a * a - eta - lambda * lambda);
+        let a_sq_u_minus = shifted - (shifted * shifted + a * a * eta).sqrt();
+        let u_plus = -eta / a_sq_u_minus;
+        let polar_parameter = a * a * u_plus / a_sq_u_minus;
+        let polar_amplitude = u_plus.sqrt();
+
+        let cos_o = inclination.cos();
+        let theta_potential = eta + a * a * cos_o * cos_o - lambda * lambda * cos_o * cos_o / inclination.sin().powi(2);
+        assert!(
+            theta_potential >= -T::from_f64(1e3) * T::EPSILON * (T::ONE + eta),
+            "no ray with these constants reaches the observer"
+        );
+        let start = (cos_o / polar_amplitude).clamp(-T::ONE, T::ONE);
+
+        Some(Self {
+            black_hole,
+            lambda,
+            eta,
+            inclination,
+            polar_sign,
+            radial,
+            radial_scale,
+            radial_parameter,
+            radial_infinity,
+            polar_amplitude,
+            polar_rate: (-a_sq_u_minus).sqrt(),
+            polar_parameter,
+            polar_quarter: complete_first_kind(polar_parameter),
+            observer_phase: incomplete_first_kind(start.asin(), polar_parameter),
+        })
+    }
+
+    /// Check if the ray ends on the horizon rather than turning back out to infinity
+    pub fn is_captured(&self) -> bool {
+        self.radial.turning_radius() < self.black_hole.outer_horizon()
+    }
+
+    /// Mino time until the ray crosses the outer horizon or returns to infinity
+    pub fn total_mino_time(&self) -> T {
+        if self.is_captured() {
+            let horizon = self.radial.argument(self.black_hole.outer_horizon(), self.radial_parameter);
+            self.radial_scale * (self.radial_infinity - horizon)
+        } else {
+            T::from_f64(2.0) * self.radial_scale * self.radial_infinity
+        }
+    }
+
+    /// Mino time at which the ray first reaches radius r on its way in, or `None` if it
+    /// never gets that deep
+    pub fn mino_time_at_radius(&self, r: T) -> Option<T> {
+        if r < self.radial.turning_radius() || (self.is_captured() && r < self.black_hole.outer_horizon()) {
+            return None;
+        }
+        Some(self.radial_scale * (self.radial_infinity - self.radial.argument(r, self.radial_parameter)))
+    }
+
+    /// Boyer-Lindquist radius after Mino time τ
+    pub fn radius_at(&self, mino_time: T) -> T {
+        self.radial.radius(self.radial_infinity - mino_time / self.radial_scale, self.radial_parameter)
+    }
+
+    /// cos θ after Mino time τ
+    pub fn cos_theta_at(&self, mino_time: T) -> T {
+        let phase = self.observer_phase + self.polar_sign * self.polar_rate * mino_time;
+        self.polar_amplitude * jacobi_elliptic(phase, self.polar_parameter).0
+    }
+
+    /// Equatorial crossing of the given order, if the ray makes it before it is captured
+    /// or escapes
+    pub fn crossing(&self, order: u32) -> Option<EquatorialCrossing<T>> {
+        // cos θ vanishes whenever the phase F_o ± ντ is a multiple of 2K; find the first
+        // multiple ahead of the observer, who may sit on either side of the equator
+        let period = T::from_f64(2.0) * self.polar_quarter;
+        let start = (self.observer_phase / period).to_f64();
+        let phase = if self.polar_sign > T::ZERO {
+            T::from_f64(start.floor() + 1.0 + order as f64) * period - self.observer_phase
+        } else {
+            self.observer_phase - T::from_f64(start.ceil() - 1.0 - order as f64) * period
+        };
+        let mino_time = phase / self.polar_rate;
+        if mino_time >= self.total_mino_time() {
+            return None;
+        }
+        Some(EquatorialCrossing { order, mino_time, radius: self.radius_at(mino_time) })
+    }
+
+    /// Every equatorial crossing in order, so `len()` is the crossing count
+    pub fn equatorial_crossings(&self) -> Vec<EquatorialCrossing<T>> {
+        (0..).map_while(|order| self.crossing(order)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dormand_prince::DormandPrince54;
+    use crate::outcome::RayOutcome;
+    use crate::{kerr_schild, ConservedQuantities, Geodesic, KerrLightRay};
+
+    /// Numerical ray sharing the analytic ray's state at Mino time τ, with E = 1
+    fn numerical_ray(ray: &AnalyticRay<f64>, mino_time: f64) -> KerrLightRay<f64, DormandPrince54<f64>> {
+        let bh = ray.black_hole;
+        let (r, cos_theta) = (ray.radius_at(mino_time), ray.cos_theta_at(mino_time));
+        let theta = cos_theta.acos();
+        let a = bh.spin;
+
+        // p_r = ±√R / Δ and p_θ = ±√Θ, with signs from the direction of motion
+        let radial = (r * r + a * a - a * ray.lambda).powi(2)
+            - kerr_schild::delta(r, bh.mass, a) * (ray.eta + (ray.lambda - a).powi(2));
+        let polar = ray.eta + cos_theta * cos_theta * (a * a - ray.lambda * ray.lambda / theta.sin().powi(2));
+        let h = 1e-6;
+        let inbound = ray.radius_at(mino_time + h) < r;
+        let northbound = ray.cos_theta_at(mino_time + h) > cos_theta;
+        let p_r = radial.max(0.0).sqrt() / kerr_schild::delta(r, bh.mass, a) * if inbound { -1.0 } else { 1.0 };
+        let p_theta = polar.max(0.0).sqrt() * if northbound { -1.0 } else { 1.0 };
+
+        let position = [0.0, r, theta, 0.0];
+        let momentum = [-1.0, p_r, p_theta, ray.lambda];
+        let mut numerical = KerrLightRay::new([r, 0.0, 0.0], [-1.0, 0.0, 0.0], bh).with_integrator(DormandPrince54::default());
+        numerical.tracer.geodesic = Geodesic::new(position, momentum);
+        numerical.conserved = ConservedQuantities::from_initial_conditions(position, momentum, bh.mass, a);
+        numerical.tracer.step_size = 0.01;
+        numerical.tracer.max_steps = 100_000;
+        numerical
+    }
+
+    #[test]
+    fn test_motion_satisfies_potentials() {
+        // One ray of each radial root structure: a lensed, escaping one and a captured one
+        let bh = KerrBlackHole::new(1.0, 0.9);
+        let inclination = 1.2;
+        for (alpha, beta, captured) in [(-7.0, 4.0, false), (1.0, -2.0, true)] {
+            let ray = AnalyticRay::from_image_plane(bh, alpha, beta, inclination).unwrap();
+            assert_eq!(ray.is_captured(), captured);
+            let a = bh.spin;
+            for fraction in [0.1, 0.35, 0.6, 0.9] {
+                let tau = fraction * ray.total_mino_time();
+                let h = 1e-5;
+                let (r, x) = (ray.radius_at(tau), ray.cos_theta_at(tau));
+                let dr = (ray.radius_at(tau + h) - ray.radius_at(tau - h)) / (2.0 * h);
+                let dx = (ray.cos_theta_at(tau + h) - ray.cos_theta_at(tau - h)) / (2.0 * h);
+
+                let radial = (r * r + a * a - a * ray.lambda).powi(2)
+                    - kerr_schild::delta(r, bh.mass, a) * (ray.eta + (ray.lambda - a).powi(2));
+                let polar = (ray.eta + a * a * x * x) * (1.0 - x * x) - ray.lambda * ray.lambda * x * x;
+                assert!((dr * dr - radial).abs() < 1e-5 * (1.0 + radial), "(dr/dτ)² = {}, R = {}", dr * dr, radial);
+                assert!((dx * dx - polar).abs() < 1e-6 * (1.0 + polar), "(dx/dτ)² = {}, Θ sin²θ = {}", dx * dx, polar);
+            }
+
+            // The ray starts at infinity, and the inbound Mino time inverts the radius
+            assert!(ray.radius_at(1e-9) > 1e4);
+            let tau = ray.mino_time_at_radius(10.0).unwrap();
+            assert!((ray.radius_at(tau) - 10.0).abs() < 1e-9);
+        }
+
+        // β > 0 sends the ray towards the north pole first
+        let ray = AnalyticRay::from_image_plane(bh, 0.5, 6.0, 0.6).unwrap();
+        assert!(ray.cos_theta_at(1e-3) > 0.6_f64.cos());
+    }
+
+    #[test]
+    fn test_crossings_match_numerical_ray() {
+        let bh = KerrBlackHole::new(1.0, 0.94);
+        for (alpha, beta) in [(-6.8, 1.5), (2.0, -5.5), (0.5, 4.2)] {
+            let ray = AnalyticRay::from_image_plane(bh, alpha, beta, 1.3).unwrap();
+            let crossings = ray.equatorial_crossings();
+            assert!(!crossings.is_empty());
+
+            // Pick the ray up numerically at r = 50M on the way in and trace it onwards
+            let start = ray.mino_time_at_radius(50.0).unwrap();
+            let mut numerical = numerical_ray(&ray, start);
+            let mut traced = Vec::new();
+            let outcome = loop {
+                let outcome = numerical.step();
+                let dense = numerical.tracer.integrator.dense_output().unwrap();
+                if let Some((_, state)) = dense.find_crossing(|s| s.position[2] - std::f64::consts::FRAC_PI_2) {
+                    traced.push(state.radius());
+                }
+                if !outcome.is_continuing() {
+                    break outcome;
+                }
+            };
+            assert!(matches!(outcome, RayOutcome::Captured(_) | RayOutcome::Escaped(_)), "{:?}", outcome);
+            assert_eq!(matches!(outcome, RayOutcome::Captured(_)), ray.is_captured());
+
+            // Crossings past r = 100M on the way out are beyond the tracer's escape radius
+            let expected: Vec<_> = crossings.iter().filter(|c| c.mino_time > start && c.radius < 100.0).collect();
+            assert_eq!(traced.len(), expected.len(), "α = {}, β = {}: traced {:?}, expected {:?}", alpha, beta, traced, expected);
+            for (radius, crossing) in traced.iter().zip(&expected) {
+                assert!(
+                    (radius - crossing.radius).abs() < 1e-5 * crossing.radius,
+                    "order {} crossing at r = {}, closed form {}",
+                    crossing.order, radius, crossing.radius
+                );
+            }
+        }
+    }
+}
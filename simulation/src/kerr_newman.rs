@@ -0,0 +1,281 @@
+//! Charged black holes: Kerr–Newman, and Reissner–Nordström when the spin is zero.
+//!
+//! Charge enters the metric only through Δ = r² - 2Mr + a² + Q² and by replacing 2Mr
+//! with 2Mr - Q² in the Kerr-Schild potential, so every formula here reduces to the
+//! `KerrBlackHole` / `kerr_schild` one when Q = 0.
+
+use crate::float::{bisect, Float};
+use crate::{kerr_schild, KerrBlackHole};
+
+/// Kerr–Newman black hole parameters in Kerr-Schild coordinates
+#[derive(Debug, Clone, Copy)]
+pub struct KerrNewmanBlackHole {
+    /// Mass of the black hole in geometric units
+    pub mass: f32,
+    /// Spin parameter a = J/M in geometric units
+    pub spin: f32,
+    /// Electric charge Q in geometric units (a² + Q² ≤ M²)
+    pub charge: f32,
+}
+
+impl KerrNewmanBlackHole {
+    /// Create a new Kerr–Newman black hole
+    pub fn new(mass: f32, spin: f32, charge: f32) -> Self {
+        // Keep the horizon real: |a| ≤ M first, then a² + Q² ≤ M²
+        let spin = spin.clamp(-mass, mass);
+        let max_charge = Self { mass, spin, charge: 0.0 }.max_charge();
+        let charge = charge.clamp(-max_charge, max_charge);
+        Self { mass, spin, charge }
+    }
+
+    /// Largest charge √(M² - a²) the hole can carry at its spin and still have a horizon
+    pub fn max_charge(&self) -> f32 {
+        (self.mass * self.mass - self.spin * self.spin).max(0.0).sqrt()
+    }
+
+    /// Create a Reissner–Nordström (charged, non-spinning) black hole
+    pub fn reissner_nordstrom(mass: f32, charge: f32) -> Self {
+        Self::new(mass, 0.0, charge)
+    }
+
+    /// The uncharged Kerr black hole with the same mass and spin
+    pub fn kerr(&self) -> KerrBlackHole {
+        KerrBlackHole::new(self.mass, self.spin)
+    }
+
+    /// Calculate the outer event horizon radius r+ = M + √(M² - a² - Q²)
+    pub fn outer_horizon(&self) -> f32 {
+        self.mass + self.horizon_offset()
+    }
+
+    /// Calculate the inner horizon radius (Cauchy horizon) r- = M - √(M² - a² - Q²)
+    pub fn inner_horizon(&self) -> f32 {
+        if self.spin.abs() < 1e-10 && self.charge.abs() < 1e-10 {
+            // For Schwarzschild case, inner and outer horizons coincide
+            self.outer_horizon()
+        } else {
+            self.mass - self.horizon_offset()
+        }
+    }
+
+    /// Calculate the ergosphere radius at a given theta
+    pub fn ergosphere_radius(&self, theta: f32) -> f32 {
+        let a_cos_sq = self.spin * self.spin * theta.cos().powi(2);
+        self.mass + (self.mass * self.mass - a_cos_sq - self.charge * self.charge).max(0.0).sqrt()
+    }
+
+    /// Calculate the radius of the circular equatorial photon orbit
+    ///
+    /// `prograde` selects the orbit co-rotating with the hole. Without spin both orbits
+    /// coincide with the Reissner–Nordström photon sphere (3M + √(9M² - 8Q²)) / 2.
+    pub fn photon_orbit_radius(&self, prograde: bool) -> f32 {
+        let mass = self.mass;
+        let q_sq = self.charge * self.charge;
+        let spin_term = if prograde { 2.0 * self.spin.abs() } else { -2.0 * self.spin.abs() };
+
+        // Circular photon orbits satisfy r² - 3Mr + 2Q² ± 2a√(Mr - Q²) = 0, which is
+        // non-positive at the horizon and positive at 4M
+        let orbit = |r: f32| r * r - 3.0 * mass * r + 2.0 * q_sq + spin_term * (mass * r - q_sq).max(0.0).sqrt();
+        bisect(self.outer_horizon(), 4.0 * mass, |r| orbit(r) > 0.0)
+    }
+
+    /// Calculate the prograde ISCO (Innermost Stable Circular Orbit) radius
+    ///
+    /// Uses the closed-form Kerr result when uncharged, otherwise locates the radius
+    /// where circular orbits become marginally stable.
+    pub fn isco_radius(&self) -> f32 {
+        if self.charge == 0.0 {
+            return self.kerr().isco_radius();
+        }
+        self.marginally_stable_radius()
+    }
+
+    /// Find the innermost radius where the prograde circular orbit is stable
+    fn marginally_stable_radius(&self) -> f32 {
+        // Below the photon orbit there are no timelike circular orbits (NaN), and
+        // between it and the ISCO they are unstable; beyond 10M they are always stable
+        bisect(self.outer_horizon(), 10.0 * self.mass, |r| self.radial_potential_curvature(r) < 0.0)
+    }
+
+    /// Second derivative R''(r) of the equatorial radial potential for the circular orbit at r
+    ///
+    /// With R = [Er² - aX]² - Δ(r² + X²) and X = L - aE, the orbit is stable when R'' < 0.
+    fn radial_potential_curvature(&self, r: f32) -> f32 {
+        let (energy, angular_momentum) = self.circular_orbit(r);
+        let a = self.spin.abs();
+        let mass = self.mass;
+        let x = angular_momentum - a * energy;
+        let del = delta(r, mass, a, self.charge);
+
+        8.0 * energy * energy * r * r + 4.0 * energy * (energy * r * r - a * x)
+            - 2.0 * (r * r + x * x)
+            - 4.0 * r * (2.0 * r - 2.0 * mass)
+            - 2.0 * del
+    }
+
+    /// Specific energy and angular momentum of the prograde circular equatorial orbit at r
+    ///
+    /// Returns NaN inside the photon orbit, where no timelike circular orbit exists.
+    fn circular_orbit(&self, r: f32) -> (f32, f32) {
+        let a = self.spin.abs();
+        let q_sq = self.charge * self.charge;
+
+        // Equatorial Boyer-Lindquist metric with ψ = (2Mr - Q²)/r²
+        let psi = (2.0 * self.mass * r - q_sq) / (r * r);
+        let dpsi = -2.0 * self.mass / (r * r) + 2.0 * q_sq / (r * r * r);
+        let g_tt = -(1.0 - psi);
+        let g_tphi = -a * psi;
+        let g_phiphi = r * r + a * a + a * a * psi;
+
+        // Angular velocity from ∂_r g_tt + 2Ω ∂_r g_tφ + Ω² ∂_r g_φφ = 0
+        let omega = (a * dpsi + (-2.0 * r * dpsi).sqrt()) / (2.0 * r + a * a * dpsi);
+        let u_t = 1.0 / (-(g_tt + 2.0 * g_tphi * omega + g_phiphi * omega * omega)).sqrt();
+
+        let energy = -(g_tt + g_tphi * omega) * u_t;
+        let angular_momentum = (g_tphi + g_phiphi * omega) * u_t;
+        (energy, angular_momentum)
+    }
+
+    /// √(M² - a² - Q²), clamped at zero for extremal holes
+    fn horizon_offset(&self) -> f32 {
+        (self.mass * self.mass - self.spin * self.spin - self.charge * self.charge).max(0.0).sqrt()
+    }
+}
+
+/// Calculate Δ = r² - 2Mr + a² + Q²
+pub fn delta(r: f32, mass: f32, spin: f32, charge: f32) -> f32 {
+    kerr_schild::delta(r, mass, spin) + charge * charge
+}
+
+/// Calculate the Kerr-Schild metric components of a Kerr–Newman black hole
+///
+/// Identical to `kerr_schild::metric_components` with 2Mr replaced by 2Mr - Q².
+pub fn metric_components<T: Float>(r: T, theta: T, bh: &KerrNewmanBlackHole) -> [[T; 4]; 4] {
+    let one = T::ONE;
+    let spin: T = bh.spin.cast();
+    let sig = kerr_schild::sigma(r, theta, spin);
+    let sin_theta = theta.sin();
+
+    // Kerr-Schild potential f = (2Mr - Q²)/Σ
+    let source = (2.0 * bh.mass).cast::<T>() * r - (bh.charge * bh.charge).cast();
+    let potential = source / sig;
+
+    let mut g = [[T::ZERO; 4]; 4];
+
+    // g_tt
+    g[0][0] = -(one - potential);
+
+    // g_tr = g_rt (mixed time-radial) - only non-zero for Kerr-Schild
+    if bh.spin.abs() > 1e-10 {
+        g[0][1] = potential;
+        g[1][0] = g[0][1];
+    }
+
+    // g_tphi = g_phi_t (mixed time-azimuthal)
+    g[0][3] = -source * spin * sin_theta.powi(2) / sig;
+    g[3][0] = g[0][3];
+
+    // g_rr
+    if bh.spin.abs() > 1e-10 {
+        g[1][1] = one + potential;
+    } else {
+        // Reissner–Nordström case: g_rr = 1/(1 - 2M/r + Q²/r²)
+        g[1][1] = sig / (sig - source);
+    }
+
+    // g_rphi = g_phi_r (mixed radial-azimuthal)
+    g[1][3] = -spin * sin_theta.powi(2) * (one + potential);
+    g[3][1] = g[1][3];
+
+    // g_theta_theta
+    g[2][2] = sig;
+
+    // g_phi_phi
+    g[3][3] = sin_theta.powi(2) * (sig + spin * spin * sin_theta.powi(2) * (one + potential));
+
+    g
+}
+
+/// Check if a position is inside the outer event horizon
+pub fn is_inside_horizon(r: f32, bh: &KerrNewmanBlackHole) -> bool {
+    r <= bh.outer_horizon()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uncharged_matches_kerr() {
+        for spin in [0.0, 0.5, -0.9, 1.0] {
+            let kn = KerrNewmanBlackHole::new(1.0, spin, 0.0);
+            let kerr = KerrBlackHole::new(1.0, spin);
+
+            assert_eq!(kn.outer_horizon(), kerr.outer_horizon());
+            assert_eq!(kn.inner_horizon(), kerr.inner_horizon());
+            assert_eq!(kn.ergosphere_radius(0.4), kerr.ergosphere_radius(0.4));
+            assert_eq!(kn.isco_radius(), kerr.isco_radius());
+            assert_eq!(metric_components(4.0, 0.7, &kn), kerr_schild::metric_components(4.0, 0.7, &kerr));
+
+            // The numerical marginal-stability search agrees with the closed form away
+            // from the degenerate extremal limit
+            if spin.abs() == 1.0 {
+                continue;
+            }
+            let numerical = kn.marginally_stable_radius();
+            assert!((numerical - kerr.isco_radius()).abs() < 1e-3,
+                    "spin {}: {} vs {}", spin, numerical, kerr.isco_radius());
+        }
+    }
+
+    #[test]
+    fn test_reissner_nordstrom_radii() {
+        let mass = 1.0;
+        let charge = 0.6;
+        let rn = KerrNewmanBlackHole::reissner_nordstrom(mass, charge);
+
+        assert!((rn.outer_horizon() - 1.8).abs() < 1e-6);
+        assert!((rn.inner_horizon() - 0.2).abs() < 1e-6);
+
+        // Photon sphere (3M + √(9M² - 8Q²)) / 2, the same for both directions
+        let photon_sphere = 0.5 * (3.0 * mass + (9.0 * mass * mass - 8.0 * charge * charge).sqrt());
+        assert!((rn.photon_orbit_radius(true) - photon_sphere).abs() < 1e-5);
+        assert!((rn.photon_orbit_radius(false) - photon_sphere).abs() < 1e-5);
+
+        // ISCO is the root of Mr³ - 6M²r² + 9MQ²r - 4Q⁴ = 0
+        let r = rn.isco_radius();
+        let q_sq = charge * charge;
+        let residual = mass * r.powi(3) - 6.0 * mass * mass * r * r + 9.0 * mass * q_sq * r - 4.0 * q_sq * q_sq;
+        assert!(residual.abs() < 1e-3, "ISCO {} leaves residual {}", r, residual);
+
+        // Extremal Reissner–Nordström: ISCO at 4M
+        let extremal = KerrNewmanBlackHole::reissner_nordstrom(mass, mass);
+        assert!((extremal.isco_radius() - 4.0 * mass).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_kerr_photon_orbits() {
+        // Closed form for Kerr: r = 2M(1 + cos(⅔ arccos(∓a/M)))
+        let bh = KerrNewmanBlackHole::new(1.0, 0.6, 0.0);
+        let prograde = 2.0 * (1.0 + ((2.0 / 3.0) * (-0.6_f32).acos()).cos());
+        let retrograde = 2.0 * (1.0 + ((2.0 / 3.0) * 0.6_f32.acos()).cos());
+
+        assert!((bh.photon_orbit_radius(true) - prograde).abs() < 1e-4);
+        assert!((bh.photon_orbit_radius(false) - retrograde).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_charge_is_clamped() {
+        let bh = KerrNewmanBlackHole::new(1.0, 0.8, 0.9);
+        assert!((bh.charge - 0.6).abs() < 1e-6);
+        assert!((bh.max_charge() - 0.6).abs() < 1e-6);
+        assert_eq!(KerrNewmanBlackHole::new(1.0, 1.0, 0.5).charge, 0.0);
+        assert!((bh.outer_horizon() - 1.0).abs() < 1e-3);
+
+        // Charge shrinks the horizon and pulls the ISCO inward
+        let charged = KerrNewmanBlackHole::new(1.0, 0.5, 0.5);
+        let kerr = KerrBlackHole::new(1.0, 0.5);
+        assert!(charged.outer_horizon() < kerr.outer_horizon());
+        assert!(charged.isco_radius() < kerr.isco_radius());
+    }
+}
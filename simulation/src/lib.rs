@@ -1,70 +1,154 @@
 //! The simulation crate handles the heavy lifting of general relativity, 
 //! solving the geodesic equations to determine how light travels.
 
+pub mod blackbody;
+pub mod christoffel;
+pub mod constants;
+pub mod disk;
+pub mod dormand_prince;
+pub mod dual;
+pub mod elliptic;
+pub mod float;
+pub mod infall;
+pub mod jet;
+pub mod kerr_analytic;
+pub mod kerr_newman;
+pub mod metric;
+pub mod observer;
+pub mod outcome;
+pub mod potential;
+pub mod redshift;
+pub mod shadow;
+pub mod symplectic;
+pub mod tidal;
+pub mod timelike;
+pub mod torus;
+pub mod trajectory;
+pub mod wormhole;
+
+use boyer_lindquist::BoyerLindquist;
+use float::Float;
+use metric::{GeodesicTracer, Metric};
+use outcome::RayOutcome;
+use trajectory::TrajectoryRecorder;
+
 /// Represents a point in 4D spacetime with position and momentum
-#[derive(Debug, Clone, Copy)]
-pub struct Geodesic {
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Geodesic<T: Float = f32> {
     /// Position in spacetime (t, r, theta, phi)
-    pub position: [f32; 4],
+    pub position: [T; 4],
     /// Four-momentum (pt, pr, ptheta, pphi)  
-    pub momentum: [f32; 4],
+    pub momentum: [T; 4],
 }
 
-impl Geodesic {
+impl<T: Float> Geodesic<T> {
     /// Create a new geodesic state
-    pub fn new(position: [f32; 4], momentum: [f32; 4]) -> Self {
+    pub fn new(position: [T; 4], momentum: [T; 4]) -> Self {
         Self { position, momentum }
     }
     
     /// Get the radial distance from origin
-    pub fn radius(&self) -> f32 {
+    pub fn radius(&self) -> T {
         self.position[1]
     }
     
     /// Check if the photon has crossed the event horizon
-    pub fn is_inside_event_horizon(&self, mass: f32) -> bool {
-        self.radius() <= 2.0 * mass
+    pub fn is_inside_event_horizon(&self, mass: T) -> bool {
+        self.radius() <= T::from_f64(2.0) * mass
+    }
+    
+    /// Check that every position and momentum component is finite
+    pub fn is_finite(&self) -> bool {
+        self.position.iter().chain(&self.momentum).all(|x| x.is_finite())
+    }
+    
+    /// Add `factor * k` to every component of the state
+    pub(crate) fn add_scaled(&mut self, k: &Geodesic<T>, factor: T) {
+        for i in 0..4 {
+            self.position[i] += factor * k.position[i];
+            self.momentum[i] += factor * k.momentum[i];
+        }
+    }
+    
+    /// Largest absolute component of the state
+    pub(crate) fn max_abs(&self) -> T {
+        self.position.iter().chain(&self.momentum).fold(T::ZERO, |m, x| m.max(x.abs()))
     }
 }
 
+/// Adaptive one-step method that advances a geodesic state
+pub trait Integrator<T: Float = f32> {
+    /// Take one adaptive step, shrinking it until the error estimate is within tolerance
+    /// Returns (new_state, actual_step_size, suggested_next_step)
+    fn step(
+        &mut self,
+        state: Geodesic<T>,
+        step_size: T,
+        derivatives_fn: impl Fn(Geodesic<T>) -> Geodesic<T>,
+    ) -> (Geodesic<T>, T, T);
+}
+
 /// Adaptive RK45 integrator for geodesic equations
 #[derive(Debug, Clone)]
-pub struct AdaptiveRK45 {
+pub struct AdaptiveRK45<T: Float = f32> {
     /// Absolute tolerance for error control
-    pub abs_tolerance: f32,
+    pub abs_tolerance: T,
     /// Relative tolerance for error control
-    pub rel_tolerance: f32,
+    pub rel_tolerance: T,
     /// Minimum step size
-    pub min_step: f32,
+    pub min_step: T,
     /// Maximum step size
-    pub max_step: f32,
+    pub max_step: T,
     /// Safety factor for step size adjustment
-    pub safety_factor: f32,
+    pub safety_factor: T,
 }
 
-impl Default for AdaptiveRK45 {
+impl<T: Float> Default for AdaptiveRK45<T> {
     fn default() -> Self {
+        // Round-off of a coordinate near r ≈ 100M; asking for more than this is
+        // unreachable, so f32 gets looser defaults while f64 keeps 1e-6 and 1e-8
+        let resolution = T::from_f64(100.0) * T::EPSILON;
         Self {
-            abs_tolerance: 1e-6,
-            rel_tolerance: 1e-6,
-            min_step: 1e-8,
-            max_step: 1.0,
-            safety_factor: 0.9,
+            abs_tolerance: T::from_f64(1e-6).max(resolution),
+            rel_tolerance: T::from_f64(1e-6).max(T::from_f64(10.0) * T::EPSILON),
+            min_step: T::from_f64(1e-8).max(resolution),
+            max_step: T::ONE,
+            safety_factor: T::from_f64(0.9),
         }
     }
 }
 
-impl AdaptiveRK45 {
+impl<T: Float> AdaptiveRK45<T> {
     /// Take one adaptive step for geodesic integration
     /// Returns (new_state, actual_step_size, suggested_next_step)
     pub fn step(
         &self,
-        state: Geodesic,
-        step_size: f32,
-        derivatives_fn: impl Fn(Geodesic) -> Geodesic,
-    ) -> (Geodesic, f32, f32) {
-        let h = step_size.clamp(self.min_step, self.max_step);
-        
+        state: Geodesic<T>,
+        step_size: T,
+        derivatives_fn: impl Fn(Geodesic<T>) -> Geodesic<T>,
+    ) -> (Geodesic<T>, T, T) {
+        let mut h = step_size.clamp(self.min_step, self.max_step);
+        loop {
+            let (y5, within_tolerance, suggested_step) = self.attempt(state, h, &derivatives_fn);
+            
+            // Accept, or give up shrinking once the minimum step is reached
+            if within_tolerance || h <= self.min_step {
+                return (y5, h, suggested_step);
+            }
+            
+            // Retry with smaller step
+            h = suggested_step;
+        }
+    }
+    
+    /// Evaluate one Fehlberg step of size h
+    /// Returns (fifth_order_state, within_tolerance, suggested_next_step)
+    fn attempt(
+        &self,
+        state: Geodesic<T>,
+        h: T,
+        derivatives_fn: &impl Fn(Geodesic<T>) -> Geodesic<T>,
+    ) -> (Geodesic<T>, bool, T) {
         // RK45 Butcher tableau coefficients
         let a = [
             [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
@@ -73,88 +157,74 @@ impl AdaptiveRK45 {
             [1932.0/2197.0, -7200.0/2197.0, 7296.0/2197.0, 0.0, 0.0, 0.0],
             [439.0/216.0, -8.0, 3680.0/513.0, -845.0/4104.0, 0.0, 0.0],
             [-8.0/27.0, 2.0, -3544.0/2565.0, 1859.0/4104.0, -11.0/40.0, 0.0],
-        ];
+        ].map(|row| row.map(T::from_f64));
         
-        let b4 = [25.0/216.0, 0.0, 1408.0/2565.0, 2197.0/4104.0, -1.0/5.0, 0.0];
-        let b5 = [16.0/135.0, 0.0, 6656.0/12825.0, 28561.0/56430.0, -9.0/50.0, 2.0/55.0];
+        let b4 = [25.0/216.0, 0.0, 1408.0/2565.0, 2197.0/4104.0, -1.0/5.0, 0.0].map(T::from_f64);
+        let b5 = [16.0/135.0, 0.0, 6656.0/12825.0, 28561.0/56430.0, -9.0/50.0, 2.0/55.0].map(T::from_f64);
         
         // Calculate k values
         let k1 = derivatives_fn(state);
         
         let mut temp_state = state;
-        self.add_k_to_state(&mut temp_state, &k1, h * a[1][0]);
+        temp_state.add_scaled(&k1, h * a[1][0]);
         let k2 = derivatives_fn(temp_state);
         
         temp_state = state;
-        self.add_k_to_state(&mut temp_state, &k1, h * a[2][0]);
-        self.add_k_to_state(&mut temp_state, &k2, h * a[2][1]);
+        temp_state.add_scaled(&k1, h * a[2][0]);
+        temp_state.add_scaled(&k2, h * a[2][1]);
         let k3 = derivatives_fn(temp_state);
         
         temp_state = state;
-        self.add_k_to_state(&mut temp_state, &k1, h * a[3][0]);
-        self.add_k_to_state(&mut temp_state, &k2, h * a[3][1]);
-        self.add_k_to_state(&mut temp_state, &k3, h * a[3][2]);
+        temp_state.add_scaled(&k1, h * a[3][0]);
+        temp_state.add_scaled(&k2, h * a[3][1]);
+        temp_state.add_scaled(&k3, h * a[3][2]);
         let k4 = derivatives_fn(temp_state);
         
         temp_state = state;
-        self.add_k_to_state(&mut temp_state, &k1, h * a[4][0]);
-        self.add_k_to_state(&mut temp_state, &k2, h * a[4][1]);
-        self.add_k_to_state(&mut temp_state, &k3, h * a[4][2]);
-        self.add_k_to_state(&mut temp_state, &k4, h * a[4][3]);
+        temp_state.add_scaled(&k1, h * a[4][0]);
+        temp_state.add_scaled(&k2, h * a[4][1]);
+        temp_state.add_scaled(&k3, h * a[4][2]);
+        temp_state.add_scaled(&k4, h * a[4][3]);
         let k5 = derivatives_fn(temp_state);
         
         temp_state = state;
-        self.add_k_to_state(&mut temp_state, &k1, h * a[5][0]);
-        self.add_k_to_state(&mut temp_state, &k2, h * a[5][1]);
-        self.add_k_to_state(&mut temp_state, &k3, h * a[5][2]);
-        self.add_k_to_state(&mut temp_state, &k4, h * a[5][3]);
-        self.add_k_to_state(&mut temp_state, &k5, h * a[5][4]);
+        temp_state.add_scaled(&k1, h * a[5][0]);
+        temp_state.add_scaled(&k2, h * a[5][1]);
+        temp_state.add_scaled(&k3, h * a[5][2]);
+        temp_state.add_scaled(&k4, h * a[5][3]);
+        temp_state.add_scaled(&k5, h * a[5][4]);
         let k6 = derivatives_fn(temp_state);
         
         // 4th order solution
         let mut y4 = state;
-        self.add_k_to_state(&mut y4, &k1, h * b4[0]);
-        self.add_k_to_state(&mut y4, &k3, h * b4[2]);
-        self.add_k_to_state(&mut y4, &k4, h * b4[3]);
-        self.add_k_to_state(&mut y4, &k5, h * b4[4]);
+        y4.add_scaled(&k1, h * b4[0]);
+        y4.add_scaled(&k3, h * b4[2]);
+        y4.add_scaled(&k4, h * b4[3]);
+        y4.add_scaled(&k5, h * b4[4]);
         
         // 5th order solution
         let mut y5 = state;
-        self.add_k_to_state(&mut y5, &k1, h * b5[0]);
-        self.add_k_to_state(&mut y5, &k3, h * b5[2]);
-        self.add_k_to_state(&mut y5, &k4, h * b5[3]);
-        self.add_k_to_state(&mut y5, &k5, h * b5[4]);
-        self.add_k_to_state(&mut y5, &k6, h * b5[5]);
+        y5.add_scaled(&k1, h * b5[0]);
+        y5.add_scaled(&k3, h * b5[2]);
+        y5.add_scaled(&k4, h * b5[3]);
+        y5.add_scaled(&k5, h * b5[4]);
+        y5.add_scaled(&k6, h * b5[5]);
         
         // Error estimation
         let error = self.estimate_error(&y4, &y5);
         let tolerance = self.abs_tolerance + self.rel_tolerance * self.state_norm(&state);
         
         // Step size adjustment
-        let ratio = tolerance / error.max(1e-14);
-        let new_step = h * self.safety_factor * ratio.powf(0.2);
+        let ratio = tolerance / error.max(T::from_f64(1e-14));
+        let new_step = h * self.safety_factor * ratio.powf(T::from_f64(0.2));
         let suggested_step = new_step.clamp(self.min_step, self.max_step);
         
-        // Accept or reject step
-        if error <= tolerance {
-            (y5, h, suggested_step)
-        } else {
-            // Retry with smaller step
-            self.step(state, suggested_step, derivatives_fn)
-        }
-    }
-    
-    /// Add k vector to geodesic state
-    fn add_k_to_state(&self, state: &mut Geodesic, k: &Geodesic, factor: f32) {
-        for i in 0..4 {
-            state.position[i] += factor * k.position[i];
-            state.momentum[i] += factor * k.momentum[i];
-        }
+        (y5, error <= tolerance, suggested_step)
     }
     
     /// Estimate error between 4th and 5th order solutions
-    fn estimate_error(&self, y4: &Geodesic, y5: &Geodesic) -> f32 {
-        let mut max_error: f32 = 0.0;
+    fn estimate_error(&self, y4: &Geodesic<T>, y5: &Geodesic<T>) -> T {
+        let mut max_error = T::ZERO;
         
         for i in 0..4 {
             let pos_error = (y5.position[i] - y4.position[i]).abs();
@@ -166,8 +236,8 @@ impl AdaptiveRK45 {
     }
     
     /// Calculate norm of geodesic state for error scaling
-    fn state_norm(&self, state: &Geodesic) -> f32 {
-        let mut norm = 0.0;
+    fn state_norm(&self, state: &Geodesic<T>) -> T {
+        let mut norm = T::ZERO;
         
         for i in 0..4 {
             norm += state.position[i] * state.position[i];
@@ -178,336 +248,250 @@ impl AdaptiveRK45 {
     }
 }
 
+impl<T: Float> Integrator<T> for AdaptiveRK45<T> {
+    fn step(
+        &mut self,
+        state: Geodesic<T>,
+        step_size: T,
+        derivatives_fn: impl Fn(Geodesic<T>) -> Geodesic<T>,
+    ) -> (Geodesic<T>, T, T) {
+        AdaptiveRK45::step(self, state, step_size, derivatives_fn)
+    }
+}
+
 /// Ray tracing data structure for a light ray with Kerr geometry
+///
+/// A `GeodesicTracer` through the Boyer-Lindquist form of the metric does the
+/// integration; this type adds the Kerr initial conditions and constants of motion.
 #[derive(Debug, Clone)]
-pub struct KerrLightRay {
-    /// Current geodesic state
-    pub geodesic: Geodesic,
+pub struct KerrLightRay<T: Float = f32, I: Integrator<T> = AdaptiveRK45<T>> {
+    /// Tracer holding the geodesic state, integrator and step bookkeeping
+    pub tracer: GeodesicTracer<BoyerLindquist<T>, T, I>,
     /// Conserved quantities
-    pub conserved: ConservedQuantities,
-    /// Black hole parameters
-    pub black_hole: KerrBlackHole,
-    /// Adaptive integrator
-    pub integrator: AdaptiveRK45,
-    /// Current step size
-    pub step_size: f32,
-    /// Maximum number of integration steps
-    pub max_steps: u32,
-    /// Current step count
-    pub step_count: u32,
+    pub conserved: ConservedQuantities<T>,
 }
 
-impl KerrLightRay {
+impl<T: Float> KerrLightRay<T> {
     /// Create a new Kerr light ray from camera position and direction
-    pub fn new(camera_pos: [f32; 3], ray_dir: [f32; 3], black_hole: KerrBlackHole) -> Self {
-        // Convert to spherical coordinates
-        let r = (camera_pos[0] * camera_pos[0] + camera_pos[1] * camera_pos[1] + camera_pos[2] * camera_pos[2]).sqrt();
-        let theta = (camera_pos[2] / r).acos();
-        let phi = camera_pos[1].atan2(camera_pos[0]);
-        
-        // Initial position in spacetime (t, r, theta, phi)
-        let position = [0.0, r, theta, phi];
-        
-        // Convert ray direction to spherical coordinates for proper momentum initialization
-        let ray_len = (ray_dir[0] * ray_dir[0] + ray_dir[1] * ray_dir[1] + ray_dir[2] * ray_dir[2]).sqrt();
-        let ray_dir_norm = [ray_dir[0] / ray_len, ray_dir[1] / ray_len, ray_dir[2] / ray_len];
-        
-        // Convert Cartesian ray direction to spherical coordinate derivatives
-        let sin_theta = theta.sin();
-        let cos_theta = theta.cos();
-        let sin_phi = phi.sin();
-        let cos_phi = phi.cos();
-        
-        // Transformation from Cartesian to spherical derivatives
-        let dr_dt = ray_dir_norm[0] * sin_theta * cos_phi + ray_dir_norm[1] * sin_theta * sin_phi + ray_dir_norm[2] * cos_theta;
-        let dtheta_dt = if sin_theta > 1e-6 {
-            (ray_dir_norm[0] * cos_theta * cos_phi + ray_dir_norm[1] * cos_theta * sin_phi - ray_dir_norm[2] * sin_theta) / r
-        } else {
-            0.0
-        };
-        let dphi_dt = if sin_theta > 1e-6 {
-            (-ray_dir_norm[0] * sin_phi + ray_dir_norm[1] * cos_phi) / (r * sin_theta)
-        } else {
-            0.0
-        };
-        
-        // For a null geodesic, we need to solve for p_t using the null condition
-        // Start with spatial momentum components
-        let pr = dr_dt;
-        let ptheta = r * dtheta_dt; 
-        let pphi = r * sin_theta * dphi_dt;
+    ///
+    /// The spatial direction is projected onto the coordinate basis at the camera and the
+    /// time component is solved from the null condition g_μν k^μ k^ν = 0 using the full
+    /// metric from `kerr_schild::metric_components`. The stored momentum is the covariant
+    /// Boyer-Lindquist four-momentum (p_t, p_r, p_θ, p_φ) of that null vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the camera sits at the origin or inside the outer horizon, if the ray
+    /// direction is zero or not finite, or if no future-directed null vector exists.
+    pub fn new(camera_pos: [T; 3], ray_dir: [T; 3], black_hole: KerrBlackHole<T>) -> Self {
+        let (position, spatial) = metric::spherical_ray(camera_pos, ray_dir);
+        let r = position[1];
+        let theta = position[2];
         
-        // Calculate metric components (for future null condition implementation)
-        let _sigma = kerr_schild::sigma(r, theta, black_hole.spin);
-        let _delta = kerr_schild::delta(r, black_hole.mass, black_hole.spin);
-        let _a = black_hole.spin;
-        let _mass = black_hole.mass;
+        let sigma = kerr_schild::sigma(r, theta, black_hole.spin);
+        let delta = kerr_schild::delta(r, black_hole.mass, black_hole.spin);
+        assert!(delta > T::ZERO, "camera must be outside the outer event horizon");
         
-        // Solve for p_t from null condition: g_μν p^μ p^ν = 0
-        // This is complex for Kerr metric, so we'll use a simplified approach
-        // Set energy E = 1 and derive p_t = -E = -1
-        let pt = -1.0; // Energy = 1 for outgoing photon
+        // Complete the coordinate direction into a null vector k^μ
+        let g = kerr_schild::metric_components(r, theta, &black_hole);
+        let k = metric::future_null_vector(&g, spatial)
+            .expect("no future-directed null vector exists for this ray direction");
         
-        let momentum = [pt, pr, ptheta, pphi];
+        // Lower the index: p_t and p_φ are shared by the Kerr-Schild and Boyer-Lindquist
+        // charts, p_θ = Σ k^θ in both, and the Boyer-Lindquist p_r is (Σ/Δ) k^r
+        let p = metric::lower_index(&g, k);
+        let momentum = [p[0], sigma / delta * k[1], sigma * k[2], p[3]];
         
         let geodesic = Geodesic::new(position, momentum);
         let conserved = ConservedQuantities::from_initial_conditions(position, momentum, black_hole.mass, black_hole.spin);
         
         Self {
-            geodesic,
+            tracer: GeodesicTracer::from_geodesic(BoyerLindquist::new(black_hole), geodesic),
             conserved,
-            black_hole,
-            integrator: AdaptiveRK45::default(),
-            step_size: 0.1,
-            max_steps: 10000,
-            step_count: 0,
         }
     }
-    
-    /// Take one integration step along the geodesic using adaptive RK45
-    pub fn step(&mut self) -> bool {
-        if self.step_count >= self.max_steps {
-            return false;
+}
+
+impl<T: Float, I: Integrator<T>> KerrLightRay<T, I> {
+    /// Swap in a different stepping method, such as `DormandPrince54`
+    pub fn with_integrator<J: Integrator<T>>(self, integrator: J) -> KerrLightRay<T, J> {
+        KerrLightRay {
+            tracer: self.tracer.with_integrator(integrator),
+            conserved: self.conserved,
         }
-        
-        if kerr_schild::is_inside_horizon(self.geodesic.radius(), &self.black_hole) {
-            return false;
+    }
+    
+    /// Black hole the ray travels around
+    pub fn black_hole(&self) -> KerrBlackHole<T> {
+        self.tracer.metric.black_hole
+    }
+    
+    /// Switch to the past-directed null momentum with the same (p_r, p_θ, p_φ)
+    ///
+    /// The light a camera receives from the ray's direction is -p_μ of this ray, so tracing
+    /// it follows that light back to its source and L/E is the light's own λ. Rays that
+    /// collect light on the way, from a disk, a torus or a jet, start out this way, so each
+    /// emitter they meet sees that light as -p_μ where they meet. The new p_t
+    /// is the other root of H = 0, p_t' = -2 g^tφ p_φ / g^tt - p_t, which is -p_t only
+    /// without spin; frame dragging bends the past-directed ray differently. Rays already
+    /// heading into the past are left alone.
+    pub fn make_past_directed(&mut self) {
+        let geodesic = &mut self.tracer.geodesic;
+        let g_inv = self.tracer.metric.inverse_metric_components(geodesic.position);
+        let [p_t, _, _, p_phi] = geodesic.momentum;
+        if g_inv[0][0] * p_t + g_inv[0][3] * p_phi <= T::ZERO {
+            return;
         }
-        
-        let (new_state, _actual_step, next_step) = self.integrator.step(
-            self.geodesic,
-            self.step_size,
-            |state| self.compute_kerr_derivatives(state),
-        );
-        
-        self.geodesic = new_state;
-        self.step_size = next_step;
-        self.step_count += 1;
-        
-        true
+        geodesic.momentum[0] = -T::from_f64(2.0) * g_inv[0][3] * p_phi / g_inv[0][0] - p_t;
+        let black_hole = self.tracer.metric.black_hole;
+        self.conserved = ConservedQuantities::from_initial_conditions(geodesic.position, geodesic.momentum, black_hole.mass, black_hole.spin);
     }
     
-    /// Compute derivatives for Kerr geodesic equation using conserved quantities
-    /// This implements the first-order ODE system using energy, angular momentum, and Carter's constant
-    fn compute_kerr_derivatives(&self, state: Geodesic) -> Geodesic {
-        let r = state.position[1];
-        let theta = state.position[2];
-        let _phi = state.position[3];
-        
-        // Conserved quantities
-        let e = self.conserved.energy;
-        let lz = self.conserved.angular_momentum_z;
-        let q = self.conserved.carter_constant;
-        
-        let mass = self.black_hole.mass;
-        let spin = self.black_hole.spin;
-        
-        // Kerr metric functions
-        let sigma = kerr_schild::sigma(r, theta, spin);
-        let delta = kerr_schild::delta(r, mass, spin);
-        let sin_theta = theta.sin();
-        let cos_theta = theta.cos();
-        
-        // First-order ODE system for null geodesics
-        // Using conserved quantities to eliminate second derivatives
-        
-        // dt/dλ = (1/Σ) * [((r²+a²)/Δ) * P_r - a(aE*sin²θ - Lz)]
-        let p_r = e * (r * r + spin * spin) - spin * lz;
-        let dt_dlambda = (1.0 / sigma) * (((r * r + spin * spin) / delta) * p_r - spin * (spin * e * sin_theta.powi(2) - lz));
-        
-        // dr/dλ determined by radial equation of motion
-        // R(r) = [E(r² + a²) - aLz]² - Δ[μr² + (Lz - aE)² + Q]
-        let term1 = e * (r * r + spin * spin) - spin * lz;
-        let term2 = lz - spin * e;
-        let radial_potential = term1 * term1 - delta * (term2 * term2 + q);
-        
-        let dr_dlambda = if radial_potential >= 0.0 {
-            radial_potential.sqrt() / sigma
-        } else {
-            0.0 // Ray is turning around
-        };
-        
-        // dθ/dλ determined by polar equation of motion
-        // Θ(θ) = Q - cos²θ[a²(μ - E²) + L²z/sin²θ]
-        let theta_potential = q - cos_theta.powi(2) * (spin * spin * (0.0 - e * e) + lz * lz / sin_theta.powi(2));
-        
-        let dtheta_dlambda = if theta_potential >= 0.0 {
-            theta_potential.sqrt() / sigma
-        } else {
-            0.0 // Ray is turning around in theta
-        };
-        
-        // dφ/dλ = (1/Σ) * [(a/Δ) * P_r + (Lz/sin²θ - aE)]
-        let dphi_dlambda = (1.0 / sigma) * ((spin / delta) * p_r + (lz / sin_theta.powi(2) - spin * e));
-        
-        // Position derivatives (dx^μ/dλ)
-        let pos_deriv = [dt_dlambda, dr_dlambda, dtheta_dlambda, dphi_dlambda];
-        
-        // For null geodesics, momentum is proportional to position derivatives
-        // p_μ = g_μν dx^ν/dλ, but for our purposes we can use the same derivatives
-        let mom_deriv = pos_deriv;
-        
-        Geodesic::new(pos_deriv, mom_deriv)
+    /// Take one integration step along the geodesic; see `GeodesicTracer::step`
+    pub fn step(&mut self) -> RayOutcome<T> {
+        self.tracer.step()
+    }
+    
+    /// Step until the ray is captured, escapes, runs out of steps or fails
+    pub fn trace(&mut self) -> RayOutcome<T> {
+        self.tracer.trace()
+    }
+    
+    /// Trace the ray with `recorder` attached and hand back what it recorded
+    pub fn trace_recorded(&mut self, recorder: TrajectoryRecorder<T>) -> (RayOutcome<T>, TrajectoryRecorder<T>) {
+        self.tracer.trace_recorded(recorder)
+    }
+
+    /// Step until the ray meets `disk` or stops; see `disk::trace`
+    pub fn trace_disk(&mut self, disk: &disk::ThinDisk<T>) -> disk::DiskTrace<T> {
+        disk::trace(self, disk)
+    }
+
+    /// Trace the ray through `torus`, collecting its light; see `torus::trace`
+    pub fn trace_torus(&mut self, torus: &torus::PolishDoughnut<T>) -> torus::TorusImage<T> {
+        torus::trace(self, torus)
+    }
+
+    /// Trace the ray through `jet`, collecting its Doppler-boosted light; see `jet::trace`
+    pub fn trace_jet(&mut self, jet: &jet::Jet<T>) -> jet::JetImage<T> {
+        jet::trace(self, jet)
+    }
+    
+    /// Classify the current state without stepping
+    pub fn outcome(&self) -> RayOutcome<T> {
+        self.tracer.outcome()
+    }
+    
+    /// Value of the Hamiltonian H = ½ g^μν p_μ p_ν, which stays zero for a null ray
+    pub fn hamiltonian(&self) -> T {
+        self.tracer.hamiltonian()
+    }
+    
+    /// Rescale (p_r, p_θ) so that H = 0 again, leaving E and L_z untouched
+    pub fn project_to_null_shell(&mut self) {
+        self.tracer.project_to_null_shell();
     }
     
     /// Check if ray has escaped to infinity
     pub fn has_escaped(&self) -> bool {
-        self.geodesic.radius() > 100.0 * self.black_hole.mass
+        self.tracer.has_escaped()
     }
 }
 
-/// Ray tracing data structure for a light ray
+/// Ray tracing data structure for a light ray in Schwarzschild spacetime
+///
+/// A thin wrapper around a `GeodesicTracer` through the Schwarzschild metric.
 #[derive(Debug, Clone)]
 pub struct LightRay {
-    /// Current geodesic state
-    pub geodesic: Geodesic,
-    /// Black hole mass
-    pub mass: f32,
-    /// Integration step size
-    pub step_size: f32,
-    /// Maximum number of integration steps
-    pub max_steps: u32,
-    /// Current step count
-    pub step_count: u32,
+    /// Tracer holding the geodesic state, integrator and step bookkeeping
+    pub tracer: GeodesicTracer<schwarzschild::Schwarzschild>,
 }
 
 impl LightRay {
     /// Create a new light ray from camera position and direction
+    ///
+    /// The momentum is the covariant null four-momentum p_μ, with its time component
+    /// solved from the Schwarzschild null condition.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the camera sits at the origin or on the horizon, or if the ray direction
+    /// is zero or not finite.
     pub fn new(camera_pos: [f32; 3], ray_dir: [f32; 3], mass: f32) -> Self {
-        // Convert to spherical coordinates
-        let r = (camera_pos[0] * camera_pos[0] + camera_pos[1] * camera_pos[1] + camera_pos[2] * camera_pos[2]).sqrt();
-        let theta = (camera_pos[2] / r).acos();
-        let phi = camera_pos[1].atan2(camera_pos[0]);
-        
-        // Initial position in spacetime (t, r, theta, phi)
-        let position = [0.0, r, theta, phi];
-        
-        // Convert ray direction to four-momentum for null geodesic
-        // This is a simplified initialization - proper implementation needs more care
-        let momentum = [1.0, ray_dir[0], ray_dir[1], ray_dir[2]];
-        
         Self {
-            geodesic: Geodesic::new(position, momentum),
-            mass,
-            step_size: 0.01,
-            max_steps: 10000,
-            step_count: 0,
+            tracer: GeodesicTracer::new(schwarzschild::Schwarzschild::new(mass), camera_pos, ray_dir),
         }
     }
     
-    /// Take one integration step along the geodesic
-    pub fn step(&mut self) -> bool {
-        if self.step_count >= self.max_steps {
-            return false; // Max steps reached
-        }
-        
-        if self.geodesic.is_inside_event_horizon(self.mass) {
-            return false; // Fell into black hole
-        }
-        
-        // Simplified geodesic integration using Runge-Kutta 4th order
-        let k1 = self.compute_derivatives(self.geodesic);
-        
-        let mut temp_state = self.geodesic;
-        self.add_derivatives(&mut temp_state, &k1, self.step_size * 0.5);
-        let k2 = self.compute_derivatives(temp_state);
-        
-        temp_state = self.geodesic;
-        self.add_derivatives(&mut temp_state, &k2, self.step_size * 0.5);
-        let k3 = self.compute_derivatives(temp_state);
-        
-        temp_state = self.geodesic;
-        self.add_derivatives(&mut temp_state, &k3, self.step_size);
-        let k4 = self.compute_derivatives(temp_state);
-        
-        // Combine derivatives for RK4
-        let mut final_deriv_pos = [0.0; 4];
-        let mut final_deriv_mom = [0.0; 4];
-        for i in 0..4 {
-            final_deriv_pos[i] = (k1.position[i] + 2.0 * k2.position[i] + 2.0 * k3.position[i] + k4.position[i]) / 6.0;
-            final_deriv_mom[i] = (k1.momentum[i] + 2.0 * k2.momentum[i] + 2.0 * k3.momentum[i] + k4.momentum[i]) / 6.0;
-        }
-        
-        // Apply the final derivatives
-        for i in 0..4 {
-            self.geodesic.position[i] += final_deriv_pos[i] * self.step_size;
-            self.geodesic.momentum[i] += final_deriv_mom[i] * self.step_size;
-        }
-        self.step_count += 1;
-        
-        true
+    /// Take one integration step along the geodesic; see `GeodesicTracer::step`
+    pub fn step(&mut self) -> RayOutcome {
+        self.tracer.step()
     }
     
-    /// Compute derivatives for the geodesic equation
-    fn compute_derivatives(&self, state: Geodesic) -> Geodesic {
-        let r = state.position[1];
-        let theta = state.position[2];
-        
-        // Simplified derivatives for Schwarzschild metric in spherical coordinates
-        // d/dλ (position) = momentum
-        let pos_deriv = state.momentum;
-        
-        // d/dλ (momentum) = -Γ^μ_αβ p^α p^β (Christoffel symbols)
-        // This is a simplified version - full implementation needs all Christoffel symbols
-        let mut mom_deriv = [0.0; 4];
-        
-        // Some key Christoffel symbols for Schwarzschild metric
-        if r > 2.0 * self.mass {
-            let rs_over_r = 2.0 * self.mass / r;
-            
-            // Simplified radial equation of motion
-            mom_deriv[1] = -rs_over_r / (2.0 * r * r) * state.momentum[0] * state.momentum[0]
-                         + rs_over_r * (1.0 - rs_over_r) / (2.0 * r * r) * state.momentum[1] * state.momentum[1]
-                         + r * (1.0 - rs_over_r) * (state.momentum[2] * state.momentum[2] + theta.sin().powi(2) * state.momentum[3] * state.momentum[3]);
-        }
-        
-        Geodesic::new(pos_deriv, mom_deriv)
+    /// Step until the ray is captured, escapes, runs out of steps or fails
+    pub fn trace(&mut self) -> RayOutcome {
+        self.tracer.trace()
     }
     
-    /// Add derivatives to a geodesic state
-    fn add_derivatives(&self, state: &mut Geodesic, deriv: &Geodesic, step: f32) {
-        for i in 0..4 {
-            state.position[i] += deriv.position[i] * step;
-            state.momentum[i] += deriv.momentum[i] * step;
-        }
+    /// Trace the ray with `recorder` attached and hand back what it recorded
+    pub fn trace_recorded(&mut self, recorder: TrajectoryRecorder) -> (RayOutcome, TrajectoryRecorder) {
+        self.tracer.trace_recorded(recorder)
+    }
+    
+    /// Classify the current state without stepping
+    pub fn outcome(&self) -> RayOutcome {
+        self.tracer.outcome()
     }
     
     /// Check if ray has escaped to infinity
     pub fn has_escaped(&self) -> bool {
-        self.geodesic.radius() > 100.0 * self.mass // Far enough to consider "escaped"
+        self.tracer.has_escaped()
     }
 }
 
 /// Conserved quantities for photon geodesics in Kerr spacetime
 #[derive(Debug, Clone, Copy)]
-pub struct ConservedQuantities {
+pub struct ConservedQuantities<T: Float = f32> {
     /// Energy (E) - conserved quantity related to time translation symmetry
-    pub energy: f32,
+    pub energy: T,
     /// Axial angular momentum (Lz) - conserved quantity related to axial rotation symmetry
-    pub angular_momentum_z: f32,
+    pub angular_momentum_z: T,
     /// Carter's constant (Q) - fourth conserved quantity in Kerr spacetime
-    pub carter_constant: f32,
+    pub carter_constant: T,
+    /// Rest mass (μ) of the particle, with p_μ p^μ = -μ²; zero for photons
+    pub rest_mass: T,
 }
 
-impl ConservedQuantities {
-    /// Create new conserved quantities from initial conditions
+impl<T: Float> ConservedQuantities<T> {
+    /// Create new conserved quantities from the covariant photon four-momentum
+    /// (p_t, p_r, p_θ, p_φ) at a given position
     pub fn from_initial_conditions(
-        position: [f32; 4], 
-        momentum: [f32; 4], 
-        mass: f32, 
-        spin: f32
+        position: [T; 4], 
+        momentum: [T; 4], 
+        mass: T, 
+        spin: T
+    ) -> Self {
+        Self::from_initial_conditions_with_rest_mass(position, momentum, mass, spin, T::ZERO)
+    }
+    
+    /// Create new conserved quantities from the covariant four-momentum of a particle
+    /// with rest mass μ
+    pub fn from_initial_conditions_with_rest_mass(
+        position: [T; 4], 
+        momentum: [T; 4], 
+        _mass: T, 
+        spin: T,
+        rest_mass: T
     ) -> Self {
-        let r = position[1];
         let theta = position[2];
         let pt = momentum[0];
-        let _pr = momentum[1];
         let ptheta = momentum[2];
         let pphi = momentum[3];
         
-        // Calculate metric components for Kerr-Schild coordinates
-        let _delta = r * r - 2.0 * mass * r + spin * spin;
-        let _sigma = r * r + spin * spin * theta.cos().powi(2);
         let sin_theta = theta.sin();
+        // π/2 is not representable exactly, so snap the round-off in cos θ at the equator
+        let cos_theta = theta.cos();
+        let cos_theta_sq = if cos_theta.abs() < T::from_f64(1e-6) { T::ZERO } else { cos_theta * cos_theta };
         
         // Energy (E = -p_t in our sign convention)
         let energy = -pt;
@@ -515,54 +499,84 @@ impl ConservedQuantities {
         // Axial angular momentum (L_z = p_phi)
         let angular_momentum_z = pphi;
         
-        // Carter's constant calculation
+        // Carter's constant Q = p_θ² + cos²θ [L_z²/sin²θ + a²(μ² - E²)]
         // Handle the pole case where sin(theta) = 0
-        let carter_constant = if sin_theta.abs() < 1e-6 {
+        let spin_term = spin * spin * (rest_mass * rest_mass - energy * energy);
+        let carter_constant = if sin_theta.abs() < T::from_f64(1e-6) {
             // At the poles, L_z should be zero anyway, so the term becomes just p_theta^2
-            ptheta * ptheta + theta.cos().powi(2) * spin * spin * (energy * energy - 1.0)
+            ptheta * ptheta + cos_theta_sq * spin_term
         } else {
-            ptheta * ptheta + theta.cos().powi(2) * 
-                (spin * spin * (energy * energy - 1.0) + angular_momentum_z * angular_momentum_z / sin_theta.powi(2))
+            ptheta * ptheta + cos_theta_sq * 
+                (angular_momentum_z * angular_momentum_z / sin_theta.powi(2) + spin_term)
         };
         
         Self {
             energy,
             angular_momentum_z,
             carter_constant,
+            rest_mass,
         }
     }
+    
+    /// Radial potential R(r) = [E(r² + a²) - aL_z]² - Δ[μ²r² + (L_z - aE)² + Q]
+    ///
+    /// Along the geodesic (Σ dr/dλ)² = R(r), so R ≥ 0 wherever the particle can be.
+    pub fn radial_potential(&self, r: T, mass: T, spin: T) -> T {
+        let e = self.energy;
+        let lz = self.angular_momentum_z;
+        let mu = self.rest_mass;
+        let p_r = e * (r * r + spin * spin) - spin * lz;
+        let l_minus_ae = lz - spin * e;
+        p_r * p_r - kerr_schild::delta(r, mass, spin) * (mu * mu * r * r + l_minus_ae * l_minus_ae + self.carter_constant)
+    }
+    
+    /// Polar potential Θ(θ) = Q - cos²θ [a²(μ² - E²) + L_z²/sin²θ]
+    ///
+    /// Along the geodesic (Σ dθ/dλ)² = Θ(θ), so Θ ≥ 0 wherever the particle can be.
+    pub fn polar_potential(&self, theta: T, spin: T) -> T {
+        let sin_theta_sq = theta.sin().powi(2).max(T::from_f64(1e-12));
+        let e = self.energy;
+        let lz = self.angular_momentum_z;
+        let mu = self.rest_mass;
+        self.carter_constant - theta.cos().powi(2) * (spin * spin * (mu * mu - e * e) + lz * lz / sin_theta_sq)
+    }
 }
 
 /// Kerr black hole parameters in Kerr-Schild coordinates
 #[derive(Debug, Clone, Copy)]
-pub struct KerrBlackHole {
+pub struct KerrBlackHole<T: Float = f32> {
     /// Mass of the black hole in geometric units
-    pub mass: f32,
+    pub mass: T,
     /// Dimensionless spin parameter (a = J/Mc, where |a| ≤ M)
-    pub spin: f32,
+    pub spin: T,
 }
 
-impl KerrBlackHole {
+impl<T: Float> KerrBlackHole<T> {
     /// Create a new Kerr black hole
-    pub fn new(mass: f32, spin: f32) -> Self {
+    pub fn new(mass: T, spin: T) -> Self {
         // Ensure spin parameter is physical (|a| ≤ M)
         let spin = spin.clamp(-mass, mass);
         Self { mass, spin }
     }
     
     /// Create a Schwarzschild (non-spinning) black hole
-    pub fn schwarzschild(mass: f32) -> Self {
-        Self::new(mass, 0.0)
+    pub fn schwarzschild(mass: T) -> Self {
+        Self::new(mass, T::ZERO)
+    }
+    
+    /// The same black hole with its parameters in another number type
+    pub fn cast<U: Float>(&self) -> KerrBlackHole<U> {
+        KerrBlackHole { mass: self.mass.cast(), spin: self.spin.cast() }
     }
     
     /// Calculate the outer event horizon radius
-    pub fn outer_horizon(&self) -> f32 {
+    pub fn outer_horizon(&self) -> T {
         self.mass + (self.mass * self.mass - self.spin * self.spin).sqrt()
     }
     
     /// Calculate the inner horizon radius (Cauchy horizon)
-    pub fn inner_horizon(&self) -> f32 {
-        if self.spin.abs() < 1e-10 {
+    pub fn inner_horizon(&self) -> T {
+        if self.spin.abs() < T::from_f64(1e-10) {
             // For Schwarzschild case (spin ≈ 0), inner and outer horizons coincide
             self.outer_horizon()
         } else {
@@ -571,100 +585,249 @@ impl KerrBlackHole {
     }
     
     /// Calculate the ergosphere radius at a given theta
-    pub fn ergosphere_radius(&self, theta: f32) -> f32 {
+    pub fn ergosphere_radius(&self, theta: T) -> T {
         self.mass + (self.mass * self.mass - self.spin * self.spin * theta.cos().powi(2)).sqrt()
     }
     
     /// Calculate the ISCO (Innermost Stable Circular Orbit) radius
-    pub fn isco_radius(&self) -> f32 {
+    pub fn isco_radius(&self) -> T {
+        let one = T::ONE;
+        let three = T::from_f64(3.0);
+        let third = T::from_f64(1.0 / 3.0);
         let a = self.spin / self.mass;
-        let z1 = 1.0 + (1.0 - a * a).powf(1.0/3.0) * ((1.0 + a).powf(1.0/3.0) + (1.0 - a).powf(1.0/3.0));
-        let z2 = (3.0 * a * a + z1 * z1).sqrt();
+        let z1 = one + (one - a * a).powf(third) * ((one + a).powf(third) + (one - a).powf(third));
+        let z2 = (three * a * a + z1 * z1).sqrt();
         
-        self.mass * (3.0 + z2 - ((3.0 - z1) * (3.0 + z1 + 2.0 * z2)).sqrt())
+        self.mass * (three + z2 - ((three - z1) * (three + z1 + T::from_f64(2.0) * z2)).sqrt())
+    }
+    
+    /// Calculate the radius of the circular equatorial photon orbit
+    ///
+    /// `prograde` selects the orbit co-rotating with the hole, r = 2M(1 + cos(⅔ arccos(∓|a|/M))),
+    /// which runs from 3M without spin to M (prograde) or 4M (retrograde) at |a| = M.
+    pub fn photon_orbit_radius(&self, prograde: bool) -> T {
+        let two = T::from_f64(2.0);
+        let spin_ratio = self.spin.abs() / self.mass;
+        let spin_ratio = if prograde { -spin_ratio } else { spin_ratio };
+        two * self.mass * (T::ONE + (two / T::from_f64(3.0) * spin_ratio.acos()).cos())
+    }
+    
+    /// Calculate the conserved (ξ, η) = (L/E, Q/E²) of the spherical photon orbit at radius r
+    ///
+    /// Orbits exist for r between the two equatorial photon orbits, where η ≥ 0. Needs
+    /// a ≠ 0: without spin every orbit sits at 3M and only ξ² + η = 27M² is fixed.
+    pub fn spherical_photon_orbit(&self, r: T) -> (T, T) {
+        let (mass, a) = (self.mass, self.spin);
+        let three = T::from_f64(3.0);
+        let xi = -(r * r * r - three * mass * r * r + a * a * r + a * a * mass) / (a * (r - mass));
+        let eta = self.photon_shell_a_sq_eta(r) / (a * a);
+        (xi, eta)
+    }
+    
+    /// Calculate the Lyapunov exponent γ of the spherical photon orbit at radius r
+    ///
+    /// Nearby rays peel away from the orbit by a factor e^γ per half libration in θ, so
+    /// successive photon subrings are demagnified by e^-γ. Schwarzschild has γ = π.
+    pub fn lyapunov_exponent(&self, r: T) -> T {
+        let (mass, a) = (self.mass, self.spin);
+        let r_minus_m_sq = (r - mass) * (r - mass);
+        let delta = r * r - T::from_f64(2.0) * mass * r + a * a;
+        
+        // ξ² + η = (2r⁴ - 6M²r² + a²(r + M)²) / (r - M)², finite as a → 0
+        let total = (T::from_f64(2.0) * r.powi(4) - T::from_f64(6.0) * mass * mass * r * r
+            + a * a * (r + mass) * (r + mass)) / r_minus_m_sq;
+        // Rounding can push η just below zero at the equatorial orbits
+        let a_sq_eta = self.photon_shell_a_sq_eta(r).max(T::ZERO);
+        
+        // Polar turning points u± = cos²θ±, kept as a²u₋ and u₊/u₋ so a → 0 stays finite
+        let shifted = T::from_f64(0.5) * (a * a - total);
+        let a_sq_u_minus = shifted - (shifted * shifted + a_sq_eta).sqrt();
+        let parameter = -a_sq_eta / (a_sq_u_minus * a_sq_u_minus);
+        
+        let chi = T::ONE - mass * delta / (r * r_minus_m_sq);
+        T::from_f64(4.0) * r * chi.sqrt() / (-a_sq_u_minus).sqrt() * elliptic::complete_first_kind(parameter)
+    }
+    
+    /// a²η of the spherical photon orbit at radius r
+    fn photon_shell_a_sq_eta(&self, r: T) -> T {
+        let (mass, a) = (self.mass, self.spin);
+        let shell = r - T::from_f64(3.0) * mass;
+        r * r * r * (T::from_f64(4.0) * a * a * mass - r * shell * shell) / ((r - mass) * (r - mass))
+    }
+    
+    /// Calculate the shadow edge seen by a distant observer at the given inclination
+    ///
+    /// Samples `samples` spherical photon orbits on each half of the Bardeen curve;
+    /// see `shadow::critical_curve`.
+    pub fn shadow_curve(&self, inclination: T, samples: usize) -> shadow::ShadowCurve<T> {
+        shadow::critical_curve(self, inclination, samples)
     }
 }
 
 /// Kerr-Schild metric calculations
 pub mod kerr_schild {
+    use super::float::Float;
     use super::KerrBlackHole;
     
     /// Calculate Σ = r² + a²cos²θ
-    pub fn sigma(r: f32, theta: f32, spin: f32) -> f32 {
+    pub fn sigma<T: Float>(r: T, theta: T, spin: T) -> T {
         r * r + spin * spin * theta.cos().powi(2)
     }
     
     /// Calculate Δ = r² - 2Mr + a²
-    pub fn delta(r: f32, mass: f32, spin: f32) -> f32 {
-        r * r - 2.0 * mass * r + spin * spin
+    pub fn delta<T: Float>(r: T, mass: T, spin: T) -> T {
+        r * r - T::from_f64(2.0) * mass * r + spin * spin
     }
     
     /// Calculate A = (r² + a²)² - a²Δsin²θ
-    pub fn a_function(r: f32, theta: f32, mass: f32, spin: f32) -> f32 {
+    pub fn a_function<T: Float>(r: T, theta: T, mass: T, spin: T) -> T {
         let r2_plus_a2 = r * r + spin * spin;
         let sin_theta_sq = theta.sin().powi(2);
         r2_plus_a2 * r2_plus_a2 - spin * spin * delta(r, mass, spin) * sin_theta_sq
     }
     
     /// Calculate the Kerr-Schild metric components
-    pub fn metric_components(r: f32, theta: f32, bh: &KerrBlackHole) -> [[f32; 4]; 4] {
-        let mass = bh.mass;
+    pub fn metric_components<T: Float>(r: T, theta: T, bh: &KerrBlackHole<T>) -> [[T; 4]; 4] {
+        let one = T::ONE;
+        let two_m = T::from_f64(2.0) * bh.mass;
         let spin = bh.spin;
+        let min_spin = T::from_f64(1e-10);
         let sig = sigma(r, theta, spin);
-        let _del = delta(r, mass, spin);
-        let _a_func = a_function(r, theta, mass, spin);
+        let _del = delta(r, bh.mass, spin);
+        let _a_func = a_function(r, theta, bh.mass, spin);
         let sin_theta = theta.sin();
         let _cos_theta = theta.cos();
         
-        let mut g = [[0.0; 4]; 4];
+        let mut g = [[T::ZERO; 4]; 4];
         
         // g_tt
-        g[0][0] = -(1.0 - 2.0 * mass * r / sig);
+        g[0][0] = -(one - two_m * r / sig);
         
         // g_tr = g_rt (mixed time-radial) - only non-zero for Kerr-Schild
-        if spin.abs() > 1e-10 {
-            g[0][1] = 2.0 * mass * r / sig;
+        if spin.abs() > min_spin {
+            g[0][1] = two_m * r / sig;
             g[1][0] = g[0][1];
         }
         
         // g_tphi = g_phi_t (mixed time-azimuthal)
-        g[0][3] = -2.0 * mass * r * spin * sin_theta.powi(2) / sig;
+        g[0][3] = -two_m * r * spin * sin_theta.powi(2) / sig;
         g[3][0] = g[0][3];
         
         // g_rr
-        if spin.abs() > 1e-10 {
-            g[1][1] = 1.0 + 2.0 * mass * r / sig;
+        if spin.abs() > min_spin {
+            g[1][1] = one + two_m * r / sig;
         } else {
             // Schwarzschild case: g_rr = 1/(1-2M/r)
-            g[1][1] = sig / (sig - 2.0 * mass * r);
+            g[1][1] = sig / (sig - two_m * r);
         }
         
         // g_rphi = g_phi_r (mixed radial-azimuthal)
-        g[1][3] = -spin * sin_theta.powi(2) * (1.0 + 2.0 * mass * r / sig);
+        g[1][3] = -spin * sin_theta.powi(2) * (one + two_m * r / sig);
         g[3][1] = g[1][3];
         
         // g_theta_theta
         g[2][2] = sig;
         
         // g_phi_phi
-        g[3][3] = sin_theta.powi(2) * (sig + spin * spin * sin_theta.powi(2) * (1.0 + 2.0 * mass * r / sig));
+        g[3][3] = sin_theta.powi(2) * (sig + spin * spin * sin_theta.powi(2) * (one + two_m * r / sig));
         
         g
     }
     
     /// Check if a position is inside the outer event horizon
-    pub fn is_inside_horizon(r: f32, bh: &KerrBlackHole) -> bool {
+    pub fn is_inside_horizon<T: Float>(r: T, bh: &KerrBlackHole<T>) -> bool {
         r <= bh.outer_horizon()
     }
     
     /// Check if a position is in the ergosphere
-    pub fn is_in_ergosphere(r: f32, theta: f32, bh: &KerrBlackHole) -> bool {
+    pub fn is_in_ergosphere<T: Float>(r: T, theta: T, bh: &KerrBlackHole<T>) -> bool {
         r <= bh.ergosphere_radius(theta) && r > bh.outer_horizon()
     }
 }
 
+/// Boyer-Lindquist form of the Kerr metric, the chart `KerrLightRay` traces in
+pub mod boyer_lindquist {
+    use super::float::Float;
+    use super::kerr_schild::{a_function, delta, sigma};
+    use super::KerrBlackHole;
+    
+    /// Kerr spacetime in Boyer-Lindquist coordinates
+    #[derive(Debug, Clone, Copy)]
+    pub struct BoyerLindquist<T: Float = f32> {
+        /// Black hole parameters
+        pub black_hole: KerrBlackHole<T>,
+    }
+    
+    impl<T: Float> BoyerLindquist<T> {
+        /// Describe a Kerr black hole in Boyer-Lindquist coordinates
+        pub fn new(black_hole: KerrBlackHole<T>) -> Self {
+            Self { black_hole }
+        }
+    }
+    
+    /// sin²θ, kept away from zero so rays crossing the axis stay finite
+    fn sin_theta_sq<T: Float>(theta: T) -> T {
+        let min_sin = T::from_f64(1e-6);
+        let s = theta.sin();
+        let s = if s.abs() < min_sin { min_sin.copysign(s) } else { s };
+        s * s
+    }
+    
+    /// Calculate the Boyer-Lindquist metric components
+    pub fn metric_components<T: Float>(r: T, theta: T, bh: &KerrBlackHole<T>) -> [[T; 4]; 4] {
+        let spin = bh.spin;
+        let sig = sigma(r, theta, spin);
+        let sin_sq = sin_theta_sq(theta);
+        let two_mr = T::from_f64(2.0) * bh.mass * r;
+        
+        let mut g = [[T::ZERO; 4]; 4];
+        g[0][0] = -(T::ONE - two_mr / sig);
+        g[0][3] = -two_mr * spin * sin_sq / sig;
+        g[3][0] = g[0][3];
+        g[1][1] = sig / delta(r, bh.mass, spin);
+        g[2][2] = sig;
+        g[3][3] = (r * r + spin * spin + two_mr * spin * spin * sin_sq / sig) * sin_sq;
+        g
+    }
+    
+    /// Calculate the contravariant Boyer-Lindquist metric components in closed form
+    pub fn inverse_metric_components<T: Float>(r: T, theta: T, bh: &KerrBlackHole<T>) -> [[T; 4]; 4] {
+        let spin = bh.spin;
+        let sig = sigma(r, theta, spin);
+        let del = delta(r, bh.mass, spin);
+        let sin_sq = sin_theta_sq(theta);
+        let sigma_delta = sig * del;
+        
+        let mut g_inv = [[T::ZERO; 4]; 4];
+        g_inv[0][0] = -a_function(r, theta, bh.mass, spin) / sigma_delta;
+        g_inv[0][3] = -T::from_f64(2.0) * bh.mass * spin * r / sigma_delta;
+        g_inv[3][0] = g_inv[0][3];
+        g_inv[1][1] = del / sig;
+        g_inv[2][2] = T::ONE / sig;
+        g_inv[3][3] = (del - spin * spin * sin_sq) / (sigma_delta * sin_sq);
+        g_inv
+    }
+}
+
 /// Basic Schwarzschild metric calculations
 pub mod schwarzschild {
+    use super::elliptic::{complete_first_kind, incomplete_first_kind};
+    use super::float::Float;
+    
+    /// Schwarzschild spacetime in Schwarzschild coordinates
+    #[derive(Debug, Clone, Copy)]
+    pub struct Schwarzschild {
+        /// Mass of the black hole in geometric units
+        pub mass: f32,
+    }
+    
+    impl Schwarzschild {
+        /// Create a new Schwarzschild spacetime
+        pub fn new(mass: f32) -> Self {
+            Self { mass }
+        }
+    }
+    
     /// Calculate the metric coefficient g_tt (time-time component)
     /// g_tt = -(1 - 2M/r) in geometric units
     pub fn g_tt(mass: f32, r: f32) -> f32 {
@@ -689,6 +852,23 @@ pub mod schwarzschild {
         r * r * theta.sin().powi(2)
     }
     
+    /// Calculate all metric components g_μν at a position (t, r, θ, φ)
+    ///
+    /// Generic over `Float` so the same expression can be differentiated automatically.
+    pub fn metric<S: Float>(mass: f32, position: [S; 4]) -> [[S; 4]; 4] {
+        let one = S::ONE;
+        let r = position[1];
+        let theta = position[2];
+        let f = one - (2.0 * mass).cast::<S>() / r;
+        
+        let mut g = [[S::ZERO; 4]; 4];
+        g[0][0] = -f;
+        g[1][1] = one / f;
+        g[2][2] = r * r;
+        g[3][3] = r * r * theta.sin().powi(2);
+        g
+    }
+    
     /// Check if a position is inside the event horizon
     pub fn is_inside_event_horizon(mass: f32, r: f32) -> bool {
         r <= 2.0 * mass
@@ -703,6 +883,60 @@ pub mod schwarzschild {
             (1.0 - (2.0 * mass) / r).sqrt()
         }
     }
+    
+    /// Exact bending of a light ray that comes in from and returns to infinity
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct Deflection<T: Float = f32> {
+        /// Deflection angle α; the ray sweeps π + α in φ overall
+        pub angle: T,
+        /// Radius of closest approach
+        pub periapsis: T,
+        /// Number of complete loops around the hole, ⌊α / 2π⌋
+        pub windings: u32,
+    }
+    
+    /// Critical impact parameter 3√3 M below which light is captured
+    pub fn critical_impact_parameter<T: Float>(mass: T) -> T {
+        T::from_f64(27.0).sqrt() * mass
+    }
+    
+    /// Exact deflection of a photon with impact parameter b = L/E, or `None` if it is captured
+    ///
+    /// The periapsis P is the largest root of r³ - b²r + 2Mb² = 0. With
+    /// Q² = (P - 2M)(P + 6M), integrating the Binet equation (du/dφ)² = 2Mu³ - u² + 1/b²
+    /// gives Darwin's closed form α = 4√(P/Q) [K(m) - F(ζ | m)] - π, where
+    /// m = (Q - P + 6M) / 2Q and sin²ζ = (Q - P + 2M) / (Q - P + 6M).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mass is not positive.
+    pub fn deflection<T: Float>(mass: T, impact_parameter: T) -> Option<Deflection<T>> {
+        assert!(mass > T::ZERO, "mass must be positive");
+        let b = impact_parameter.abs();
+        if b <= critical_impact_parameter(mass) {
+            return None;
+        }
+        
+        let two_m = T::from_f64(2.0) * mass;
+        let six_m = T::from_f64(6.0) * mass;
+        let third = T::from_f64(1.0 / 3.0);
+        let sqrt_three = T::from_f64(3.0).sqrt();
+        
+        // Largest root of the depressed cubic, by the trigonometric method
+        let cos_arg = (-critical_impact_parameter(mass) / b).clamp(-T::ONE, T::ONE);
+        let periapsis = T::from_f64(2.0) * b / sqrt_three * (third * cos_arg.acos()).cos();
+        
+        let q = ((periapsis - two_m) * (periapsis + six_m)).sqrt();
+        let m = (q - periapsis + six_m) / (T::from_f64(2.0) * q);
+        let sin_zeta_sq = ((q - periapsis + two_m) / (q - periapsis + six_m)).clamp(T::ZERO, T::ONE);
+        let zeta = sin_zeta_sq.sqrt().asin();
+        
+        let angle = T::from_f64(4.0) * (periapsis / q).sqrt()
+            * (complete_first_kind(m) - incomplete_first_kind(zeta, m)) - T::PI;
+        let windings = (angle / (T::from_f64(2.0) * T::PI)).to_f64().floor().max(0.0) as u32;
+        
+        Some(Deflection { angle, periapsis, windings })
+    }
 }
 
 /// This is a placeholder.
@@ -747,16 +981,75 @@ mod tests {
     }
 
     #[test]
-    fn test_geodesic_creation() {
-        let pos = [0.0, 5.0, std::f32::consts::PI / 2.0, 0.0];
-        let mom = [1.0, 0.0, 0.0, 0.1];
-        let geodesic = Geodesic::new(pos, mom);
+    fn test_schwarzschild_exact_deflection() {
+        use super::schwarzschild::*;
         
-        assert_eq!(geodesic.radius(), 5.0);
-        assert!(!geodesic.is_inside_event_horizon(1.0));
-        assert!(geodesic.is_inside_event_horizon(3.0));
+        let mass = 1.0_f64;
+        let b_c = critical_impact_parameter(mass);
+        assert!(deflection(mass, b_c).is_none());
+        
+        // Weak field: α = 4M/b + 15πM²/4b² + O(M³/b³)
+        let b = 1.0e3;
+        let weak = deflection(mass, b).unwrap();
+        let expected = 4.0 * mass / b + 15.0 * std::f64::consts::PI * mass * mass / (4.0 * b * b);
+        assert!((weak.angle - expected).abs() < 1e-7, "α = {}, expected {}", weak.angle, expected);
+        assert!((weak.periapsis - (b - mass)).abs() < 1e-2);
+        assert_eq!(weak.windings, 0);
+        
+        // Strong field: α = -ln(b/b_c - 1) + ln(216(7 - 4√3)) - π + O(b - b_c)
+        let epsilon = 1e-8;
+        let strong = deflection(mass, b_c * (1.0 + epsilon)).unwrap();
+        let expected = -epsilon.ln() + (216.0 * (7.0 - 4.0 * 3.0_f64.sqrt())).ln() - std::f64::consts::PI;
+        assert!((strong.angle - expected).abs() < 1e-4, "α = {}, expected {}", strong.angle, expected);
+        assert!((strong.periapsis - 3.0 * mass).abs() < 1e-3);
+        assert_eq!(strong.windings, (strong.angle / (2.0 * std::f64::consts::PI)) as u32);
+        assert!(strong.windings >= 2);
+        
+        // The numerical tracer turns around where the closed form puts the periapsis
+        let mut ray = KerrLightRay::new([20.0, 0.0, 0.0], [-1.0, 0.35, 0.0], KerrBlackHole::<f64>::schwarzschild(mass))
+            .with_integrator(dormand_prince::DormandPrince54::default());
+        let b = ray.conserved.angular_momentum_z / ray.conserved.energy;
+        let mut turning_point = None;
+        while turning_point.is_none() && ray.step().is_continuing() {
+            turning_point = ray.tracer.integrator.dense_output().unwrap().find_crossing(|state| state.momentum[1]);
+        }
+        let traced = turning_point.expect("ray never reached periapsis").1.radius();
+        let exact = deflection(mass, b).unwrap();
+        assert!((traced - exact.periapsis).abs() < 1e-5 * exact.periapsis, "traced {}, exact {}", traced, exact.periapsis);
     }
-
+    
+    #[test]
+    fn test_lyapunov_exponent_sets_winding_rate() {
+        use super::kerr_analytic::AnalyticRay;
+        
+        // Each factor e^γ closer to the critical curve adds one half libration in θ,
+        // which takes 2K(u₊/u₋) / √(-u₋a²) of Mino time
+        let bh = KerrBlackHole::<f64>::new(1.0, 0.94);
+        let a = bh.spin;
+        let inclination = 1.2_f64;
+        let (prograde, retrograde) = (bh.photon_orbit_radius(true), bh.photon_orbit_radius(false));
+        for fraction in [0.15, 0.5, 0.8] {
+            let r = prograde + fraction * (retrograde - prograde);
+            let (xi, eta) = bh.spherical_photon_orbit(r);
+            let alpha = -xi / inclination.sin();
+            let beta = (eta + a * a * inclination.cos().powi(2) - xi * xi / inclination.tan().powi(2)).sqrt();
+            
+            let shifted = 0.5 * (a * a - xi * xi - eta);
+            let a_sq_u_minus = shifted - (shifted * shifted + a * a * eta).sqrt();
+            let parameter = -a * a * eta / (a_sq_u_minus * a_sq_u_minus);
+            let half_libration = 2.0 * elliptic::complete_first_kind(parameter) / (-a_sq_u_minus).sqrt();
+            
+            let mino_time = |offset: f64| {
+                AnalyticRay::from_image_plane(bh, alpha * (1.0 + offset), beta * (1.0 + offset), inclination)
+                    .unwrap()
+                    .total_mino_time()
+            };
+            let measured = 1e4_f64.ln() * half_libration / (mino_time(1e-8) - mino_time(1e-4));
+            let gamma = bh.lyapunov_exponent(r);
+            assert!((measured - gamma).abs() < 1e-3 * gamma, "r = {}: γ = {}, measured {}", r, gamma, measured);
+        }
+    }
+    
     #[test]
     fn test_light_ray_creation() {
         let camera_pos = [0.0, 0.0, 5.0];
@@ -764,9 +1057,9 @@ mod tests {
         let mass = 1.0;
         
         let ray = LightRay::new(camera_pos, ray_dir, mass);
-        assert_eq!(ray.mass, mass);
-        assert_eq!(ray.step_count, 0);
-        assert!(ray.geodesic.radius() > 0.0);
+        assert_eq!(ray.tracer.metric.mass, mass);
+        assert_eq!(ray.tracer.step_count, 0);
+        assert!(ray.tracer.geodesic.radius() > 0.0);
     }
 
     #[test]
@@ -780,285 +1073,536 @@ mod tests {
     }
     
     #[test]
-    fn test_kerr_black_hole_creation() {
-        let bh = KerrBlackHole::new(1.0, 0.5);
-        assert_eq!(bh.mass, 1.0);
-        assert_eq!(bh.spin, 0.5);
-        
-        // Test spin clamping
-        let bh_extreme = KerrBlackHole::new(1.0, 1.5); // Spin > mass
-        assert_eq!(bh_extreme.spin, 1.0); // Should be clamped to mass
-        
-        // Test Schwarzschild case
-        let bh_schwarzschild = KerrBlackHole::schwarzschild(2.0);
-        assert_eq!(bh_schwarzschild.mass, 2.0);
-        assert_eq!(bh_schwarzschild.spin, 0.0);
-    }
-    
-    #[test]
-    fn test_kerr_horizons() {
-        let bh = KerrBlackHole::new(1.0, 0.6);
-        
-        let outer = bh.outer_horizon();
-        let inner = bh.inner_horizon();
-        
-        assert!(outer > inner);
-        assert!(outer <= 2.0 * bh.mass); // Should be <= Schwarzschild radius
-        assert!(inner >= 0.0);
-        
-        // For Schwarzschild case (a=0), outer horizon should equal Schwarzschild radius
-        let bh_schwarzschild = KerrBlackHole::schwarzschild(1.0);
-        assert!((bh_schwarzschild.outer_horizon() - 2.0).abs() < 1e-6);
-    }
-    
-    #[test]
-    fn test_kerr_isco() {
-        let bh = KerrBlackHole::new(1.0, 0.5);
-        let isco = bh.isco_radius();
-        
-        // ISCO should be between the outer horizon and 6M (Schwarzschild ISCO)
-        assert!(isco > bh.outer_horizon());
-        assert!(isco <= 6.0 * bh.mass);
-        
-        // For Schwarzschild case, ISCO should be 6M
-        let bh_schwarzschild = KerrBlackHole::schwarzschild(1.0);
-        let isco_schwarzschild = bh_schwarzschild.isco_radius();
-        assert!((isco_schwarzschild - 6.0).abs() < 0.1); // Within reasonable tolerance
-    }
-    
-    #[test]
-    fn test_kerr_schild_metric_functions() {
-        use super::kerr_schild::*;
-        
+    fn test_light_ray_follows_full_geodesic() {
+        // Inward-angled ray from r = 10M that misses the photon sphere
         let mass = 1.0;
-        let spin = 0.5;
-        let r = 4.0;
-        let theta = std::f32::consts::PI / 2.0; // Equatorial plane
-        
-        let sig = sigma(r, theta, spin);
-        let del = delta(r, mass, spin);
+        let mut ray = LightRay::new([10.0, 0.0, 0.0], [-1.0, 1.0, 0.0], mass);
         
-        assert!(sig > 0.0);
-        assert!(del > 0.0); // Outside horizon
+        assert!(ray.tracer.hamiltonian().abs() < 1e-5);
         
-        // Test metric components
-        let bh = KerrBlackHole::new(mass, spin);
-        let g = metric_components(r, theta, &bh);
-        
-        // g_tt should be negative (timelike)
-        assert!(g[0][0] < 0.0);
-        
-        // g_rr should be positive (spacelike)
-        assert!(g[1][1] > 0.0);
-        
-        // g_theta_theta should be positive
-        assert!(g[2][2] > 0.0);
-        
-        // g_phi_phi should be positive
-        assert!(g[3][3] > 0.0);
+        // The ray first falls inward, then bounces off periapsis and escapes
+        let mut min_radius = ray.tracer.geodesic.radius();
+        while ray.step().is_continuing() {
+            min_radius = min_radius.min(ray.tracer.geodesic.radius());
+        }
+        assert!(min_radius < 9.0 && min_radius > 3.0, "periapsis at {}", min_radius);
+        assert!(ray.has_escaped());
+        assert!(ray.tracer.hamiltonian().abs() < 1e-3, "H drifted to {}", ray.tracer.hamiltonian());
     }
-    
+
     #[test]
-    fn test_conserved_quantities() {
-        let position = [0.0, 10.0, std::f32::consts::PI / 2.0, 0.0];
-        let momentum = [-1.0, 0.1, 0.0, 0.2]; // Negative p_t for positive energy
-        let mass = 1.0;
-        let spin = 0.3;
-        
-        let conserved = ConservedQuantities::from_initial_conditions(position, momentum, mass, spin);
-        
-        // Energy should be positive for escaping photons
-        assert!(conserved.energy > 0.0);
-        
-        // Angular momentum can be positive or negative
-        assert!(conserved.angular_momentum_z.is_finite());
-        
-        // Carter's constant should be non-negative
-        assert!(conserved.carter_constant >= 0.0);
+    fn test_default_tolerances_follow_precision() {
+        // f32 spacing near r = 40M is about 4e-6, so its tolerances must not ask for less
+        let single = AdaptiveRK45::<f32>::default();
+        assert!(single.abs_tolerance > 40.0 * f32::EPSILON);
+        assert!(40.0 + single.min_step > 40.0);
+
+        let double = AdaptiveRK45::<f64>::default();
+        assert_eq!(double.abs_tolerance, 1e-6);
+        assert_eq!(double.rel_tolerance, 1e-6);
+        assert_eq!(double.min_step, 1e-8);
     }
-    
+
     #[test]
-    fn test_kerr_light_ray_creation() {
-        let camera_pos = [0.0, 0.0, 10.0];
-        let ray_dir = [0.0, 0.0, -1.0]; // Towards black hole
-        let bh = KerrBlackHole::new(1.0, 0.5);
-        
-        let ray = KerrLightRay::new(camera_pos, ray_dir, bh);
-        
-        assert_eq!(ray.black_hole.mass, 1.0);
-        assert_eq!(ray.black_hole.spin, 0.5);
-        assert_eq!(ray.step_count, 0);
-        assert!(ray.geodesic.radius() > 0.0);
-        assert!(!ray.has_escaped()); // Starting at r=10, should not be escaped yet
+    fn test_rk45_rejection_stops_at_min_step() {
+        // An unreachable tolerance must settle on the minimum step instead of recursing forever
+        let integrator = AdaptiveRK45 { abs_tolerance: 0.0, rel_tolerance: 0.0, ..AdaptiveRK45::default() };
+        let state = Geodesic::new([0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 1.0, 0.0]);
+        let (new_state, step_used, _) = integrator.step(state, 0.5, |s: Geodesic| {
+            Geodesic::new([0.0, s.momentum[2], 0.0, 0.0], [0.0, 0.0, -s.position[1], 0.0])
+        });
+        
+        assert_eq!(step_used, integrator.min_step);
+        assert!(new_state.position[1].is_finite());
     }
-    
-    #[test]
-    fn test_adaptive_rk45_integrator() {
-        let integrator = AdaptiveRK45::default();
-        
-        // Test with a simple harmonic oscillator: d²x/dt² = -x
-        // Rewritten as first order: dx/dt = v, dv/dt = -x
-        let initial_state = Geodesic::new([0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 1.0, 0.0]); // x=1, v=0
-        
-        let derivatives_fn = |state: Geodesic| {
-            let x = state.position[1];
-            let v = state.momentum[2];
-            Geodesic::new([0.0, v, 0.0, 0.0], [0.0, 0.0, -x, 0.0])
+
+    /// Tests shared by both precisions, instantiated once for f32 and once for f64
+    macro_rules! precision_tests {
+        ($name:ident, $real:ty) => {
+            mod $name {
+                use crate::float::Float;
+                use crate::{kerr_schild, schwarzschild};
+                
+                type Real = $real;
+                type Geodesic = crate::Geodesic<Real>;
+                type AdaptiveRK45 = crate::AdaptiveRK45<Real>;
+                type ConservedQuantities = crate::ConservedQuantities<Real>;
+                type KerrBlackHole = crate::KerrBlackHole<Real>;
+                type KerrLightRay = crate::KerrLightRay<Real>;
+                
+                #[test]
+                fn test_geodesic_creation() {
+                    let pos = [0.0, 5.0, Real::PI / 2.0, 0.0];
+                    let mom = [1.0, 0.0, 0.0, 0.1];
+                    let geodesic = Geodesic::new(pos, mom);
+                
+                    assert_eq!(geodesic.radius(), 5.0);
+                    assert!(!geodesic.is_inside_event_horizon(1.0));
+                    assert!(geodesic.is_inside_event_horizon(3.0));
+                }
+                
+                #[test]
+                fn test_kerr_black_hole_creation() {
+                    let bh = KerrBlackHole::new(1.0, 0.5);
+                    assert_eq!(bh.mass, 1.0);
+                    assert_eq!(bh.spin, 0.5);
+                
+                    // Test spin clamping
+                    let bh_extreme = KerrBlackHole::new(1.0, 1.5); // Spin > mass
+                    assert_eq!(bh_extreme.spin, 1.0); // Should be clamped to mass
+                
+                    // Test Schwarzschild case
+                    let bh_schwarzschild = KerrBlackHole::schwarzschild(2.0);
+                    assert_eq!(bh_schwarzschild.mass, 2.0);
+                    assert_eq!(bh_schwarzschild.spin, 0.0);
+                }
+                
+                #[test]
+                fn test_kerr_horizons() {
+                    let bh = KerrBlackHole::new(1.0, 0.6);
+                
+                    let outer = bh.outer_horizon();
+                    let inner = bh.inner_horizon();
+                
+                    assert!(outer > inner);
+                    assert!(outer <= 2.0 * bh.mass); // Should be <= Schwarzschild radius
+                    assert!(inner >= 0.0);
+                
+                    // For Schwarzschild case (a=0), outer horizon should equal Schwarzschild radius
+                    let bh_schwarzschild = KerrBlackHole::schwarzschild(1.0);
+                    assert!((bh_schwarzschild.outer_horizon() - 2.0).abs() < 1e-6);
+                }
+                
+                #[test]
+                fn test_kerr_isco() {
+                    let bh = KerrBlackHole::new(1.0, 0.5);
+                    let isco = bh.isco_radius();
+                
+                    // ISCO should be between the outer horizon and 6M (Schwarzschild ISCO)
+                    assert!(isco > bh.outer_horizon());
+                    assert!(isco <= 6.0 * bh.mass);
+                
+                    // For Schwarzschild case, ISCO should be 6M
+                    let bh_schwarzschild = KerrBlackHole::schwarzschild(1.0);
+                    let isco_schwarzschild = bh_schwarzschild.isco_radius();
+                    assert!((isco_schwarzschild - 6.0).abs() < 0.1); // Within reasonable tolerance
+                }
+                
+                #[test]
+                fn test_kerr_photon_shell() {
+                    // Equatorial photon orbits: 3M without spin, M and 4M when extremal
+                    let schwarzschild = KerrBlackHole::schwarzschild(1.0);
+                    assert!((schwarzschild.photon_orbit_radius(true) - 3.0).abs() < 1e-5);
+                    assert!((schwarzschild.photon_orbit_radius(false) - 3.0).abs() < 1e-5);
+                    let extremal = KerrBlackHole::new(1.0, 1.0);
+                    assert!((extremal.photon_orbit_radius(true) - 1.0).abs() < 1e-5);
+                    assert!((extremal.photon_orbit_radius(false) - 4.0).abs() < 1e-5);
+                    assert!((schwarzschild.lyapunov_exponent(3.0) - Real::PI).abs() < 1e-5);
+                
+                    // Every orbit in the shell is a double root of the radial potential R(r),
+                    // and the equatorial ones have η = 0
+                    let bh = KerrBlackHole::new(1.0, 0.8);
+                    let (prograde, retrograde) = (bh.photon_orbit_radius(true), bh.photon_orbit_radius(false));
+                    let a = bh.spin;
+                    let potential = |r: Real, xi: Real, eta: Real| {
+                        (r * r + a * a - a * xi).powi(2) - kerr_schild::delta(r, bh.mass, a) * (eta + (xi - a).powi(2))
+                    };
+                    for fraction in [0.0, 0.3, 0.7, 1.0] {
+                        let r = prograde + fraction * (retrograde - prograde);
+                        let (xi, eta) = bh.spherical_photon_orbit(r);
+                        let h = 1e-2;
+                        let slope = (potential(r + h, xi, eta) - potential(r - h, xi, eta)) / (2.0 * h);
+                        assert!(potential(r, xi, eta).abs() < 1e-3, "R({}) = {}", r, potential(r, xi, eta));
+                        assert!(slope.abs() < 1e-2, "R'({}) = {}", r, slope);
+                        assert!(eta > -1e-3);
+                
+                        let gamma = bh.lyapunov_exponent(r);
+                        assert!(gamma.is_finite() && gamma > 0.0);
+                    }
+                    assert!(bh.spherical_photon_orbit(prograde).1.abs() < 1e-3);
+                    assert!(bh.spherical_photon_orbit(retrograde).1.abs() < 1e-3);
+                    assert!(bh.spherical_photon_orbit(prograde).0 > 0.0 && bh.spherical_photon_orbit(retrograde).0 < 0.0);
+                
+                    // Spin makes the inner part of the shell less unstable than Schwarzschild
+                    assert!(bh.lyapunov_exponent(0.5 * (prograde + retrograde)) < Real::PI);
+                }
+                
+                #[test]
+                fn test_kerr_schild_metric_functions() {
+                    use super::kerr_schild::*;
+                
+                    let mass = 1.0;
+                    let spin = 0.5;
+                    let r = 4.0;
+                    let theta = Real::PI / 2.0; // Equatorial plane
+                
+                    let sig = sigma(r, theta, spin);
+                    let del = delta(r, mass, spin);
+                
+                    assert!(sig > 0.0);
+                    assert!(del > 0.0); // Outside horizon
+                
+                    // Test metric components
+                    let bh = KerrBlackHole::new(mass, spin);
+                    let g = metric_components(r, theta, &bh);
+                
+                    // g_tt should be negative (timelike)
+                    assert!(g[0][0] < 0.0);
+                
+                    // g_rr should be positive (spacelike)
+                    assert!(g[1][1] > 0.0);
+                
+                    // g_theta_theta should be positive
+                    assert!(g[2][2] > 0.0);
+                
+                    // g_phi_phi should be positive
+                    assert!(g[3][3] > 0.0);
+                }
+                
+                #[test]
+                fn test_conserved_quantities() {
+                    let position = [0.0, 10.0, Real::PI / 2.0, 0.0];
+                    let momentum = [-1.0, 0.1, 0.0, 0.2]; // Negative p_t for positive energy
+                    let mass = 1.0;
+                    let spin = 0.3;
+                
+                    let conserved = ConservedQuantities::from_initial_conditions(position, momentum, mass, spin);
+                
+                    // Energy should be positive for escaping photons
+                    assert!(conserved.energy > 0.0);
+                
+                    // Angular momentum can be positive or negative
+                    assert!(conserved.angular_momentum_z.is_finite());
+                
+                    // Carter's constant should be non-negative
+                    assert!(conserved.carter_constant >= 0.0);
+                }
+                
+                #[test]
+                fn test_kerr_light_ray_creation() {
+                    let camera_pos = [0.0, 0.0, 10.0];
+                    let ray_dir = [0.0, 0.0, -1.0]; // Towards black hole
+                    let bh = KerrBlackHole::new(1.0, 0.5);
+                
+                    let ray = KerrLightRay::new(camera_pos, ray_dir, bh);
+                
+                    assert_eq!(ray.black_hole().mass, 1.0);
+                    assert_eq!(ray.black_hole().spin, 0.5);
+                    assert_eq!(ray.tracer.step_count, 0);
+                    assert!(ray.tracer.geodesic.radius() > 0.0);
+                    assert!(!ray.has_escaped()); // Starting at r=10, should not be escaped yet
+                }
+                
+                #[test]
+                fn test_kerr_light_ray_initial_momentum_is_null() {
+                    let bh = KerrBlackHole::new(1.0, 0.9);
+                    let camera_pos = [6.0, 2.0, 3.0];
+                    let ray_dir = [-1.0, 0.4, -0.2];
+                
+                    let ray = KerrLightRay::new(camera_pos, ray_dir, bh);
+                    let r = ray.tracer.geodesic.position[1];
+                    let theta = ray.tracer.geodesic.position[2];
+                    let sigma = kerr_schild::sigma(r, theta, bh.spin);
+                    let delta = kerr_schild::delta(r, bh.mass, bh.spin);
+                
+                    // Conserved quantities come straight from the covariant momentum
+                    assert_eq!(ray.conserved.energy, -ray.tracer.geodesic.momentum[0]);
+                    assert_eq!(ray.conserved.angular_momentum_z, ray.tracer.geodesic.momentum[3]);
+                    assert!(ray.conserved.energy > 0.0);
+                
+                    // (Σ dr/dλ)² = R(r) and (Σ dθ/dλ)² = Θ(θ) hold only for a null vector
+                    let sigma_dr = delta * ray.tracer.geodesic.momentum[1];
+                    let sigma_dtheta = ray.tracer.geodesic.momentum[2];
+                    let radial = ray.conserved.radial_potential(r, bh.mass, bh.spin);
+                    let polar = ray.conserved.polar_potential(theta, bh.spin);
+                    assert!((radial - sigma_dr * sigma_dr).abs() < 1e-3 * sigma * sigma, "R(r) = {}, (Σṙ)² = {}", radial, sigma_dr * sigma_dr);
+                    assert!((polar - sigma_dtheta * sigma_dtheta).abs() < 1e-3 * sigma, "Θ(θ) = {}, (Σθ̇)² = {}", polar, sigma_dtheta * sigma_dtheta);
+                }
+                
+                #[test]
+                fn test_kerr_light_ray_schwarzschild_energy() {
+                    // A purely radial photon in Schwarzschild has p_r = E / (1 - 2M/r)
+                    let bh = KerrBlackHole::schwarzschild(1.0);
+                    let ray = KerrLightRay::new([10.0, 0.0, 0.0], [-1.0, 0.0, 0.0], bh);
+                
+                    let e = ray.conserved.energy;
+                    assert!(e > 0.0);
+                    assert!((ray.tracer.geodesic.momentum[1] + e / (1.0 - 0.2)).abs() < 1e-5);
+                    assert!(ray.conserved.angular_momentum_z.abs() < 1e-6);
+                    assert!(ray.conserved.carter_constant.abs() < 1e-6);
+                }
+                
+                #[test]
+                fn test_kerr_light_ray_past_directed() {
+                    // Without spin the past-directed ray only flips E, so λ = L/E changes sign
+                    let camera_pos = [6.0, 2.0, 3.0];
+                    let ray_dir = [-1.0, 0.4, -0.2];
+                    let ray = KerrLightRay::new(camera_pos, ray_dir, KerrBlackHole::schwarzschild(1.0));
+                    let mut past = ray.clone();
+                    past.make_past_directed();
+                    assert!((past.conserved.energy + ray.conserved.energy).abs() < 1e-6);
+                    assert_eq!(past.conserved.angular_momentum_z, ray.conserved.angular_momentum_z);
+                
+                    // With spin it is the other null root, not just E reversed
+                    let ray = KerrLightRay::new(camera_pos, ray_dir, KerrBlackHole::new(1.0, 0.9));
+                    let mut past = ray.clone();
+                    past.make_past_directed();
+                    assert_eq!(past.tracer.geodesic.momentum[1..], ray.tracer.geodesic.momentum[1..]);
+                    assert!(past.hamiltonian().abs() < 1e-5);
+                    assert!(past.conserved.energy < 0.0);
+                    assert!((past.conserved.energy + ray.conserved.energy).abs() > 1e-3);
+                    let momentum = past.tracer.geodesic.momentum;
+                    past.make_past_directed();
+                    assert_eq!(past.tracer.geodesic.momentum, momentum);
+                }
+                
+                #[test]
+                #[should_panic(expected = "ray direction must be finite and non-zero")]
+                fn test_kerr_light_ray_rejects_zero_direction() {
+                    KerrLightRay::new([10.0, 0.0, 0.0], [0.0, 0.0, 0.0], KerrBlackHole::new(1.0, 0.5));
+                }
+                
+                #[test]
+                #[should_panic(expected = "ray direction must be finite and non-zero")]
+                fn test_kerr_light_ray_rejects_nan_direction() {
+                    KerrLightRay::new([10.0, 0.0, 0.0], [Real::NAN, 1.0, 0.0], KerrBlackHole::new(1.0, 0.5));
+                }
+                
+                #[test]
+                fn test_kerr_light_ray_passes_periapsis() {
+                    // A photon with b > 3√3 M dips to periapsis and climbs back out
+                    let bh = KerrBlackHole::schwarzschild(1.0);
+                    let mut ray = KerrLightRay::new([20.0, 0.0, 0.0], [-1.0, 0.35, 0.0], bh);
+                    let b = ray.conserved.angular_momentum_z / ray.conserved.energy;
+                    assert!(b > 3.0 * (3.0 as Real).sqrt());
+                
+                    let mut min_r = ray.tracer.geodesic.radius();
+                    while ray.step().is_continuing() {
+                        min_r = min_r.min(ray.tracer.geodesic.radius());
+                    }
+                
+                    assert!(ray.has_escaped(), "ray stalled at r = {}", ray.tracer.geodesic.radius());
+                    assert!(ray.tracer.geodesic.momentum[1] > 0.0, "ray should be outgoing after periapsis");
+                
+                    // Periapsis satisfies b² = r³ / (r - 2M)
+                    let expected_b_sq = min_r.powi(3) / (min_r - 2.0);
+                    assert!((expected_b_sq - b * b).abs() < 1e-2 * b * b, "min r = {}, b = {}", min_r, b);
+                }
+                
+                #[test]
+                fn test_kerr_light_ray_polar_turning_point() {
+                    // A ray launched upwards from the equator must reach a polar turning point and come back
+                    let bh = KerrBlackHole::new(1.0, 0.9);
+                    let mut ray = KerrLightRay::new([15.0, 0.0, 0.0], [-0.3, 1.0, 0.4], bh);
+                    let initial_q = ray.conserved.carter_constant;
+                    assert!(initial_q > 0.0);
+                
+                    let mut min_theta = ray.tracer.geodesic.position[2];
+                    while ray.step().is_continuing() {
+                        min_theta = min_theta.min(ray.tracer.geodesic.position[2]);
+                    }
+                
+                    assert!(ray.has_escaped());
+                    assert!(min_theta < 1.4, "ray never left the equatorial band");
+                    assert!(ray.tracer.geodesic.momentum[2] > 0.0 || ray.tracer.geodesic.position[2] > min_theta + 0.05,
+                            "θ should turn around after the polar turning point");
+                
+                    // The evolved p_θ must stay consistent with the conserved Carter constant
+                    let final_q = ConservedQuantities::from_initial_conditions(
+                        ray.tracer.geodesic.position, ray.tracer.geodesic.momentum, bh.mass, bh.spin).carter_constant;
+                    assert!((final_q - initial_q).abs() < 1e-2 * initial_q, "Q drifted from {} to {}", initial_q, final_q);
+                }
+                
+                #[test]
+                fn test_kerr_prograde_retrograde_deflection() {
+                    // Frame dragging makes prograde and retrograde photons bend by different amounts
+                    let bh = KerrBlackHole::new(1.0, 0.99);
+                    let deflection = |dir_y: Real| {
+                        let mut ray = KerrLightRay::new([30.0, 0.0, 0.0], [-1.0, dir_y, 0.0], bh);
+                        ray.trace();
+                        assert!(ray.has_escaped(), "ray with dir_y = {} was captured", dir_y);
+                        (ray.tracer.geodesic.position[3] - ray.conserved.angular_momentum_z.signum() * Real::PI).abs()
+                    };
+                
+                    let prograde = deflection(0.3);
+                    let retrograde = deflection(-0.3);
+                    assert!((prograde - retrograde).abs() > 1e-2,
+                            "prograde {} vs retrograde {}", prograde, retrograde);
+                }
+                
+                #[test]
+                fn test_adaptive_rk45_integrator() {
+                    let integrator = AdaptiveRK45::default();
+                
+                    // Test with a simple harmonic oscillator: d²x/dt² = -x
+                    // Rewritten as first order: dx/dt = v, dv/dt = -x
+                    let initial_state = Geodesic::new([0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 1.0, 0.0]); // x=1, v=0
+                
+                    let derivatives_fn = |state: Geodesic| {
+                        let x = state.position[1];
+                        let v = state.momentum[2];
+                        Geodesic::new([0.0, v, 0.0, 0.0], [0.0, 0.0, -x, 0.0])
+                    };
+                
+                    let (new_state, step_used, next_step) = integrator.step(initial_state, 0.1, derivatives_fn);
+                
+                    assert!(step_used > 0.0);
+                    assert!(next_step > 0.0);
+                    assert!(new_state.position[1].is_finite()); // Position should be finite
+                    assert!(new_state.momentum[2].is_finite()); // Velocity should be finite
+                }
+                
+                #[test]
+                fn test_kerr_vs_schwarzschild_limit() {
+                    // Test that Kerr reduces to Schwarzschild when spin = 0
+                    let mass = 1.0;
+                    let r = 5.0;
+                    let theta = Real::PI / 2.0;
+                
+                    let bh_kerr = KerrBlackHole::new(mass, 0.0); // Zero spin
+                    let g_kerr = kerr_schild::metric_components(r, theta, &bh_kerr);
+                
+                    // Compare with Schwarzschild metric components
+                    let g_tt_schwarzschild = schwarzschild::g_tt(mass as f32, r as f32) as Real;
+                    let g_rr_schwarzschild = schwarzschild::g_rr(mass as f32, r as f32) as Real;
+                    let g_theta_theta_schwarzschild = schwarzschild::g_theta_theta(r as f32) as Real;
+                    let g_phi_phi_schwarzschild = schwarzschild::g_phi_phi(r as f32, theta as f32) as Real;
+                
+                    // Allow for small numerical differences
+                    assert!((g_kerr[0][0] - g_tt_schwarzschild).abs() < 1e-6);
+                    assert!((g_kerr[1][1] - g_rr_schwarzschild).abs() < 1e-6);
+                    assert!((g_kerr[2][2] - g_theta_theta_schwarzschild).abs() < 1e-6);
+                    assert!((g_kerr[3][3] - g_phi_phi_schwarzschild).abs() < 1e-6);
+                
+                    // Off-diagonal terms should be zero for Schwarzschild
+                    assert!(g_kerr[0][1].abs() < 1e-6);
+                    assert!(g_kerr[0][3].abs() < 1e-6);
+                    assert!(g_kerr[1][3].abs() < 1e-6);
+                }
+                
+                #[test]
+                fn test_kerr_schwarzschild_horizon_compatibility() {
+                    // Verify that Kerr with spin=0 gives identical results to Schwarzschild
+                    let mass = 2.0;
+                
+                    // Create Kerr black hole with zero spin
+                    let kerr_bh = KerrBlackHole::new(mass, 0.0);
+                
+                    // Test that horizons match Schwarzschild
+                    let kerr_horizon = kerr_bh.outer_horizon();
+                    let schwarzschild_radius = 2.0 * mass;
+                
+                    assert!((kerr_horizon - schwarzschild_radius).abs() < 1e-10);
+                
+                    // Inner horizon should equal outer horizon for non-spinning case
+                    let inner_horizon = kerr_bh.inner_horizon();
+                    assert!((inner_horizon - kerr_horizon).abs() < 1e-10);
+                
+                    // ISCO should be at 6M for Schwarzschild
+                    let isco = kerr_bh.isco_radius();
+                    assert!((isco - 6.0 * mass).abs() < 0.1);
+                }
+                
+                #[test]
+                fn test_kerr_light_ray_schwarzschild_consistency() {
+                    // Test that KerrLightRay with spin=0 behaves like Schwarzschild
+                    let camera_pos = [0.0, 0.0, 10.0];
+                    let ray_dir = [0.0, 0.0, -1.0];
+                
+                    // Create both types of black holes
+                    let kerr_bh = KerrBlackHole::schwarzschild(1.0);
+                    let kerr_ray = KerrLightRay::new(camera_pos, ray_dir, kerr_bh);
+                
+                    // Verify the black hole parameters are correct
+                    assert_eq!(kerr_ray.black_hole().mass, 1.0);
+                    assert_eq!(kerr_ray.black_hole().spin, 0.0);
+                
+                    // Test that horizon detection works the same
+                    let horizon_radius = kerr_ray.black_hole().outer_horizon();
+                    assert!((horizon_radius - 2.0).abs() < 1e-10);
+                
+                    // Test conserved quantities initialization
+                    assert!(kerr_ray.conserved.energy.is_finite());
+                    assert!(kerr_ray.conserved.angular_momentum_z.is_finite());
+                    assert!(kerr_ray.conserved.carter_constant >= 0.0);
+                }
+                
+                #[test]
+                fn test_frame_dragging_effect() {
+                    // Test that corrected Kerr geodesics show frame-dragging
+                    let mass = 1.0;
+                    let high_spin = 0.99 * mass; // Near-maximal spin
+                    let no_spin = 0.0;
+                
+                    // Camera position much closer to black hole for stronger effect
+                    let camera_pos = [5.0, 0.0, 0.0]; // At r=5M, theta=π/2 - very close to ISCO
+                
+                    // Ray direction highly tangential for maximum frame-dragging
+                    let ray_dir = [-0.1, 1.0, 0.0]; // Very slightly inward but mostly tangential
+                
+                    // Create black holes
+                    let bh_spinning = KerrBlackHole::new(mass, high_spin);
+                    let bh_nonspinning = KerrBlackHole::new(mass, no_spin);
+                
+                    // Create light rays
+                    let mut ray_spinning = KerrLightRay::new(camera_pos, ray_dir, bh_spinning);
+                    let mut ray_nonspinning = KerrLightRay::new(camera_pos, ray_dir, bh_nonspinning);
+                
+                    let initial_phi_spinning = ray_spinning.tracer.geodesic.position[3];
+                    let initial_phi_nonspinning = ray_nonspinning.tracer.geodesic.position[3];
+                
+                    // Integrate both rays for a fixed number of steps
+                    let max_steps = 200;
+                    let mut steps_spinning = 0;
+                    let mut steps_nonspinning = 0;
+                
+                    // Integrate spinning ray
+                    while steps_spinning < max_steps && ray_spinning.step().is_continuing() {
+                        steps_spinning += 1;
+                
+                        // Stop if too close to horizon or escaped
+                        if ray_spinning.tracer.geodesic.radius() < 2.0 * mass || ray_spinning.has_escaped() {
+                            break;
+                        }
+                    }
+                
+                    // Integrate non-spinning ray
+                    while steps_nonspinning < max_steps && ray_nonspinning.step().is_continuing() {
+                        steps_nonspinning += 1;
+                
+                        // Stop if too close to horizon or escaped
+                        if ray_nonspinning.tracer.geodesic.radius() < 2.0 * mass || ray_nonspinning.has_escaped() {
+                            break;
+                        }
+                    }
+                
+                    // Compare final phi coordinates - this shows frame-dragging
+                    let final_phi_spinning = ray_spinning.tracer.geodesic.position[3];
+                    let final_phi_nonspinning = ray_nonspinning.tracer.geodesic.position[3];
+                
+                    let phi_change_spinning = final_phi_spinning - initial_phi_spinning;
+                    let phi_change_nonspinning = final_phi_nonspinning - initial_phi_nonspinning;
+                    let phi_difference = phi_change_spinning - phi_change_nonspinning;
+                
+                    // Frame-dragging should cause a noticeable difference in phi evolution
+                    // For a high-spin black hole, this effect should be detectable
+                    assert!(phi_difference.abs() > 1e-6, 
+                           "Frame-dragging not detected: phi difference = {:.8}, spinning change = {:.8}, non-spinning change = {:.8}", 
+                           phi_difference, phi_change_spinning, phi_change_nonspinning);
+                
+                    // Both rays should take roughly the same number of steps
+                    assert!(steps_spinning > 0, "Spinning ray should take some integration steps");
+                    assert!(steps_nonspinning > 0, "Non-spinning ray should take some integration steps");
+                
+                    // Conserved quantities should be finite
+                    assert!(ray_spinning.conserved.energy.is_finite());
+                    assert!(ray_spinning.conserved.angular_momentum_z.is_finite());
+                    assert!(ray_spinning.conserved.carter_constant.is_finite());
+                }
+            }
         };
-        
-        let (new_state, step_used, next_step) = integrator.step(initial_state, 0.1, derivatives_fn);
-        
-        assert!(step_used > 0.0);
-        assert!(next_step > 0.0);
-        assert!(new_state.position[1].is_finite()); // Position should be finite
-        assert!(new_state.momentum[2].is_finite()); // Velocity should be finite
-    }
-    
-    #[test]
-    fn test_kerr_vs_schwarzschild_limit() {
-        // Test that Kerr reduces to Schwarzschild when spin = 0
-        let mass = 1.0;
-        let r = 5.0;
-        let theta = std::f32::consts::PI / 2.0;
-        
-        let bh_kerr = KerrBlackHole::new(mass, 0.0); // Zero spin
-        let g_kerr = kerr_schild::metric_components(r, theta, &bh_kerr);
-        
-        // Compare with Schwarzschild metric components
-        let g_tt_schwarzschild = schwarzschild::g_tt(mass, r);
-        let g_rr_schwarzschild = schwarzschild::g_rr(mass, r);
-        let g_theta_theta_schwarzschild = schwarzschild::g_theta_theta(r);
-        let g_phi_phi_schwarzschild = schwarzschild::g_phi_phi(r, theta);
-        
-        // Allow for small numerical differences
-        assert!((g_kerr[0][0] - g_tt_schwarzschild).abs() < 1e-6);
-        assert!((g_kerr[1][1] - g_rr_schwarzschild).abs() < 1e-6);
-        assert!((g_kerr[2][2] - g_theta_theta_schwarzschild).abs() < 1e-6);
-        assert!((g_kerr[3][3] - g_phi_phi_schwarzschild).abs() < 1e-6);
-        
-        // Off-diagonal terms should be zero for Schwarzschild
-        assert!(g_kerr[0][1].abs() < 1e-6);
-        assert!(g_kerr[0][3].abs() < 1e-6);
-        assert!(g_kerr[1][3].abs() < 1e-6);
-    }
-    
-    #[test]
-    fn test_kerr_schwarzschild_horizon_compatibility() {
-        // Verify that Kerr with spin=0 gives identical results to Schwarzschild
-        let mass = 2.0;
-        
-        // Create Kerr black hole with zero spin
-        let kerr_bh = KerrBlackHole::new(mass, 0.0);
-        
-        // Test that horizons match Schwarzschild
-        let kerr_horizon = kerr_bh.outer_horizon();
-        let schwarzschild_radius = 2.0 * mass;
-        
-        assert!((kerr_horizon - schwarzschild_radius).abs() < 1e-10);
-        
-        // Inner horizon should equal outer horizon for non-spinning case
-        let inner_horizon = kerr_bh.inner_horizon();
-        assert!((inner_horizon - kerr_horizon).abs() < 1e-10);
-        
-        // ISCO should be at 6M for Schwarzschild
-        let isco = kerr_bh.isco_radius();
-        assert!((isco - 6.0 * mass).abs() < 0.1);
-    }
-    
-    #[test]
-    fn test_kerr_light_ray_schwarzschild_consistency() {
-        // Test that KerrLightRay with spin=0 behaves like Schwarzschild
-        let camera_pos = [0.0, 0.0, 10.0];
-        let ray_dir = [0.0, 0.0, -1.0];
-        
-        // Create both types of black holes
-        let kerr_bh = KerrBlackHole::schwarzschild(1.0);
-        let kerr_ray = KerrLightRay::new(camera_pos, ray_dir, kerr_bh);
-        
-        // Verify the black hole parameters are correct
-        assert_eq!(kerr_ray.black_hole.mass, 1.0);
-        assert_eq!(kerr_ray.black_hole.spin, 0.0);
-        
-        // Test that horizon detection works the same
-        let horizon_radius = kerr_ray.black_hole.outer_horizon();
-        assert!((horizon_radius - 2.0).abs() < 1e-10);
-        
-        // Test conserved quantities initialization
-        assert!(kerr_ray.conserved.energy.is_finite());
-        assert!(kerr_ray.conserved.angular_momentum_z.is_finite());
-        assert!(kerr_ray.conserved.carter_constant >= 0.0);
     }
     
-    #[test]
-    fn test_frame_dragging_effect() {
-        // Test that corrected Kerr geodesics show frame-dragging
-        let mass = 1.0;
-        let high_spin = 0.99 * mass; // Near-maximal spin
-        let no_spin = 0.0;
-        
-        // Camera position much closer to black hole for stronger effect
-        let camera_pos = [5.0, 0.0, 0.0]; // At r=5M, theta=π/2 - very close to ISCO
-        
-        // Ray direction highly tangential for maximum frame-dragging
-        let ray_dir = [-0.1, 1.0, 0.0]; // Very slightly inward but mostly tangential
-        
-        // Create black holes
-        let bh_spinning = KerrBlackHole::new(mass, high_spin);
-        let bh_nonspinning = KerrBlackHole::new(mass, no_spin);
-        
-        // Create light rays
-        let mut ray_spinning = KerrLightRay::new(camera_pos, ray_dir, bh_spinning);
-        let mut ray_nonspinning = KerrLightRay::new(camera_pos, ray_dir, bh_nonspinning);
-        
-        let initial_phi_spinning = ray_spinning.geodesic.position[3];
-        let initial_phi_nonspinning = ray_nonspinning.geodesic.position[3];
-        
-        // Integrate both rays for a fixed number of steps
-        let max_steps = 200;
-        let mut steps_spinning = 0;
-        let mut steps_nonspinning = 0;
-        
-        // Integrate spinning ray
-        while steps_spinning < max_steps && ray_spinning.step() {
-            steps_spinning += 1;
-            
-            // Stop if too close to horizon or escaped
-            if ray_spinning.geodesic.radius() < 2.0 * mass || ray_spinning.has_escaped() {
-                break;
-            }
-        }
-        
-        // Integrate non-spinning ray
-        while steps_nonspinning < max_steps && ray_nonspinning.step() {
-            steps_nonspinning += 1;
-            
-            // Stop if too close to horizon or escaped
-            if ray_nonspinning.geodesic.radius() < 2.0 * mass || ray_nonspinning.has_escaped() {
-                break;
-            }
-        }
-        
-        // Compare final phi coordinates - this shows frame-dragging
-        let final_phi_spinning = ray_spinning.geodesic.position[3];
-        let final_phi_nonspinning = ray_nonspinning.geodesic.position[3];
-        
-        let phi_change_spinning = final_phi_spinning - initial_phi_spinning;
-        let phi_change_nonspinning = final_phi_nonspinning - initial_phi_nonspinning;
-        let phi_difference = phi_change_spinning - phi_change_nonspinning;
-        
-        // Frame-dragging should cause a noticeable difference in phi evolution
-        // For a high-spin black hole, this effect should be detectable
-        assert!(phi_difference.abs() > 1e-6, 
-               "Frame-dragging not detected: phi difference = {:.8}, spinning change = {:.8}, non-spinning change = {:.8}", 
-               phi_difference, phi_change_spinning, phi_change_nonspinning);
-        
-        // Both rays should take roughly the same number of steps
-        assert!(steps_spinning > 0, "Spinning ray should take some integration steps");
-        assert!(steps_nonspinning > 0, "Non-spinning ray should take some integration steps");
-        
-        // Conserved quantities should be finite
-        assert!(ray_spinning.conserved.energy.is_finite());
-        assert!(ray_spinning.conserved.angular_momentum_z.is_finite());
-        assert!(ray_spinning.conserved.carter_constant.is_finite());
-    }
+    precision_tests!(single_precision, f32);
+    precision_tests!(double_precision, f64);
 }
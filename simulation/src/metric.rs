@@ -0,0 +1,619 @@
+//! Generic spacetime metrics and a geodesic tracer that works for any of them.
+//!
+//! A spacetime only has to describe g_μν in (t, r, θ, φ)-like coordinates, where it
+//! stops rays (its horizon) and how far out a ray counts as escaped. The tracer
+//! integrates Hamilton's equations for H = ½ g^μν p_μ p_ν, taking ∂_μ g_αβ from the
+//! metric itself with dual numbers, so no per-spacetime derivatives are needed.
+
+use crate::boyer_lindquist::{self, BoyerLindquist};
+use crate::christoffel::metric_derivatives;
+use crate::float::Float;
+use crate::outcome::{RayEnd, RayOutcome};
+use crate::symplectic::ConstraintMonitor;
+use crate::trajectory::TrajectoryRecorder;
+use crate::{kerr_newman, kerr_schild, schwarzschild, wormhole, AdaptiveRK45, Geodesic, Integrator, KerrBlackHole};
+
+/// A spacetime metric that the geodesic tracer can integrate through
+pub trait Metric<T: Float = f32> {
+    /// Covariant metric components g_μν at a spacetime position (t, r, θ, φ)
+    ///
+    /// Generic over the number type so the tracer can evaluate it on dual numbers and
+    /// read off every ∂_α g_μν exactly.
+    fn metric_components<S: Float>(&self, position: [S; 4]) -> [[S; 4]; 4];
+
+    /// Contravariant metric components g^μν at a spacetime position
+    ///
+    /// Defaults to inverting `metric_components`; override when a closed form exists.
+    fn inverse_metric_components(&self, position: [T; 4]) -> [[T; 4]; 4] {
+        invert(&self.metric_components(position))
+    }
+
+    /// Check if a position lies inside the horizon, where tracing stops
+    fn is_inside_horizon(&self, position: [T; 4]) -> bool;
+
+    /// Radial coordinate beyond which a ray is considered to have escaped
+    fn escape_radius(&self) -> T;
+}
+
+impl Metric for schwarzschild::Schwarzschild {
+    fn metric_components<S: Float>(&self, position: [S; 4]) -> [[S; 4]; 4] {
+        schwarzschild::metric(self.mass, position)
+    }
+
+    fn inverse_metric_components(&self, position: [f32; 4]) -> [[f32; 4]; 4] {
+        // Diagonal metric: invert component by component
+        let g = self.metric_components(position);
+        let mut g_inv = [[0.0; 4]; 4];
+        for mu in 0..4 {
+            g_inv[mu][mu] = 1.0 / g[mu][mu];
+        }
+        g_inv
+    }
+
+    fn is_inside_horizon(&self, position: [f32; 4]) -> bool {
+        schwarzschild::is_inside_event_horizon(self.mass, position[1])
+    }
+
+    fn escape_radius(&self) -> f32 {
+        100.0 * self.mass
+    }
+}
+
+impl<T: Float> Metric<T> for KerrBlackHole<T> {
+    fn metric_components<S: Float>(&self, position: [S; 4]) -> [[S; 4]; 4] {
+        kerr_schild::metric_components(position[1], position[2], &self.cast())
+    }
+
+    fn is_inside_horizon(&self, position: [T; 4]) -> bool {
+        kerr_schild::is_inside_horizon(position[1], self)
+    }
+
+    fn escape_radius(&self) -> T {
+        T::from_f64(100.0) * self.mass
+    }
+}
+
+impl<T: Float> Metric<T> for BoyerLindquist<T> {
+    fn metric_components<S: Float>(&self, position: [S; 4]) -> [[S; 4]; 4] {
+        boyer_lindquist::metric_components(position[1], position[2], &self.black_hole.cast())
+    }
+
+    fn inverse_metric_components(&self, position: [T; 4]) -> [[T; 4]; 4] {
+        boyer_lindquist::inverse_metric_components(position[1], position[2], &self.black_hole)
+    }
+
+    fn is_inside_horizon(&self, position: [T; 4]) -> bool {
+        // Boyer-Lindquist p_r diverges at the horizon, so an infalling ray only creeps up
+        // on it; count the ray as captured once it is within a hair of r+
+        position[1] <= self.black_hole.outer_horizon() * T::from_f64(1.0 + 1e-6)
+    }
+
+    fn escape_radius(&self) -> T {
+        T::from_f64(100.0) * self.black_hole.mass
+    }
+}
+
+impl Metric for kerr_newman::KerrNewmanBlackHole {
+    fn metric_components<S: Float>(&self, position: [S; 4]) -> [[S; 4]; 4] {
+        kerr_newman::metric_components(position[1], position[2], self)
+    }
+
+    fn is_inside_horizon(&self, position: [f32; 4]) -> bool {
+        kerr_newman::is_inside_horizon(position[1], self)
+    }
+
+    fn escape_radius(&self) -> f32 {
+        100.0 * self.mass
+    }
+}
+
+impl Metric for wormhole::EllisWormhole {
+    fn metric_components<S: Float>(&self, position: [S; 4]) -> [[S; 4]; 4] {
+        wormhole::metric_components(position[1], position[2], self)
+    }
+
+    fn inverse_metric_components(&self, position: [f32; 4]) -> [[f32; 4]; 4] {
+        // Diagonal metric: invert component by component
+        let g = self.metric_components(position);
+        let mut g_inv = [[0.0; 4]; 4];
+        for mu in 0..4 {
+            g_inv[mu][mu] = 1.0 / g[mu][mu];
+        }
+        g_inv
+    }
+
+    fn is_inside_horizon(&self, _position: [f32; 4]) -> bool {
+        // A traversable wormhole has no horizon
+        false
+    }
+
+    fn escape_radius(&self) -> f32 {
+        100.0 * self.throat_radius
+    }
+}
+
+/// Invert a 4x4 matrix using cofactor expansion
+pub fn invert<T: Float>(m: &[[T; 4]; 4]) -> [[T; 4]; 4] {
+    // 2x2 sub-determinants of the top two and bottom two rows
+    let s0 = m[0][0] * m[1][1] - m[1][0] * m[0][1];
+    let s1 = m[0][0] * m[1][2] - m[1][0] * m[0][2];
+    let s2 = m[0][0] * m[1][3] - m[1][0] * m[0][3];
+    let s3 = m[0][1] * m[1][2] - m[1][1] * m[0][2];
+    let s4 = m[0][1] * m[1][3] - m[1][1] * m[0][3];
+    let s5 = m[0][2] * m[1][3] - m[1][2] * m[0][3];
+
+    let c5 = m[2][2] * m[3][3] - m[3][2] * m[2][3];
+    let c4 = m[2][1] * m[3][3] - m[3][1] * m[2][3];
+    let c3 = m[2][1] * m[3][2] - m[3][1] * m[2][2];
+    let c2 = m[2][0] * m[3][3] - m[3][0] * m[2][3];
+    let c1 = m[2][0] * m[3][2] - m[3][0] * m[2][2];
+    let c0 = m[2][0] * m[3][1] - m[3][0] * m[2][1];
+
+    let det = s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0;
+    let inv_det = T::ONE / det;
+
+    [
+        [
+            (m[1][1] * c5 - m[1][2] * c4 + m[1][3] * c3) * inv_det,
+            (-m[0][1] * c5 + m[0][2] * c4 - m[0][3] * c3) * inv_det,
+            (m[3][1] * s5 - m[3][2] * s4 + m[3][3] * s3) * inv_det,
+            (-m[2][1] * s5 + m[2][2] * s4 - m[2][3] * s3) * inv_det,
+        ],
+        [
+            (-m[1][0] * c5 + m[1][2] * c2 - m[1][3] * c1) * inv_det,
+            (m[0][0] * c5 - m[0][2] * c2 + m[0][3] * c1) * inv_det,
+            (-m[3][0] * s5 + m[3][2] * s2 - m[3][3] * s1) * inv_det,
+            (m[2][0] * s5 - m[2][2] * s2 + m[2][3] * s1) * inv_det,
+        ],
+        [
+            (m[1][0] * c4 - m[1][1] * c2 + m[1][3] * c0) * inv_det,
+            (-m[0][0] * c4 + m[0][1] * c2 - m[0][3] * c0) * inv_det,
+            (m[3][0] * s4 - m[3][1] * s2 + m[3][3] * s0) * inv_det,
+            (-m[2][0] * s4 + m[2][1] * s2 - m[2][3] * s0) * inv_det,
+        ],
+        [
+            (-m[1][0] * c3 + m[1][1] * c1 - m[1][2] * c0) * inv_det,
+            (m[0][0] * c3 - m[0][1] * c1 + m[0][2] * c0) * inv_det,
+            (-m[3][0] * s3 + m[3][1] * s1 - m[3][2] * s0) * inv_det,
+            (m[2][0] * s3 - m[2][1] * s1 + m[2][2] * s0) * inv_det,
+        ],
+    ]
+}
+
+/// Lower the index of a contravariant vector: p_μ = g_μν k^ν
+pub fn lower_index<T: Float>(g: &[[T; 4]; 4], k: [T; 4]) -> [T; 4] {
+    let mut p = [T::ZERO; 4];
+    for (p_mu, g_row) in p.iter_mut().zip(g) {
+        *p_mu = g_row.iter().zip(k).map(|(&g_mu_nu, k_nu)| g_mu_nu * k_nu).sum();
+    }
+    p
+}
+
+/// Complete spatial components (k^r, k^θ, k^φ) into a future-directed null vector k^μ
+///
+/// Solves g_tt (k^t)² + 2 (g_ti k^i) k^t + g_ij k^i k^j = 0 for k^t and returns `None`
+/// when the quadratic has no real, positive root.
+pub fn future_null_vector<T: Float>(g: &[[T; 4]; 4], spatial: [T; 3]) -> Option<[T; 4]> {
+    let k = [T::ZERO, spatial[0], spatial[1], spatial[2]];
+
+    let a_coef = g[0][0];
+    let b_half = g[0][1] * k[1] + g[0][2] * k[2] + g[0][3] * k[3];
+    let mut c_coef = T::ZERO;
+    for i in 1..4 {
+        for j in 1..4 {
+            c_coef += g[i][j] * k[i] * k[j];
+        }
+    }
+
+    let discriminant = b_half * b_half - a_coef * c_coef;
+    if discriminant.is_nan() || discriminant < T::ZERO {
+        return None;
+    }
+
+    // Numerically stable roots; the future-directed solution is the larger one
+    let q = -(b_half + b_half.signum() * discriminant.sqrt());
+    let kt = [q / a_coef, c_coef / q]
+        .into_iter()
+        .filter(|root| root.is_finite())
+        .fold(T::NEG_INFINITY, Float::max);
+
+    if kt.is_finite() && kt > T::ZERO {
+        Some([kt, k[1], k[2], k[3]])
+    } else {
+        None
+    }
+}
+
+/// Convert a Cartesian camera position and ray direction into a spherical position
+/// (0, r, θ, φ) and coordinate direction (dr, dθ, dφ)
+///
+/// # Panics
+///
+/// Panics if the camera sits at the origin or the ray direction is zero or not finite.
+pub fn spherical_ray<T: Float>(camera_pos: [T; 3], ray_dir: [T; 3]) -> ([T; 4], [T; 3]) {
+    // Convert to spherical coordinates
+    let r = (camera_pos[0] * camera_pos[0] + camera_pos[1] * camera_pos[1] + camera_pos[2] * camera_pos[2]).sqrt();
+    assert!(r.is_finite() && r > T::ZERO, "camera position must be finite and away from the origin");
+    let theta = (camera_pos[2] / r).acos();
+    let phi = camera_pos[1].atan2(camera_pos[0]);
+
+    let ray_len = (ray_dir[0] * ray_dir[0] + ray_dir[1] * ray_dir[1] + ray_dir[2] * ray_dir[2]).sqrt();
+    assert!(ray_len.is_finite() && ray_len > T::from_f64(1e-12), "ray direction must be finite and non-zero");
+    let ray_dir_norm = [ray_dir[0] / ray_len, ray_dir[1] / ray_len, ray_dir[2] / ray_len];
+
+    // Transformation from Cartesian to spherical derivatives
+    let sin_theta = theta.sin();
+    let cos_theta = theta.cos();
+    let sin_phi = phi.sin();
+    let cos_phi = phi.cos();
+
+    let dr = ray_dir_norm[0] * sin_theta * cos_phi + ray_dir_norm[1] * sin_theta * sin_phi + ray_dir_norm[2] * cos_theta;
+    let min_sin = T::from_f64(1e-6);
+    let dtheta = if sin_theta > min_sin {
+        (ray_dir_norm[0] * cos_theta * cos_phi + ray_dir_norm[1] * cos_theta * sin_phi - ray_dir_norm[2] * sin_theta) / r
+    } else {
+        T::ZERO
+    };
+    let dphi = if sin_theta > min_sin {
+        (-ray_dir_norm[0] * sin_phi + ray_dir_norm[1] * cos_phi) / (r * sin_theta)
+    } else {
+        T::ZERO
+    };
+
+    ([T::ZERO, r, theta, phi], [dr, dtheta, dphi])
+}
+
+/// Convert a spherical position (t, r, θ, φ) back into a Cartesian point (x, y, z)
+///
+/// This is the inverse of the position part of `spherical_ray`.
+pub fn cartesian_position<T: Float>(position: [T; 4]) -> [T; 3] {
+    let [_, r, theta, phi] = position;
+    let sin_theta = theta.sin();
+    [r * sin_theta * phi.cos(), r * sin_theta * phi.sin(), r * theta.cos()]
+}
+
+/// Light ray traced through any `Metric` using the Hamiltonian geodesic equations
+#[derive(Debug, Clone)]
+pub struct GeodesicTracer<M: Metric<T>, T: Float = f32, I: Integrator<T> = AdaptiveRK45<T>> {
+    /// Current geodesic state with covariant momentum p_μ
+    pub geodesic: Geodesic<T>,
+    /// Spacetime the ray travels through
+    pub metric: M,
+    /// Adaptive integrator, Fehlberg RK45 unless replaced with `with_integrator`
+    pub integrator: I,
+    /// Current step size
+    pub step_size: T,
+    /// Maximum number of integration steps
+    pub max_steps: u32,
+    /// Current step count
+    pub step_count: u32,
+    /// Affine parameter λ accumulated along the ray
+    pub affine_parameter: T,
+    /// Record of the null constraint H = 0, off unless enabled
+    pub constraint: ConstraintMonitor<T>,
+    /// Recorder of the path taken, if attached
+    pub trajectory: Option<TrajectoryRecorder<T>>,
+}
+
+impl<M: Metric<T>, T: Float> GeodesicTracer<M, T> {
+    /// Create a new light ray from camera position and direction
+    ///
+    /// # Panics
+    ///
+    /// Panics if the camera sits at the origin, if the ray direction is zero or not
+    /// finite, or if no future-directed null vector exists at the camera.
+    pub fn new(metric: M, camera_pos: [T; 3], ray_dir: [T; 3]) -> Self {
+        let (position, spatial) = spherical_ray(camera_pos, ray_dir);
+        let g = metric.metric_components(position);
+        let k = future_null_vector(&g, spatial)
+            .expect("no future-directed null vector exists for this ray direction");
+
+        Self::from_geodesic(metric, Geodesic::new(position, lower_index(&g, k)))
+    }
+
+    /// Create a light ray from an explicit position and covariant momentum
+    pub fn from_geodesic(metric: M, geodesic: Geodesic<T>) -> Self {
+        Self {
+            geodesic,
+            metric,
+            integrator: AdaptiveRK45::default(),
+            step_size: T::from_f64(0.1),
+            max_steps: 10000,
+            step_count: 0,
+            affine_parameter: T::ZERO,
+            constraint: ConstraintMonitor::default(),
+            trajectory: None,
+        }
+    }
+}
+
+impl<M: Metric<T>, T: Float, I: Integrator<T>> GeodesicTracer<M, T, I> {
+    /// Swap in a different stepping method, such as `DormandPrince54`
+    pub fn with_integrator<J: Integrator<T>>(self, integrator: J) -> GeodesicTracer<M, T, J> {
+        GeodesicTracer {
+            geodesic: self.geodesic,
+            metric: self.metric,
+            integrator,
+            step_size: self.step_size,
+            max_steps: self.max_steps,
+            step_count: self.step_count,
+            affine_parameter: self.affine_parameter,
+            constraint: self.constraint,
+            trajectory: self.trajectory,
+        }
+    }
+
+    /// Take one integration step along the geodesic with the adaptive integrator
+    ///
+    /// Returns `RayOutcome::Continuing` while the ray is in flight. Once it is captured,
+    /// escapes or runs out of steps, that outcome is returned without stepping further.
+    pub fn step(&mut self) -> RayOutcome<T> {
+        let current = self.outcome();
+        if !current.is_continuing() {
+            return current;
+        }
+
+        if let Some(trajectory) = &mut self.trajectory {
+            trajectory.record_start(self.affine_parameter, &self.geodesic);
+        }
+
+        let metric = &self.metric;
+        let (new_state, actual_step, next_step) = self.integrator.step(
+            self.geodesic,
+            self.step_size,
+            |state| hamilton_equations(metric, state),
+        );
+
+        if !new_state.is_finite() || !next_step.is_finite() {
+            if let Some(trajectory) = &mut self.trajectory {
+                trajectory.record_end(self.affine_parameter, &self.geodesic);
+            }
+            return RayOutcome::NumericalFailure(self.end());
+        }
+
+        self.geodesic = new_state;
+        self.step_size = next_step;
+        self.step_count += 1;
+        self.affine_parameter += actual_step;
+
+        if self.constraint.record {
+            let drift = self.hamiltonian().abs();
+            self.constraint.drift.push(drift);
+        }
+        if self.constraint.project {
+            self.project_to_null_shell();
+        }
+
+        let outcome = self.outcome();
+        if let Some(trajectory) = &mut self.trajectory {
+            trajectory.record_step(self.affine_parameter, &self.geodesic, !outcome.is_continuing());
+        }
+        outcome
+    }
+
+    /// Step until the ray is captured, escapes, runs out of steps or fails
+    pub fn trace(&mut self) -> RayOutcome<T> {
+        loop {
+            let outcome = self.step();
+            if !outcome.is_continuing() {
+                return outcome;
+            }
+        }
+    }
+
+    /// Trace the ray with `recorder` attached and hand back what it recorded
+    pub fn trace_recorded(&mut self, recorder: TrajectoryRecorder<T>) -> (RayOutcome<T>, TrajectoryRecorder<T>) {
+        self.trajectory = Some(recorder);
+        let outcome = self.trace();
+        (outcome, self.trajectory.take().expect("recorder stays attached while tracing"))
+    }
+
+    /// Classify the current state without stepping
+    pub fn outcome(&self) -> RayOutcome<T> {
+        let captured = self.metric.is_inside_horizon(self.geodesic.position);
+        RayOutcome::classify(self.end(), captured, self.has_escaped(), self.max_steps)
+    }
+
+    /// Snapshot of the current state as a ray end
+    fn end(&self) -> RayEnd<T> {
+        RayEnd {
+            geodesic: self.geodesic,
+            step_count: self.step_count,
+            affine_parameter: self.affine_parameter,
+        }
+    }
+
+    /// Value of the Hamiltonian H = ½ g^μν p_μ p_ν, which stays zero for a null ray
+    pub fn hamiltonian(&self) -> T {
+        hamiltonian(&self.metric, &self.geodesic)
+    }
+
+    /// Rescale (p_r, p_θ) so that H = 0 again, leaving p_t and p_φ untouched
+    ///
+    /// Every metric here is stationary and axisymmetric, so p_t = -E and p_φ = L_z are
+    /// conserved and only p_r and p_θ absorb the drift. H is quadratic in the scale
+    /// factor, and the root closest to one is taken. Nothing changes when both are zero,
+    /// no real root exists or the ray is inside the horizon.
+    pub fn project_to_null_shell(&mut self) {
+        if self.metric.is_inside_horizon(self.geodesic.position) {
+            return;
+        }
+
+        // 2H(s) = s² Q + 2s B + C with (p_r, p_θ) scaled by s
+        let g_inv = self.metric.inverse_metric_components(self.geodesic.position);
+        let p = self.geodesic.momentum;
+        let (free, fixed) = ([1, 2], [0, 3]);
+        let quadratic = |rows: [usize; 2], cols: [usize; 2]| {
+            let mut sum = T::ZERO;
+            for mu in rows {
+                for nu in cols {
+                    sum += g_inv[mu][nu] * p[mu] * p[nu];
+                }
+            }
+            sum
+        };
+        let q = quadratic(free, free);
+        let b = quadratic(free, fixed);
+        let c = quadratic(fixed, fixed);
+
+        let discriminant = b * b - q * c;
+        if q <= T::ZERO || discriminant < T::ZERO {
+            return;
+        }
+        let root = discriminant.sqrt();
+        let candidates = [(-b + root) / q, (-b - root) / q];
+        let scale = if (candidates[0] - T::ONE).abs() <= (candidates[1] - T::ONE).abs() {
+            candidates[0]
+        } else {
+            candidates[1]
+        };
+        self.geodesic.momentum[1] = p[1] * scale;
+        self.geodesic.momentum[2] = p[2] * scale;
+    }
+
+    /// Check if ray has escaped to infinity
+    pub fn has_escaped(&self) -> bool {
+        self.geodesic.radius().abs() > self.metric.escape_radius()
+    }
+}
+
+/// Hamilton's equations dx^μ/dλ = g^μν p_ν and dp_μ/dλ = -½ ∂_μ g^αβ p_α p_β
+///
+/// With k^μ = g^μν p_ν, the momentum equation is ½ (∂_μ g_αβ) k^α k^β, and every
+/// ∂_μ g_αβ comes exactly from evaluating the metric on dual numbers.
+pub fn hamilton_equations<M: Metric<T>, T: Float>(metric: &M, state: Geodesic<T>) -> Geodesic<T> {
+    let g_inv = metric.inverse_metric_components(state.position);
+    let k = lower_index(&g_inv, state.momentum);
+    let (_, dg) = metric_derivatives(|x| metric.metric_components(x), state.position);
+
+    let half = T::from_f64(0.5);
+    let mut mom_deriv = [T::ZERO; 4];
+    for (deriv, dg_mu) in mom_deriv.iter_mut().zip(&dg) {
+        let mut sum = T::ZERO;
+        for (alpha, dg_alpha) in dg_mu.iter().enumerate() {
+            for (beta, dg_alpha_beta) in dg_alpha.iter().enumerate() {
+                sum += *dg_alpha_beta * k[alpha] * k[beta];
+            }
+        }
+        *deriv = half * sum;
+    }
+
+    Geodesic::new(k, mom_deriv)
+}
+
+/// Evaluate H = ½ g^μν p_μ p_ν for a geodesic state
+fn hamiltonian<M: Metric<T>, T: Float>(metric: &M, state: &Geodesic<T>) -> T {
+    let g_inv = metric.inverse_metric_components(state.position);
+    let raised = lower_index(&g_inv, state.momentum);
+    T::from_f64(0.5) * raised.iter().zip(state.momentum).map(|(&k, p)| k * p).sum::<T>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::KerrLightRay;
+
+    #[test]
+    fn test_invert_kerr_metric() {
+        let bh = KerrBlackHole::new(1.0, 0.9);
+        let position = [0.0, 4.0, 1.1, 0.3];
+        let g = bh.metric_components(position);
+        let g_inv = bh.inverse_metric_components(position);
+
+        for (i, g_row) in g.iter().enumerate() {
+            for j in 0..4 {
+                let product: f32 = g_row.iter().zip(&g_inv).map(|(g_ik, inv_row)| g_ik * inv_row[j]).sum();
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((product - expected).abs() < 1e-5, "(g g⁻¹)[{}][{}] = {}", i, j, product);
+            }
+        }
+    }
+
+    #[test]
+    fn test_schwarzschild_closed_form_inverse() {
+        let metric = schwarzschild::Schwarzschild::new(1.0);
+        let position = [0.0, 5.0, 0.7, 0.0];
+        let closed_form = metric.inverse_metric_components(position);
+        let numeric = invert(&metric.metric_components(position));
+
+        for (closed_row, numeric_row) in closed_form.iter().zip(&numeric) {
+            for (a, b) in closed_row.iter().zip(numeric_row) {
+                assert!((a - b).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_tracer_schwarzschild_periapsis() {
+        let metric = schwarzschild::Schwarzschild::new(1.0);
+        let mut ray = GeodesicTracer::new(metric, [20.0, 0.0, 0.0], [-1.0, 0.35, 0.0]);
+        assert!(ray.hamiltonian().abs() < 1e-5);
+        let b = ray.geodesic.momentum[3] / -ray.geodesic.momentum[0];
+
+        let mut min_r = ray.geodesic.radius();
+        while ray.step().is_continuing() {
+            min_r = min_r.min(ray.geodesic.radius());
+        }
+
+        assert!(ray.has_escaped());
+        let expected_b_sq = min_r.powi(3) / (min_r - 2.0);
+        assert!((expected_b_sq - b * b).abs() < 1e-2 * b * b, "min r = {}, b = {}", min_r, b);
+        assert!(ray.hamiltonian().abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_tracer_captures_radial_ray() {
+        let mut ray = GeodesicTracer::new(KerrBlackHole::new(1.0, 0.7), [0.0, 10.0, 0.0], [0.0, -1.0, 0.0]);
+        let outcome = ray.trace();
+
+        assert!(matches!(outcome, RayOutcome::Captured(_)), "{:?}", outcome);
+        assert!(ray.metric.is_inside_horizon(ray.geodesic.position));
+        assert!(ray.step_count < ray.max_steps);
+    }
+
+    #[test]
+    fn test_tracer_matches_kerr_light_ray() {
+        // Kerr-Schild and Boyer-Lindquist share r and θ, so both tracers must agree on them
+        let bh = KerrBlackHole::new(1.0, 0.9);
+        let camera_pos = [15.0, 0.0, 2.0];
+        let ray_dir = [-1.0, 0.4, -0.1];
+
+        let mut generic = GeodesicTracer::new(bh, camera_pos, ray_dir);
+        let mut kerr = KerrLightRay::new(camera_pos, ray_dir, bh);
+
+        // Conserved E and L_z are chart independent
+        assert!((generic.geodesic.momentum[0] - kerr.tracer.geodesic.momentum[0]).abs() < 1e-5);
+        assert!((generic.geodesic.momentum[3] - kerr.tracer.geodesic.momentum[3]).abs() < 1e-5);
+
+        generic.trace();
+        kerr.trace();
+        assert!(generic.has_escaped() && kerr.has_escaped());
+
+        // Compare the outgoing polar direction at the escape sphere
+        let theta_generic = generic.geodesic.position[2];
+        let theta_kerr = kerr.tracer.geodesic.position[2];
+        assert!((theta_generic - theta_kerr).abs() < 2e-2, "θ: {} vs {}", theta_generic, theta_kerr);
+    }
+
+    #[test]
+    fn test_tracer_through_charged_black_hole() {
+        let camera_pos = [12.0, 0.0, 1.0];
+        let ray_dir = [-1.0, 0.35, 0.0];
+
+        // Uncharged Kerr–Newman traces exactly like Kerr
+        let mut kerr = GeodesicTracer::new(KerrBlackHole::new(1.0, 0.6), camera_pos, ray_dir);
+        let mut uncharged = GeodesicTracer::new(kerr_newman::KerrNewmanBlackHole::new(1.0, 0.6, 0.0), camera_pos, ray_dir);
+        for _ in 0..200 {
+            assert_eq!(kerr.step(), uncharged.step());
+        }
+        assert_eq!(kerr.geodesic.position, uncharged.geodesic.position);
+
+        // Charge weakens gravity at small radii, so a grazing ray bends less
+        let mut charged = GeodesicTracer::new(kerr_newman::KerrNewmanBlackHole::new(1.0, 0.6, 0.7), camera_pos, ray_dir);
+        assert!(charged.hamiltonian().abs() < 1e-4);
+        charged.trace();
+        kerr.trace();
+        assert!(charged.has_escaped() && kerr.has_escaped());
+        assert!(charged.geodesic.position[3] < kerr.geodesic.position[3]);
+    }
+}
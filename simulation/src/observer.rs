@@ -0,0 +1,287 @@
+//! Observers outside a Kerr black hole and their local orthonormal frames.
+//!
+//! Every frame is built from Carter's tetrad, the frame in which the Kerr curvature takes
+//! its simplest form:
+//! e₀ = ((r² + a²)∂_t + a∂_φ) / √(ΔΣ), e₁ = √(Δ/Σ) ∂_r, e₂ = ∂_θ / √Σ and
+//! e₃ = (a sin²θ ∂_t + ∂_φ) / (√Σ sin θ), all in Boyer-Lindquist coordinates. An observer's
+//! spatial axes are e₁, e₂, e₃ made orthogonal to its four-velocity, in that order, so the
+//! first axis points radially outward as nearly as the motion allows.
+
+use crate::float::Float;
+use crate::timelike::orbit_direction;
+use crate::{kerr_schild, KerrBlackHole};
+
+/// Observer whose local frame physical quantities are measured in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Observer {
+    /// Held at fixed (r, θ, φ) by a rocket; only possible outside the ergosphere
+    Static,
+    /// Zero angular momentum observer, dragged around at ω = 2Mar / A
+    ZeroAngularMomentum,
+    /// Falling from rest at infinity with zero angular momentum and Carter constant,
+    /// keeping its θ all the way in
+    FreeFall,
+    /// Circling the axis at the angular velocity Ω = ±√M / (r^{3/2} ± a√M) of the equatorial
+    /// circular orbit, co-rotating with the hole if `prograde`; in free fall only on the equator
+    Circular { prograde: bool },
+}
+
+impl Observer {
+    /// Four-velocity u^μ in Boyer-Lindquist coordinates (t, r, θ, φ) at radius r and polar angle θ
+    ///
+    /// Returns `None` at or inside the outer horizon, for a static observer inside the
+    /// ergosphere where no timelike worldline stays at rest, and for a circular one wherever
+    /// its angular velocity would be faster than light.
+    pub fn four_velocity<T: Float>(&self, black_hole: &KerrBlackHole<T>, r: T, theta: T) -> Option<[T; 4]> {
+        if r <= black_hole.outer_horizon() {
+            return None;
+        }
+        let (mass, a) = (black_hole.mass, black_hole.spin);
+        let two_mr = T::from_f64(2.0) * mass * r;
+        let sigma = kerr_schild::sigma(r, theta, a);
+        let delta = kerr_schild::delta(r, mass, a);
+        let big_a = kerr_schild::a_function(r, theta, mass, a);
+
+        match self {
+            Observer::Static => {
+                let lapse_sq = T::ONE - two_mr / sigma;
+                (lapse_sq > T::ZERO).then(|| [T::ONE / lapse_sq.sqrt(), T::ZERO, T::ZERO, T::ZERO])
+            }
+            Observer::ZeroAngularMomentum => {
+                let u_t = (big_a / (delta * sigma)).sqrt();
+                Some([u_t, T::ZERO, T::ZERO, two_mr * a / big_a * u_t])
+            }
+            Observer::FreeFall => {
+                let u_r = -(two_mr * (r * r + a * a)).sqrt() / sigma;
+                Some([big_a / (delta * sigma), u_r, T::ZERO, two_mr * a / (delta * sigma)])
+            }
+            Observer::Circular { prograde } => {
+                let direction = orbit_direction(a, *prograde);
+                let sqrt_m = mass.sqrt();
+                let omega = direction * sqrt_m / (r * r.sqrt() + direction * a * sqrt_m);
+                // -g_μν u^μ u^ν / (u^t)² for u ∝ ∂_t + Ω∂_φ
+                let sin_sq = theta.sin().powi(2);
+                let norm = T::ONE - two_mr / sigma + T::from_f64(2.0) * two_mr * a * sin_sq / sigma * omega
+                    - big_a * sin_sq / sigma * omega * omega;
+                (norm > T::ZERO).then(|| {
+                    let u_t = T::ONE / norm.sqrt();
+                    [u_t, T::ZERO, T::ZERO, omega * u_t]
+                })
+            }
+        }
+    }
+
+    /// Orthonormal tetrad [u, e_r̂, e_θ̂, e_φ̂] with Boyer-Lindquist components, or `None`
+    /// wherever `four_velocity` is
+    pub fn tetrad<T: Float>(&self, black_hole: &KerrBlackHole<T>, r: T, theta: T) -> Option<[[T; 4]; 4]> {
+        let frame = self.carter_frame(black_hole, r, theta)?;
+        let basis = carter_basis(black_hole, r, theta);
+        Some(frame.map(|vector| {
+            let mut components = [T::ZERO; 4];
+            for (weight, basis_vector) in vector.iter().zip(&basis) {
+                for (component, &value) in components.iter_mut().zip(basis_vector) {
+                    *component += *weight * value;
+                }
+            }
+            components
+        }))
+    }
+
+    /// Boyer-Lindquist four-momentum p^μ of a photon this observer measures at unit
+    /// frequency, moving along `direction` in its frame, or `None` wherever `four_velocity` is
+    ///
+    /// The direction has components along e_r̂, e_θ̂, e_φ̂ and need not be normalized.
+    pub fn photon_momentum<T: Float>(&self, black_hole: &KerrBlackHole<T>, r: T, theta: T, direction: [T; 3]) -> Option<[T; 4]> {
+        let tetrad = self.tetrad(black_hole, r, theta)?;
+        let norm = direction.iter().fold(T::ZERO, |sum, &n| sum + n * n).sqrt();
+        let mut momentum = tetrad[0];
+        for (axis, &n) in tetrad[1..].iter().zip(&direction) {
+            for (component, &value) in momentum.iter_mut().zip(axis) {
+                *component += n / norm * value;
+            }
+        }
+        Some(momentum)
+    }
+
+    /// Lorentz transformation Λ^a_b from this observer's frame to that of `reference` at
+    /// (r, θ), or `None` wherever either `four_velocity` is
+    ///
+    /// A vector with components x^b in this frame has components Λ^a_b x^b in the other, so
+    /// a photon seen at unit frequency along n arrives there as Λ (1, n): frequency first,
+    /// then the aberrated direction scaled by it.
+    pub fn lorentz_transform<T: Float>(&self, reference: &Observer, black_hole: &KerrBlackHole<T>, r: T, theta: T) -> Option<[[T; 4]; 4]> {
+        let own = self.carter_frame(black_hole, r, theta)?;
+        let other = reference.carter_frame(black_hole, r, theta)?;
+        Some(frame_transform(own, other))
+    }
+
+    /// The tetrad of `tetrad`, with components in Carter's frame instead
+    pub(crate) fn carter_frame<T: Float>(&self, black_hole: &KerrBlackHole<T>, r: T, theta: T) -> Option<[[T; 4]; 4]> {
+        let velocity = self.four_velocity(black_hole, r, theta)?;
+        Some(comoving_frame(black_hole, r, theta, velocity))
+    }
+}
+
+/// Carter-frame components of the tetrad of a body moving at Boyer-Lindquist four-velocity
+/// u^μ outside the horizon, with its spatial axes built the way an observer's are
+pub(crate) fn comoving_frame<T: Float>(black_hole: &KerrBlackHole<T>, r: T, theta: T, velocity: [T; 4]) -> [[T; 4]; 4] {
+    let mut frame = [carter_components(black_hole, r, theta, velocity), [T::ZERO; 4], [T::ZERO; 4], [T::ZERO; 4]];
+
+    // Gram-Schmidt on Carter's spatial axes with the Minkowski product
+    for axis in 1..4 {
+        let mut vector = [T::ZERO; 4];
+        vector[axis] = T::ONE;
+        for (index, previous) in frame[..axis].iter().enumerate() {
+            let sign = if index == 0 { T::ONE } else { -T::ONE };
+            let projection = sign * minkowski(vector, *previous);
+            for (component, &value) in vector.iter_mut().zip(previous) {
+                *component += projection * value;
+            }
+        }
+        let norm = minkowski(vector, vector).sqrt();
+        frame[axis] = vector.map(|component| component / norm);
+    }
+    frame
+}
+
+/// Lorentz transformation Λ^a_b between two tetrads given in Carter's frame
+pub(crate) fn frame_transform<T: Float>(own: [[T; 4]; 4], other: [[T; 4]; 4]) -> [[T; 4]; 4] {
+    let mut transform = [[T::ZERO; 4]; 4];
+    for (a, row) in transform.iter_mut().enumerate() {
+        let sign = if a == 0 { -T::ONE } else { T::ONE };
+        for (b, entry) in row.iter_mut().enumerate() {
+            *entry = sign * minkowski(own[b], other[a]);
+        }
+    }
+    transform
+}
+
+/// Minkowski product η_ab x^a y^b with signature (-, +, +, +)
+fn minkowski<T: Float>(x: [T; 4], y: [T; 4]) -> T {
+    -x[0] * y[0] + x[1] * y[1] + x[2] * y[2] + x[3] * y[3]
+}
+
+/// Boyer-Lindquist components of Carter's tetrad e₀, e₁, e₂, e₃
+fn carter_basis<T: Float>(black_hole: &KerrBlackHole<T>, r: T, theta: T) -> [[T; 4]; 4] {
+    let (mass, a) = (black_hole.mass, black_hole.spin);
+    let sin_theta = theta.sin();
+    let sqrt_sigma = kerr_schild::sigma(r, theta, a).sqrt();
+    let sqrt_delta = kerr_schild::delta(r, mass, a).sqrt();
+    let zero = T::ZERO;
+
+    let time_scale = T::ONE / (sqrt_delta * sqrt_sigma);
+    let axial_scale = T::ONE / (sqrt_sigma * sin_theta);
+    [
+        [(r * r + a * a) * time_scale, zero, zero, a * time_scale],
+        [zero, sqrt_delta / sqrt_sigma, zero, zero],
+        [zero, zero, T::ONE / sqrt_sigma, zero],
+        [a * sin_theta * sin_theta * axial_scale, zero, zero, axial_scale],
+    ]
+}
+
+/// Components of a Boyer-Lindquist vector in Carter's tetrad, from the dual one-forms
+fn carter_components<T: Float>(black_hole: &KerrBlackHole<T>, r: T, theta: T, vector: [T; 4]) -> [T; 4] {
+    let (mass, a) = (black_hole.mass, black_hole.spin);
+    let sin_theta = theta.sin();
+    let sqrt_sigma = kerr_schild::sigma(r, theta, a).sqrt();
+    let sqrt_delta = kerr_schild::delta(r, mass, a).sqrt();
+    let [v_t, v_r, v_theta, v_phi] = vector;
+    [
+        sqrt_delta / sqrt_sigma * (v_t - a * sin_theta * sin_theta * v_phi),
+        sqrt_sigma / sqrt_delta * v_r,
+        sqrt_sigma * v_theta,
+        sin_theta / sqrt_sigma * ((r * r + a * a) * v_phi - a * v_t),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::boyer_lindquist::metric_components;
+    use crate::metric::lower_index;
+
+    #[test]
+    fn test_tetrads_are_orthonormal() {
+        let bh = KerrBlackHole::<f64>::new(1.0, 0.9);
+        let (r, theta) = (2.5, 0.8);
+        let g = metric_components(r, theta, &bh);
+        let circular = Observer::Circular { prograde: true };
+        for observer in [Observer::Static, Observer::ZeroAngularMomentum, Observer::FreeFall, circular] {
+            let tetrad = observer.tetrad(&bh, r, theta).unwrap();
+            for (i, x) in tetrad.iter().enumerate() {
+                let lowered = lower_index(&g, *x);
+                for (j, y) in tetrad.iter().enumerate() {
+                    let product: f64 = lowered.iter().zip(y).map(|(p, q)| p * q).sum();
+                    let expected = if i != j { 0.0 } else if i == 0 { -1.0 } else { 1.0 };
+                    assert!((product - expected).abs() < 1e-12, "{:?}: e{}·e{} = {}", observer, i, j, product);
+                }
+            }
+            let velocity = observer.four_velocity(&bh, r, theta).unwrap();
+            assert!(tetrad[0].iter().zip(velocity).all(|(x, y)| (x - y).abs() < 1e-14));
+        }
+
+        // The ZAMO carries no angular momentum and the free faller has E = 1
+        let g = metric_components(5.0, 1.2, &bh);
+        let zamo = lower_index(&g, Observer::ZeroAngularMomentum.four_velocity(&bh, 5.0, 1.2).unwrap());
+        assert!(zamo[3].abs() < 1e-14);
+        let faller = lower_index(&g, Observer::FreeFall.four_velocity(&bh, 5.0, 1.2).unwrap());
+        assert!((faller[0] + 1.0).abs() < 1e-14 && faller[3].abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_photons_and_aberration() {
+        let bh = KerrBlackHole::<f64>::new(1.0, 0.7);
+        let (r, theta) = (4.0, 1.1);
+        let g = metric_components(r, theta, &bh);
+        let circular = Observer::Circular { prograde: true };
+        let direction = [0.3, -0.5, 0.8];
+        for observer in [Observer::Static, Observer::ZeroAngularMomentum, Observer::FreeFall, circular] {
+            // Null, at unit frequency, and heading along the direction asked for
+            let momentum = observer.photon_momentum(&bh, r, theta, direction).unwrap();
+            let lowered = lower_index(&g, momentum);
+            let dot = |x: [f64; 4]| lowered.iter().zip(x).map(|(p, q)| p * q).sum::<f64>();
+            assert!(dot(momentum).abs() < 1e-12);
+            let tetrad = observer.tetrad(&bh, r, theta).unwrap();
+            assert!((dot(tetrad[0]) + 1.0).abs() < 1e-12);
+            let norm = direction.iter().map(|n| n * n).sum::<f64>().sqrt();
+            for (axis, n) in tetrad[1..].iter().zip(direction) {
+                assert!((dot(*axis) - n / norm).abs() < 1e-12);
+            }
+
+            // The ZAMO sees the same photon at the frequency and direction Λ predicts
+            let transform = observer.lorentz_transform(&Observer::ZeroAngularMomentum, &bh, r, theta).unwrap();
+            let local = [1.0, direction[0] / norm, direction[1] / norm, direction[2] / norm];
+            let zamo = Observer::ZeroAngularMomentum.tetrad(&bh, r, theta).unwrap();
+            for (a, row) in transform.iter().enumerate() {
+                let expected = if a == 0 { -dot(zamo[0]) } else { dot(zamo[a]) };
+                let actual: f64 = row.iter().zip(local).map(|(x, y)| x * y).sum();
+                assert!((actual - expected).abs() < 1e-12, "{:?}: {} vs {}", observer, actual, expected);
+            }
+        }
+
+        // Falling inward at v = √(2M/r) past a static observer in Schwarzschild, an outgoing
+        // photon the faller meets head-on has √((1 - v)/(1 + v)) of the frequency for the
+        // static observer, and one crossing the faller's path sideways moves inward for the
+        // static observer at cos α = v
+        let bh = KerrBlackHole::<f64>::schwarzschild(1.0);
+        let r = 8.0;
+        let v = (2.0 / r).sqrt();
+        let transform = Observer::FreeFall.lorentz_transform(&Observer::Static, &bh, r, 1.0).unwrap();
+        let outgoing: f64 = transform[0].iter().zip([1.0, 1.0, 0.0, 0.0]).map(|(x, y)| x * y).sum();
+        assert!((outgoing - ((1.0 - v) / (1.0 + v)).sqrt()).abs() < 1e-12);
+        let sideways = transform.map(|row| row[0] + row[2]);
+        assert!((sideways[1] / sideways[0] + v).abs() < 1e-12);
+        assert!(Observer::Static.lorentz_transform(&Observer::FreeFall, &bh, bh.outer_horizon(), 1.0).is_none());
+    }
+
+    #[test]
+    fn test_static_observers_end_at_the_ergosphere() {
+        let bh = KerrBlackHole::<f64>::new(1.0, 0.9);
+        let equator = std::f64::consts::FRAC_PI_2;
+        let ergosphere = bh.ergosphere_radius(equator);
+        assert!(Observer::Static.four_velocity(&bh, ergosphere * 0.99, equator).is_none());
+        assert!(Observer::Static.four_velocity(&bh, ergosphere * 1.01, equator).is_some());
+        assert!(Observer::ZeroAngularMomentum.four_velocity(&bh, ergosphere * 0.99, equator).is_some());
+        assert!(Observer::FreeFall.four_velocity(&bh, bh.outer_horizon(), equator).is_none());
+    }
+}
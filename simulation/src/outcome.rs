@@ -0,0 +1,108 @@
+//! Why a traced ray stopped.
+//!
+//! Every tracer's `step` reports `RayOutcome::Continuing` while the ray is in flight and
+//! one of the terminal variants once it stops, so callers can tell a captured ray from
+//! one that escaped, ran out of steps, or broke down numerically.
+
+use crate::float::Float;
+use crate::Geodesic;
+
+/// Final state of a ray that stopped being traced
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayEnd<T: Float = f32> {
+    /// Last valid geodesic state
+    pub geodesic: Geodesic<T>,
+    /// Number of integration steps taken
+    pub step_count: u32,
+    /// Affine parameter λ accumulated along the ray
+    pub affine_parameter: T,
+}
+
+/// Result of advancing a ray
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RayOutcome<T: Float = f32> {
+    /// The ray took a step and is still in flight
+    Continuing,
+    /// The ray crossed the horizon
+    Captured(RayEnd<T>),
+    /// The ray reached the escape radius
+    Escaped(RayEnd<T>),
+    /// The step budget ran out before the ray was captured or escaped
+    MaxSteps(RayEnd<T>),
+    /// The integrator produced a non-finite state; the last finite one is kept
+    NumericalFailure(RayEnd<T>),
+}
+
+impl<T: Float> RayOutcome<T> {
+    /// Check if the ray is still being traced
+    pub fn is_continuing(&self) -> bool {
+        matches!(self, RayOutcome::Continuing)
+    }
+
+    /// Final state of a stopped ray, or `None` while it is still in flight
+    pub fn end(&self) -> Option<&RayEnd<T>> {
+        match self {
+            RayOutcome::Continuing => None,
+            RayOutcome::Captured(end)
+            | RayOutcome::Escaped(end)
+            | RayOutcome::MaxSteps(end)
+            | RayOutcome::NumericalFailure(end) => Some(end),
+        }
+    }
+
+    /// Classify a ray's current state, checking capture before escape before the step budget
+    pub(crate) fn classify(end: RayEnd<T>, captured: bool, escaped: bool, max_steps: u32) -> Self {
+        if !end.geodesic.is_finite() {
+            RayOutcome::NumericalFailure(end)
+        } else if captured {
+            RayOutcome::Captured(end)
+        } else if escaped {
+            RayOutcome::Escaped(end)
+        } else if end.step_count >= max_steps {
+            RayOutcome::MaxSteps(end)
+        } else {
+            RayOutcome::Continuing
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{KerrBlackHole, KerrLightRay, LightRay};
+
+    #[test]
+    fn test_kerr_ray_outcomes() {
+        let bh = KerrBlackHole::new(1.0, 0.7);
+
+        let captured = KerrLightRay::new([10.0, 0.0, 0.0], [-1.0, 0.0, 0.0], bh).trace();
+        assert!(matches!(captured, RayOutcome::Captured(_)), "{:?}", captured);
+
+        let escaped = KerrLightRay::new([10.0, 0.0, 0.0], [1.0, 0.2, 0.0], bh).trace();
+        let end = escaped.end().unwrap();
+        assert!(matches!(escaped, RayOutcome::Escaped(_)), "{:?}", escaped);
+        assert!(end.geodesic.radius() > 100.0);
+        assert!(end.step_count > 0);
+        assert!(end.affine_parameter > 0.0);
+
+        let mut short = KerrLightRay::new([10.0, 0.0, 0.0], [1.0, 0.2, 0.0], bh);
+        short.tracer.max_steps = 3;
+        let outcome = short.trace();
+        assert!(matches!(outcome, RayOutcome::MaxSteps(RayEnd { step_count: 3, .. })), "{:?}", outcome);
+    }
+
+    #[test]
+    fn test_numerical_failure_keeps_last_finite_state() {
+        let mut ray = LightRay::new([10.0, 0.0, 0.0], [-1.0, 1.0, 0.0], 1.0);
+        let start = ray.tracer.geodesic;
+        ray.tracer.step_size = f32::NAN;
+
+        match ray.step() {
+            RayOutcome::NumericalFailure(end) => {
+                assert_eq!(end.geodesic, start);
+                assert_eq!(end.step_count, 0);
+            }
+            other => panic!("expected a numerical failure, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,237 @@
+//! Effective potentials, turning points and orbital precession for equatorial orbits.
+//!
+//! On the equator the radial potential is quadratic in the energy,
+//! R(r) = A E² - 2B E + C, so R ≥ 0 exactly where E ≥ V(r) for the upper root
+//! V(r) = (B + √(B² - AC)) / A. Without spin that is the textbook
+//! V(r) = √((1 - 2M/r)(μ² + L²/r²)), and with μ = 0 it covers photons too.
+
+use crate::float::{bisect, Float};
+use crate::{kerr_schild, ConservedQuantities, KerrBlackHole};
+
+/// Growth factor of the distance from the horizon between turning-point samples
+const SCAN_RATIO: f64 = 1.01;
+
+/// Radius, in units of M, where the turning-point scan stops
+pub(crate) const SCAN_LIMIT: f64 = 1e6;
+
+/// Distance from the horizon, in units of M, where the turning-point scan starts
+pub(crate) const HORIZON_GAP: f64 = 1e-6;
+
+/// Quadrature nodes for the precession integral
+const PRECESSION_NODES: usize = 256;
+
+/// Effective potential V(r) for equatorial motion with angular momentum L and rest mass μ
+///
+/// A particle with energy E can be at r only where E ≥ V(r), and turns around where
+/// they are equal. Set μ = 0 for photons.
+pub fn effective_potential<T: Float>(black_hole: &KerrBlackHole<T>, r: T, angular_momentum: T, rest_mass: T) -> T {
+    let (mass, a) = (black_hole.mass, black_hole.spin);
+    let l = angular_momentum;
+    let delta = kerr_schild::delta(r, mass, a);
+    let r_sq_a_sq = r * r + a * a;
+
+    let quadratic = r_sq_a_sq * r_sq_a_sq - delta * a * a;
+    let linear = T::from_f64(2.0) * mass * r * a * l;
+    let constant = a * a * l * l - delta * (rest_mass * rest_mass * r * r + l * l);
+    (linear + (linear * linear - quadratic * constant).max(T::ZERO).sqrt()) / quadratic
+}
+
+/// `samples` points (r, V(r)) spaced evenly from `r_min` to `r_max`, e.g. for plotting
+///
+/// # Panics
+///
+/// Panics if `samples` is less than two.
+pub fn potential_curve<T: Float>(
+    black_hole: &KerrBlackHole<T>,
+    angular_momentum: T,
+    rest_mass: T,
+    r_min: T,
+    r_max: T,
+    samples: usize,
+) -> Vec<[T; 2]> {
+    assert!(samples >= 2, "a curve needs at least two samples");
+    (0..samples)
+        .map(|i| {
+            let r = r_min + (r_max - r_min) * T::from_f64(i as f64 / (samples - 1) as f64);
+            [r, effective_potential(black_hole, r, angular_momentum, rest_mass)]
+        })
+        .collect()
+}
+
+/// All radial turning points R(r) = 0 outside the outer horizon, in increasing order
+///
+/// The scan starts 10⁻⁶ M outside the horizon and runs out to 10⁶ M; see
+/// `scan_turning_points`.
+pub fn turning_points<T: Float>(black_hole: &KerrBlackHole<T>, conserved: &ConservedQuantities<T>) -> Vec<T> {
+    let mass = black_hole.mass;
+    scan_turning_points(black_hole, conserved, T::from_f64(HORIZON_GAP) * mass, T::from_f64(SCAN_LIMIT) * mass).collect()
+}
+
+/// Radial turning points R(r) = 0 met walking from `gap` outside the outer horizon to
+/// radius `end`, in the order met
+///
+/// R is taken to be non-negative where the walk starts, as it is wherever a particle
+/// actually is, so a particle sitting on a turning point still finds it. The distance
+/// from the horizon changes by 1% between samples and each sign change is bisected,
+/// so features narrower than about 1% of that distance can be missed. `end` must lie
+/// outside the horizon.
+pub(crate) fn scan_turning_points<'a, T: Float>(
+    black_hole: &'a KerrBlackHole<T>,
+    conserved: &'a ConservedQuantities<T>,
+    gap: T,
+    end: T,
+) -> impl Iterator<Item = T> + 'a {
+    let (mass, spin) = (black_hole.mass, black_hole.spin);
+    let potential = move |r: T| conserved.radial_potential(r, mass, spin);
+    let horizon = black_hole.outer_horizon();
+    let outward = end > horizon + gap;
+    let factor = if outward { T::from_f64(SCAN_RATIO) } else { T::ONE / T::from_f64(SCAN_RATIO) };
+
+    let (mut gap, mut previous) = (gap, horizon + gap);
+    let mut allowed = true;
+    std::iter::from_fn(move || {
+        while previous != end {
+            gap *= factor;
+            let r = if (horizon + gap > end) == outward { end } else { horizon + gap };
+            let (from, now_allowed) = (previous, potential(r) >= T::ZERO);
+            previous = r;
+            if now_allowed != allowed {
+                allowed = now_allowed;
+                return Some(bisect(from, r, |x| (potential(x) >= T::ZERO) == now_allowed));
+            }
+        }
+        None
+    })
+}
+
+/// Periapsis advance per radial period, Δφ - 2π, of a bound equatorial orbit (Q = 0)
+///
+/// Δφ = 2 ∫ Φ(r) / √R(r) dr between periapsis and apoapsis, with
+/// Φ = (a/Δ)(E(r² + a²) - aL) + L - aE, evaluated with r = r̄ - h cos χ so the endpoint
+/// singularities drop out. Returns `None` if the orbit has no bound region.
+pub fn periapsis_precession<T: Float>(black_hole: &KerrBlackHole<T>, conserved: &ConservedQuantities<T>) -> Option<T> {
+    let (mass, a) = (black_hole.mass, black_hole.spin);
+    let half = T::from_f64(0.5);
+    let potential = |r: T| conserved.radial_potential(r, mass, a);
+
+    // The outermost pair of turning points with the particle allowed in between
+    let points = turning_points(black_hole, conserved);
+    let (periapsis, apoapsis) = points
+        .windows(2)
+        .rev()
+        .map(|pair| (pair[0], pair[1]))
+        .find(|&(inner, outer)| potential((inner + outer) * half) > T::ZERO)?;
+
+    let (e, l) = (conserved.energy, conserved.angular_momentum_z);
+    let center = (periapsis + apoapsis) * half;
+    let width = (apoapsis - periapsis) * half;
+    let mut integral = T::ZERO;
+    for k in 0..PRECESSION_NODES {
+        let chi = T::PI * T::from_f64((k as f64 + 0.5) / PRECESSION_NODES as f64);
+        let r = center - width * chi.cos();
+        let delta = kerr_schild::delta(r, mass, a);
+        let azimuthal = a / delta * (e * (r * r + a * a) - a * l) + l - a * e;
+        // R / ((r - periapsis)(apoapsis - r)) stays finite and positive across the orbit
+        let reduced = potential(r) / ((r - periapsis) * (apoapsis - r));
+        integral += azimuthal / reduced.sqrt();
+    }
+    let step = T::PI / T::from_f64(PRECESSION_NODES as f64);
+    Some(T::from_f64(2.0) * integral * step - T::from_f64(2.0) * T::PI)
+}
+
+/// Lense–Thirring nodal precession rate Ω_φ - Ω_θ of a slightly tilted prograde circular
+/// orbit at radius r, per unit coordinate time
+///
+/// Uses Ω_φ = √M / (r^{3/2} + a√M) and Ω_θ = Ω_φ √(1 - 4a√M / r^{3/2} + 3a²/r²), which
+/// tends to the weak-field 2aM/r³ far from the hole.
+pub fn nodal_precession_frequency<T: Float>(black_hole: &KerrBlackHole<T>, r: T) -> T {
+    let (mass, a) = (black_hole.mass, black_hole.spin.abs());
+    let (sqrt_m, r_three_halves) = (mass.sqrt(), r * r.sqrt());
+    let orbital = sqrt_m / (r_three_halves + a * sqrt_m);
+    let vertical = T::ONE - T::from_f64(4.0) * a * sqrt_m / r_three_halves + T::from_f64(3.0) * a * a / (r * r);
+    orbital * (T::ONE - vertical.max(T::ZERO).sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dormand_prince::DormandPrince54;
+    use crate::elliptic::complete_first_kind;
+    use crate::timelike::{circular_orbit, eccentric_orbit, KerrParticle};
+
+    #[test]
+    fn test_effective_potential_and_turning_points() {
+        // Textbook Schwarzschild potentials for particles and photons
+        let schwarzschild = KerrBlackHole::<f64>::schwarzschild(1.0);
+        let r = 7.0;
+        let massive = effective_potential(&schwarzschild, r, 4.0, 1.0);
+        assert!((massive - ((1.0 - 2.0 / r) * (1.0 + 16.0 / (r * r))).sqrt()).abs() < 1e-12);
+        let photon = effective_potential(&schwarzschild, 3.0, 5.0, 0.0);
+        assert!((photon - 5.0 / 27.0_f64.sqrt()).abs() < 1e-12);
+
+        // In Kerr, circular orbits sit at extrema of V and turning points at E = V
+        let bh = KerrBlackHole::<f64>::new(1.0, 0.8);
+        let orbit = circular_orbit(&bh, 8.0, true, 1.0).unwrap();
+        let v = |r: f64| effective_potential(&bh, r, orbit.angular_momentum_z, 1.0);
+        assert!((v(8.0) - orbit.energy).abs() < 1e-12);
+        assert!(((v(8.0 + 1e-4) - v(8.0 - 1e-4)) / 2e-4).abs() < 1e-8);
+
+        let bound = ConservedQuantities { energy: 0.97, ..orbit };
+        let points = turning_points(&bh, &bound);
+        assert_eq!(points.len(), 3, "{:?}", points);
+        for &r in &points {
+            assert!((v(r) - bound.energy).abs() < 1e-9);
+        }
+        let curve = potential_curve(&bh, orbit.angular_momentum_z, 1.0, 3.0, 30.0, 28);
+        assert_eq!(curve[5], [8.0, v(8.0)]);
+    }
+
+    #[test]
+    fn test_schwarzschild_precession_closed_form() {
+        // Δφ = 4√(p / (p - 6 + 2e)) K(4e / (p - 6 + 2e)) - 2π with M = 1
+        let bh = KerrBlackHole::<f64>::schwarzschild(1.0);
+        for (p, e) in [(12.0, 0.2), (20.0, 0.5), (1000.0, 0.1)] {
+            let orbit = eccentric_orbit(&bh, p, e, true, 1.0).unwrap();
+            let denominator = p - 6.0 + 2.0 * e;
+            let exact = 4.0 * (p / denominator).sqrt() * complete_first_kind(4.0 * e / denominator) - 2.0 * std::f64::consts::PI;
+            let precession = periapsis_precession(&bh, &orbit).unwrap();
+            assert!((precession - exact).abs() < 1e-8, "p = {}: {} vs {}", p, precession, exact);
+        }
+        // Weak field: 6πM/p
+        let far = eccentric_orbit(&bh, 1000.0, 0.1, true, 1.0).unwrap();
+        assert!((periapsis_precession(&bh, &far).unwrap() - 6.0 * std::f64::consts::PI / 1000.0).abs() < 1e-4);
+        assert!(periapsis_precession(&bh, &ConservedQuantities { energy: 1.1, ..far }).is_none());
+    }
+
+    #[test]
+    fn test_kerr_precession_matches_traced_orbit() {
+        let bh = KerrBlackHole::<f64>::new(1.0, 0.7);
+        let orbit = eccentric_orbit(&bh, 10.0, 0.3, true, 1.0).unwrap();
+        let apoapsis = 10.0 / 0.7;
+        let mut particle = KerrParticle::new(bh, orbit, [apoapsis, std::f64::consts::FRAC_PI_2, 0.0], -1.0, 0.0)
+            .with_integrator(DormandPrince54::default());
+
+        // φ at each periapsis, where p_r turns from negative to positive
+        let mut passages = Vec::new();
+        while passages.len() < 3 && particle.step().is_continuing() {
+            let dense = particle.integrator.dense_output().unwrap();
+            if let Some((_, state)) = dense.find_crossing(|s| s.momentum[1]) {
+                if state.radius() < 10.0 {
+                    passages.push(state.position[3]);
+                }
+            }
+        }
+        assert_eq!(passages.len(), 3);
+        let expected = periapsis_precession(&bh, &orbit).unwrap();
+        for pair in passages.windows(2) {
+            let traced = pair[1] - pair[0] - 2.0 * std::f64::consts::PI;
+            assert!((traced - expected).abs() < 1e-5, "traced {}, integral {}", traced, expected);
+        }
+
+        // Lense–Thirring: no precession without spin, 2aM/r³ far out
+        assert_eq!(nodal_precession_frequency(&KerrBlackHole::<f64>::schwarzschild(1.0), 10.0), 0.0);
+        let r = 1e4;
+        let weak = 2.0 * bh.spin * bh.mass / (r * r * r);
+        assert!((nodal_precession_frequency(&bh, r) - weak).abs() < 0.02 * weak);
+    }
+}
@@ -0,0 +1,142 @@
+//! Frequency shift between an emitter and an observer.
+//!
+//! A photon with covariant momentum p_μ has frequency ν = -p_μ u^μ in the frame of an
+//! observer moving with four-velocity u^μ, so the ratio g = ν_obs / ν_emit combines the
+//! gravitational redshift with the Doppler shift of both ends. Specific intensity
+//! transforms as I_ν / ν³, so an emitter's brightness arrives scaled by g³ (or g⁴ when
+//! integrated over frequency).
+
+use crate::float::Float;
+use crate::observer::Observer;
+use crate::{kerr_schild, ConservedQuantities, KerrBlackHole};
+
+/// Covariant momentum p_μ = (-E, ±√R / Δ, ±√Θ, L) of a photon at Boyer-Lindquist (r, θ)
+///
+/// The signs of `radial_sign` and `polar_sign` pick the direction of motion. Slightly
+/// negative potentials from rounding are treated as turning points.
+pub fn photon_momentum<T: Float>(
+    black_hole: &KerrBlackHole<T>,
+    conserved: &ConservedQuantities<T>,
+    r: T,
+    theta: T,
+    radial_sign: T,
+    polar_sign: T,
+) -> [T; 4] {
+    let (mass, a) = (black_hole.mass, black_hole.spin);
+    let radial = conserved.radial_potential(r, mass, a).max(T::ZERO).sqrt() / kerr_schild::delta(r, mass, a);
+    let polar = conserved.polar_potential(theta, a).max(T::ZERO).sqrt();
+    [
+        -conserved.energy,
+        radial * radial_sign.signum(),
+        polar * polar_sign.signum(),
+        conserved.angular_momentum_z,
+    ]
+}
+
+/// Frequency ν = -p_μ u^μ of a photon with covariant momentum p_μ, as measured by an
+/// observer with four-velocity u^μ
+pub fn measured_frequency<T: Float>(momentum: [T; 4], velocity: [T; 4]) -> T {
+    -momentum.iter().zip(velocity).fold(T::ZERO, |sum, (&p, u)| sum + p * u)
+}
+
+/// Redshift factor g = ν_obs / ν_emit
+///
+/// `emitted` and `received` are the photon's covariant momentum at the emitter and at the
+/// observer. When neither end moves in r or θ only the conserved p_t and p_φ matter and
+/// the same momentum can be passed twice. A distant observer at rest has u = (1, 0, 0, 0).
+pub fn redshift_factor<T: Float>(emitted: [T; 4], emitter: [T; 4], received: [T; 4], observer: [T; 4]) -> T {
+    measured_frequency(received, observer) / measured_frequency(emitted, emitter)
+}
+
+/// Redshift factor from matter on the prograde or retrograde circular equatorial orbit
+/// at radius r to a distant observer at rest, for a photon with λ = L/E
+///
+/// This is the g = 1 / (u^t (1 - Ωλ)) that colors a thin disk. Returns `None` inside the
+/// photon orbit, where no circular orbit exists.
+pub fn circular_emitter_redshift<T: Float>(black_hole: &KerrBlackHole<T>, r: T, prograde: bool, lambda: T) -> Option<T> {
+    let emitter = Observer::Circular { prograde }.four_velocity(black_hole, r, T::PI * T::from_f64(0.5))?;
+    let momentum = [-T::ONE, T::ZERO, T::ZERO, lambda];
+    Some(redshift_factor(momentum, emitter, momentum, [T::ONE, T::ZERO, T::ZERO, T::ZERO]))
+}
+
+/// Redshift factor √(1 - 2Mr/Σ) from a static emitter at (r, θ) to a distant observer at
+/// rest, or `None` inside the ergosphere where nothing can stay static
+pub fn static_emitter_redshift<T: Float>(black_hole: &KerrBlackHole<T>, r: T, theta: T) -> Option<T> {
+    let emitter = Observer::Static.four_velocity(black_hole, r, theta)?;
+    Some(T::ONE / emitter[0])
+}
+
+/// Frequency of a photon with covariant momentum p_μ as seen by the zero angular momentum
+/// observer at (r, θ), or `None` at or inside the horizon
+///
+/// Only E and L enter: ν = (E - ωL) u^t with ω = 2Mar / A the frame-dragging rate.
+pub fn zamo_frequency<T: Float>(black_hole: &KerrBlackHole<T>, momentum: [T; 4], r: T, theta: T) -> Option<T> {
+    let observer = Observer::ZeroAngularMomentum.four_velocity(black_hole, r, theta)?;
+    Some(measured_frequency(momentum, observer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timelike::circular_orbit;
+
+    #[test]
+    fn test_static_and_circular_emitters() {
+        // Static emitters see only the gravitational redshift
+        let schwarzschild = KerrBlackHole::<f64>::schwarzschild(1.0);
+        let g = static_emitter_redshift(&schwarzschild, 6.0, 0.4).unwrap();
+        assert!((g - (1.0 - 2.0 / 6.0_f64).sqrt()).abs() < 1e-14);
+        let bh = KerrBlackHole::<f64>::new(1.0, 0.9);
+        let sigma = kerr_schild::sigma(3.0, 1.0, 0.9);
+        assert!((static_emitter_redshift(&bh, 3.0, 1.0).unwrap() - (1.0 - 6.0 / sigma).sqrt()).abs() < 1e-14);
+        assert!(static_emitter_redshift(&bh, 1.9, std::f64::consts::FRAC_PI_2).is_none());
+
+        // Light sent sideways from a Schwarzschild orbit: g = √(1 - 3M/r)
+        let g = circular_emitter_redshift(&schwarzschild, 10.0, true, 0.0).unwrap();
+        assert!((g - 0.7_f64.sqrt()).abs() < 1e-14);
+
+        // In Kerr, λ = 0 gives g = 1/u^t, and light sent along the orbit (λ > 0) is
+        // blueshifted relative to light sent against it
+        let r = 4.0;
+        let orbit = circular_orbit(&bh, r, true, 1.0).unwrap();
+        let u_t = (r.powf(1.5) + 0.9) / (r.powf(0.75) * (r.powf(1.5) - 3.0 * r.sqrt() + 1.8).sqrt());
+        assert!((circular_emitter_redshift(&bh, r, true, 0.0).unwrap() - 1.0 / u_t).abs() < 1e-13);
+        let (ahead, behind) = (circular_emitter_redshift(&bh, r, true, 3.0).unwrap(), circular_emitter_redshift(&bh, r, true, -3.0).unwrap());
+        assert!(ahead > 1.0 / u_t && behind < 1.0 / u_t);
+
+        // The orbit's own four-velocity gives back its energy per unit mass
+        let emitter = Observer::Circular { prograde: true }.four_velocity(&bh, r, std::f64::consts::FRAC_PI_2).unwrap();
+        let particle = [-orbit.energy, 0.0, 0.0, orbit.angular_momentum_z];
+        assert!((measured_frequency(particle, emitter) - 1.0).abs() < 1e-13);
+        assert!(circular_emitter_redshift(&bh, 1.5, false, 0.0).is_none());
+    }
+
+    #[test]
+    fn test_redshift_between_moving_endpoints() {
+        // A radial photon leaving a faller that passes a static observer at r = 8M, where it
+        // falls at v = √(2M/r) = ½: the relativistic Doppler factor √((1 - v) / (1 + v))
+        let schwarzschild = KerrBlackHole::<f64>::schwarzschild(1.0);
+        let radial = ConservedQuantities { energy: 1.0, angular_momentum_z: 0.0, carter_constant: 0.0, rest_mass: 0.0 };
+        let (r, theta) = (8.0, 1.0);
+        let outgoing = photon_momentum(&schwarzschild, &radial, r, theta, 1.0, 1.0);
+        let faller = Observer::FreeFall.four_velocity(&schwarzschild, r, theta).unwrap();
+        let hover = Observer::Static.four_velocity(&schwarzschild, r, theta).unwrap();
+        let g = redshift_factor(outgoing, faller, outgoing, hover);
+        assert!((g - (1.0_f64 / 3.0).sqrt()).abs() < 1e-14);
+
+        // Climbing out to infinity then adds the gravitational redshift
+        let distant = redshift_factor(outgoing, faller, outgoing, [1.0, 0.0, 0.0, 0.0]);
+        assert!((distant - g * static_emitter_redshift(&schwarzschild, r, theta).unwrap()).abs() < 1e-14);
+
+        // In Kerr the ZAMO sees ν = (E - ωL) u^t, whatever the photon's r and θ motion
+        let bh = KerrBlackHole::<f64>::new(1.0, 0.6);
+        let conserved = ConservedQuantities { energy: 1.0, angular_momentum_z: 2.0, carter_constant: 10.0, rest_mass: 0.0 };
+        let zamo = Observer::ZeroAngularMomentum.four_velocity(&bh, 5.0, 1.2).unwrap();
+        let omega = 2.0 * 5.0 * 0.6 / kerr_schild::a_function(5.0, 1.2, 1.0, 0.6);
+        for (radial_sign, polar_sign) in [(1.0, 1.0), (-1.0, 1.0), (1.0, -1.0)] {
+            let momentum = photon_momentum(&bh, &conserved, 5.0, 1.2, radial_sign, polar_sign);
+            let frequency = zamo_frequency(&bh, momentum, 5.0, 1.2).unwrap();
+            assert!((frequency - (1.0 - omega * 2.0) * zamo[0]).abs() < 1e-14);
+        }
+    }
+}
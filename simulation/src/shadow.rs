@@ -0,0 +1,207 @@
+//! The shadow a distant observer sees, bounded by the Bardeen critical curve.
+//!
+//! Each spherical photon orbit at radius r between the prograde and retrograde
+//! equatorial photon orbits has conserved λ(r) and η(r). Rays from the image-plane points
+//! α = -λ / sin θ_o, β = ±√(η + a² cos²θ_o - λ² cot²θ_o) asymptote onto that orbit, so
+//! those points trace the edge of the shadow as r sweeps through the photon shell.
+
+use crate::float::{bisect, Float};
+use crate::KerrBlackHole;
+
+/// Inclinations closer to the axis than this are clamped, where α = -λ / sin θ_o degenerates
+const MIN_INCLINATION: f64 = 1e-3;
+
+/// Closed curve in the image plane (α, β) of a distant observer
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShadowCurve<T: Float = f32> {
+    /// Points (α, β) going once around the curve, without repeating the first point
+    pub points: Vec<[T; 2]>,
+}
+
+impl<T: Float> ShadowCurve<T> {
+    /// Enclosed area, from the shoelace formula
+    pub fn area(&self) -> T {
+        self.signed_area().abs()
+    }
+
+    /// Centroid of the enclosed region
+    pub fn centroid(&self) -> [T; 2] {
+        let mut sum = [T::ZERO; 2];
+        for (p, q) in self.edges() {
+            let weight = cross(p, q);
+            sum[0] += (p[0] + q[0]) * weight;
+            sum[1] += (p[1] + q[1]) * weight;
+        }
+        let scale = T::from_f64(6.0) * self.signed_area();
+        [sum[0] / scale, sum[1] / scale]
+    }
+
+    /// Distance of the centroid from the image of the black hole at α = β = 0
+    pub fn centroid_displacement(&self) -> T {
+        let [alpha, beta] = self.centroid();
+        (alpha * alpha + beta * beta).sqrt()
+    }
+
+    /// Twice the mean distance R̄ of the curve from its centroid, averaged over polar angle
+    pub fn diameter(&self) -> T {
+        let (mean, _) = self.radius_moments();
+        T::from_f64(2.0) * mean
+    }
+
+    /// Johannsen–Psaltis asymmetry 2√⟨(R - R̄)²⟩, zero for a circle
+    pub fn asymmetry(&self) -> T {
+        let (_, variance) = self.radius_moments();
+        T::from_f64(2.0) * variance.sqrt()
+    }
+
+    /// Shoelace area, positive when the points run anticlockwise
+    fn signed_area(&self) -> T {
+        self.edges().fold(T::ZERO, |sum, (p, q)| sum + cross(p, q)) * T::from_f64(0.5)
+    }
+
+    /// Mean and variance of the distance from the centroid, averaged over polar angle
+    fn radius_moments(&self) -> (T, T) {
+        let center = self.centroid();
+        let polar = |p: [T; 2]| {
+            let (x, y) = (p[0] - center[0], p[1] - center[1]);
+            ((x * x + y * y).sqrt(), y.atan2(x))
+        };
+        let angle_step = |p: [T; 2], q: [T; 2]| {
+            let ((_, from), (_, to)) = (polar(p), polar(q));
+            let mut step = to - from;
+            if step > T::PI {
+                step -= T::from_f64(2.0) * T::PI;
+            } else if step < -T::PI {
+                step += T::from_f64(2.0) * T::PI;
+            }
+            step
+        };
+        let half = T::from_f64(0.5);
+        let average = |f: &dyn Fn(T) -> T| {
+            let (mut integral, mut total) = (T::ZERO, T::ZERO);
+            for (p, q) in self.edges() {
+                let step = angle_step(p, q);
+                integral += (f(polar(p).0) + f(polar(q).0)) * half * step;
+                total += step;
+            }
+            integral / total
+        };
+
+        let mean = average(&|radius| radius);
+        (mean, average(&|radius| (radius - mean) * (radius - mean)))
+    }
+
+    /// Consecutive pairs of points, closing the curve
+    fn edges(&self) -> impl Iterator<Item = ([T; 2], [T; 2])> + '_ {
+        let next = self.points.iter().cycle().skip(1);
+        self.points.iter().copied().zip(next.copied())
+    }
+}
+
+/// Two-dimensional cross product p × q
+fn cross<T: Float>(p: [T; 2], q: [T; 2]) -> T {
+    p[0] * q[1] - p[1] * q[0]
+}
+
+/// Critical curve seen at inclination θ_o, with `samples` radii through the photon shell
+/// on each of the upper and lower halves
+///
+/// # Panics
+///
+/// Panics if `samples` is less than two.
+pub fn critical_curve<T: Float>(black_hole: &KerrBlackHole<T>, inclination: T, samples: usize) -> ShadowCurve<T> {
+    assert!(samples >= 2, "need at least two samples per half of the curve");
+    let (mass, a) = (black_hole.mass, black_hole.spin);
+    let min_inclination = T::from_f64(MIN_INCLINATION);
+    let inclination = inclination.clamp(min_inclination, T::PI - min_inclination);
+    let (sin_o, cos_o) = (inclination.sin(), inclination.cos());
+
+    // Without spin every spherical orbit sits at 3M and the curve is a circle of radius √27 M
+    if a.abs() <= T::EPSILON.sqrt() * mass {
+        let radius = T::from_f64(27.0).sqrt() * mass;
+        let points = (0..2 * samples)
+            .map(|i| {
+                let angle = T::PI * T::from_f64(i as f64) / T::from_f64(samples as f64);
+                [radius * angle.cos(), radius * angle.sin()]
+            })
+            .collect();
+        return ShadowCurve { points };
+    }
+
+    let two = T::from_f64(2.0);
+    let lambda = |r: T| black_hole.spherical_photon_orbit(r).0;
+    let beta_sq = |r: T| {
+        let (l, eta) = black_hole.spherical_photon_orbit(r);
+        eta + a * a * cos_o * cos_o - l * l * cos_o * cos_o / (sin_o * sin_o)
+    };
+
+    // The visible part of the shell lies between the equatorial photon orbits, around the
+    // radius with λ = 0 where β² = η + a² cos²θ_o > 0
+    let (prograde, retrograde) = (black_hole.photon_orbit_radius(true), black_hole.photon_orbit_radius(false));
+    let retrograde_sign = lambda(retrograde).signum();
+    let polar = bisect(prograde, retrograde, |r| lambda(r).signum() == retrograde_sign);
+    let inner = bisect(prograde, polar, |r| beta_sq(r) >= T::ZERO);
+    let outer = bisect(polar, retrograde, |r| beta_sq(r) < T::ZERO);
+
+    // Cluster samples towards the ends, where β changes fastest
+    let radius = |i: usize| {
+        let fraction = (T::ONE - (T::PI * T::from_f64(i as f64) / T::from_f64((samples - 1) as f64)).cos()) / two;
+        inner + (outer - inner) * fraction
+    };
+    let point = |r: T, sign: T| [-lambda(r) / sin_o, sign * beta_sq(r).max(T::ZERO).sqrt()];
+
+    let mut points: Vec<[T; 2]> = (0..samples).map(|i| point(radius(i), T::ONE)).collect();
+    points.extend((1..samples - 1).rev().map(|i| point(radius(i), -T::ONE)));
+    ShadowCurve { points }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kerr_analytic::AnalyticRay;
+
+    #[test]
+    fn test_schwarzschild_shadow_is_a_circle() {
+        let curve = KerrBlackHole::<f64>::schwarzschild(2.0).shadow_curve(1.0, 200);
+        let radius = 27.0_f64.sqrt() * 2.0;
+        assert_eq!(curve.points.len(), 400);
+        assert!((curve.diameter() - 2.0 * radius).abs() < 1e-9);
+        assert!(curve.centroid_displacement() < 1e-9);
+        assert!(curve.asymmetry() < 1e-9);
+        assert!((curve.area() - std::f64::consts::PI * radius * radius).abs() < 1e-3 * radius * radius);
+    }
+
+    #[test]
+    fn test_kerr_shadow_edge() {
+        let bh = KerrBlackHole::<f64>::new(1.0, 0.9);
+        let edge_on = bh.shadow_curve(std::f64::consts::FRAC_PI_2, 400);
+
+        // Edge-on, the horizontal extremes are the equatorial photon orbits, with α = -λ
+        let alphas = edge_on.points.iter().map(|p| p[0]);
+        let (left, right) = alphas.fold((f64::MAX, f64::MIN), |(lo, hi), x| (lo.min(x), hi.max(x)));
+        for (extreme, prograde) in [(left, true), (right, false)] {
+            let lambda = bh.spherical_photon_orbit(bh.photon_orbit_radius(prograde)).0;
+            assert!((extreme + lambda).abs() < 1e-9, "edge at {}, expected {}", extreme, -lambda);
+        }
+
+        // Flattened on the prograde side and shifted away from it, with the size barely
+        // changed from Schwarzschild
+        let diameter = 2.0 * 27.0_f64.sqrt();
+        assert!(edge_on.centroid()[0] > 0.5 && edge_on.centroid()[1].abs() < 1e-9);
+        assert!((edge_on.centroid_displacement() - edge_on.centroid()[0]).abs() < 1e-12);
+        assert!(edge_on.asymmetry() > 0.1);
+        assert!((edge_on.diameter() - diameter).abs() < 0.05 * diameter);
+
+        // Closed-form rays just inside the curve fall in, just outside they escape
+        let inclination = 1.0;
+        let curve = bh.shadow_curve(inclination, 100);
+        let [center_alpha, center_beta] = curve.centroid();
+        for &[alpha, beta] in curve.points.iter().filter(|p| p[1].abs() > 1.0) {
+            for (scale, captured) in [(0.99, true), (1.01, false)] {
+                let (a, b) = (center_alpha + scale * (alpha - center_alpha), center_beta + scale * (beta - center_beta));
+                let ray = AnalyticRay::from_image_plane(bh, a, b, inclination).unwrap();
+                assert_eq!(ray.is_captured(), captured, "α = {}, β = {}", a, b);
+            }
+        }
+    }
+}
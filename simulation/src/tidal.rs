@@ -0,0 +1,303 @@
+//! Tidal forces felt by an observer near a Kerr black hole.
+//!
+//! Two nearby free-falling particles separated by ξ accelerate apart as
+//! d²ξ^i/dτ² = -E_ij ξ^j, where E_ij = R_{0i0j} is the Riemann tensor projected into the
+//! observer's frame. Kerr is algebraically special: in Carter's frame the curvature is
+//! fixed by the single complex number Ψ = M / (r - ia cos θ)³, with
+//! E = Re Ψ diag(-2, 1, 1) and magnetic part B = -Im Ψ diag(-2, 1, 1). Any other observer
+//! sees that curvature boosted into its own frame.
+
+use crate::constants::SPEED_OF_LIGHT;
+use crate::float::Float;
+use crate::observer::Observer;
+use crate::KerrBlackHole;
+
+/// Tidal tensor E_ij in an observer's frame, in units of 1/length²
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TidalTensor<T: Float = f32> {
+    /// Components along the observer's (r̂, θ̂, φ̂) axes
+    pub components: [[T; 3]; 3],
+}
+
+/// Tidal load on a uniform rod lined up with the principal axes of the tidal tensor
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TidalLoad<T: Float = f32> {
+    /// Acceleration of one end relative to the other along the stretching axis
+    pub stretch_acceleration: T,
+    /// Acceleration of the ends towards each other along the compressing axis
+    pub compression_acceleration: T,
+    /// Tension at the middle of the rod along the stretching axis
+    pub tension: T,
+    /// Compressive force at the middle of the rod along the compressing axis
+    pub compression: T,
+}
+
+impl<T: Float> TidalTensor<T> {
+    /// Eigenvalues of -E, largest first: positive ones stretch along their axis,
+    /// negative ones squeeze
+    ///
+    /// Uses the closed-form roots of the characteristic cubic of a symmetric matrix.
+    pub fn principal_stretches(&self) -> [T; 3] {
+        let m = self.components.map(|row| row.map(|value| -value));
+        let third = T::from_f64(1.0 / 3.0);
+        let mean = (m[0][0] + m[1][1] + m[2][2]) * third;
+        let off_diagonal = m[0][1] * m[0][1] + m[0][2] * m[0][2] + m[1][2] * m[1][2];
+        let spread = (m[0][0] - mean).powi(2) + (m[1][1] - mean).powi(2) + (m[2][2] - mean).powi(2)
+            + T::from_f64(2.0) * off_diagonal;
+        let scale = (spread / T::from_f64(6.0)).sqrt();
+        if scale == T::ZERO {
+            return [mean; 3];
+        }
+
+        // Eigenvalues of the shifted, rescaled matrix are 2 cos(φ + 2πk/3)
+        let b = |i: usize, j: usize| (m[i][j] - if i == j { mean } else { T::ZERO }) / scale;
+        let determinant = b(0, 0) * (b(1, 1) * b(2, 2) - b(1, 2) * b(2, 1))
+            - b(0, 1) * (b(1, 0) * b(2, 2) - b(1, 2) * b(2, 0))
+            + b(0, 2) * (b(1, 0) * b(2, 1) - b(1, 1) * b(2, 0));
+        let angle = (determinant * T::from_f64(0.5)).clamp(-T::ONE, T::ONE).acos() * third;
+        let two = T::from_f64(2.0);
+        let largest = mean + two * scale * angle.cos();
+        let smallest = mean + two * scale * (angle + two * T::PI * third).cos();
+        [largest, T::from_f64(3.0) * mean - largest - smallest, smallest]
+    }
+
+    /// Load on a rod of the given length and mass in free fall, centered on the observer
+    ///
+    /// The ends separate at λL for a principal stretch λ, and the tension at the middle
+    /// is mλL/8 from integrating the tidal pull over each half.
+    pub fn load(&self, length: T, body_mass: T) -> TidalLoad<T> {
+        let [stretch, _, squeeze] = self.principal_stretches();
+        let stretch = stretch.max(T::ZERO);
+        let squeeze = (-squeeze).max(T::ZERO);
+        let eighth = T::from_f64(0.125);
+        TidalLoad {
+            stretch_acceleration: stretch * length,
+            compression_acceleration: squeeze * length,
+            tension: body_mass * stretch * length * eighth,
+            compression: body_mass * squeeze * length * eighth,
+        }
+    }
+
+    /// The same tensor in s⁻², given the length of one geometric unit in meters
+    ///
+    /// With the hole's mass as the unit, `meters_per_unit` is the mass in solar masses
+    /// times `constants::SOLAR_MASS_LENGTH`.
+    pub fn in_si_units(&self, meters_per_unit: T) -> Self {
+        let scale = (T::from_f64(SPEED_OF_LIGHT) / meters_per_unit).powi(2);
+        Self { components: self.components.map(|row| row.map(|value| value * scale)) }
+    }
+}
+
+/// Tidal tensor measured by `observer` at Boyer-Lindquist radius r and polar angle θ
+///
+/// Returns `None` wherever the observer cannot exist; see `Observer::four_velocity`.
+pub fn tidal_tensor<T: Float>(black_hole: &KerrBlackHole<T>, observer: Observer, r: T, theta: T) -> Option<TidalTensor<T>> {
+    let frame = observer.carter_frame(black_hole, r, theta)?;
+    Some(electric_part(&carter_riemann(black_hole, r, theta), frame))
+}
+
+/// Tidal tensor R_abcd u^a x^b u^c y^d of `riemann` in the frame [u, e_r̂, e_θ̂, e_φ̂], with
+/// the frame's vectors given by their components in the basis `riemann` is written in
+pub(crate) fn electric_part<T: Float>(riemann: &[[[[T; 4]; 4]; 4]; 4], frame: [[T; 4]; 4]) -> TidalTensor<T> {
+    let velocity = frame[0];
+
+    let mut components = [[T::ZERO; 3]; 3];
+    for i in 0..3 {
+        for j in 0..3 {
+            let (x, y) = (frame[i + 1], frame[j + 1]);
+            let mut sum = T::ZERO;
+            for (a, block) in riemann.iter().enumerate() {
+                for (b, row) in block.iter().enumerate() {
+                    for (c, column) in row.iter().enumerate() {
+                        for (d, &value) in column.iter().enumerate() {
+                            sum += value * velocity[a] * x[b] * velocity[c] * y[d];
+                        }
+                    }
+                }
+            }
+            components[i][j] = sum;
+        }
+    }
+    TidalTensor { components }
+}
+
+/// Riemann tensor R_abcd in Carter's frame, assembled from its electric and magnetic parts
+///
+/// Boosts along e_r̂ leave Ψ alone, so this also holds in any frame whose time and radial
+/// axes span the same plane as Carter's, inside the horizon as well. In vacuum R_0i0j = E_ij, R_0ijk = ε_jkl B_il and R_ijkl = -ε_ijm ε_kln E_mn.
+pub(crate) fn carter_riemann<T: Float>(black_hole: &KerrBlackHole<T>, r: T, theta: T) -> [[[[T; 4]; 4]; 4]; 4] {
+    // Ψ = M (r + iy)³ / Σ³ with y = a cos θ
+    let y = black_hole.spin * theta.cos();
+    let sigma = r * r + y * y;
+    let scale = black_hole.mass / (sigma * sigma * sigma);
+    let three = T::from_f64(3.0);
+    let real = scale * (r * r * r - three * r * y * y);
+    let imaginary = scale * (three * r * r * y - y * y * y);
+    let principal = [-T::from_f64(2.0), T::ONE, T::ONE];
+    let electric = |i: usize, j: usize| if i == j { real * principal[i] } else { T::ZERO };
+    let magnetic = |i: usize, j: usize| if i == j { -imaginary * principal[i] } else { T::ZERO };
+
+    let mut riemann = [[[[T::ZERO; 4]; 4]; 4]; 4];
+    let mut set = |a: usize, b: usize, c: usize, d: usize, value: T| {
+        for (p, q, sign) in [(a, b, T::ONE), (b, a, -T::ONE)] {
+            for (s, t, other) in [(c, d, T::ONE), (d, c, -T::ONE)] {
+                riemann[p][q][s][t] = sign * other * value;
+                riemann[s][t][p][q] = sign * other * value;
+            }
+        }
+    };
+    for i in 0..3 {
+        for j in 0..3 {
+            set(0, i + 1, 0, j + 1, electric(i, j));
+            for k in 0..3 {
+                let value = (0..3).fold(T::ZERO, |sum, l| sum + levi_civita::<T>(j, k, l) * magnetic(i, l));
+                set(0, i + 1, j + 1, k + 1, value);
+                for l in 0..3 {
+                    let mut spatial = T::ZERO;
+                    for m in 0..3 {
+                        for n in 0..3 {
+                            spatial -= levi_civita::<T>(i, j, m) * levi_civita::<T>(k, l, n) * electric(m, n);
+                        }
+                    }
+                    if spatial != T::ZERO {
+                        set(i + 1, j + 1, k + 1, l + 1, spatial);
+                    }
+                }
+            }
+        }
+    }
+    riemann
+}
+
+/// Levi-Civita symbol ε_ijk on three spatial indices
+fn levi_civita<T: Float>(i: usize, j: usize, k: usize) -> T {
+    match (i, j, k) {
+        (0, 1, 2) | (1, 2, 0) | (2, 0, 1) => T::ONE,
+        (0, 2, 1) | (2, 1, 0) | (1, 0, 2) => -T::ONE,
+        _ => T::ZERO,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::SOLAR_MASS_LENGTH;
+    use crate::boyer_lindquist;
+    use crate::christoffel::metric_derivatives;
+    use crate::dual::Dual;
+    use crate::metric::invert;
+
+    /// Christoffel symbols Γ^a_bc from the exact dual-number derivatives of the metric
+    fn christoffel(bh: &KerrBlackHole<f64>, r: f64, theta: f64) -> [[[f64; 4]; 4]; 4] {
+        let metric = |x: [Dual<f64>; 4]| boyer_lindquist::metric_components(x[1], x[2], &bh.cast());
+        let (g, derivative) = metric_derivatives(metric, [0.0, r, theta, 0.0]);
+        let inverse = invert(&g);
+        let mut gamma = [[[0.0; 4]; 4]; 4];
+        for a in 0..4 {
+            for b in 0..4 {
+                for c in 0..4 {
+                    gamma[a][b][c] = (0..4)
+                        .map(|d| 0.5 * inverse[a][d] * (derivative[b][d][c] + derivative[c][d][b] - derivative[d][b][c]))
+                        .sum();
+                }
+            }
+        }
+        gamma
+    }
+
+    /// R_abcd in Boyer-Lindquist coordinates, by differencing the Christoffel symbols
+    fn numerical_riemann(bh: &KerrBlackHole<f64>, r: f64, theta: f64) -> [[[[f64; 4]; 4]; 4]; 4] {
+        let h = 1e-4;
+        let gamma = christoffel(bh, r, theta);
+        let mut derivative = [[[[0.0; 4]; 4]; 4]; 4];
+        for (coordinate, (dr, dtheta)) in [(1, (h, 0.0)), (2, (0.0, h))] {
+            let plus = christoffel(bh, r + dr, theta + dtheta);
+            let minus = christoffel(bh, r - dr, theta - dtheta);
+            for a in 0..4 {
+                for b in 0..4 {
+                    for c in 0..4 {
+                        derivative[coordinate][a][b][c] = (plus[a][b][c] - minus[a][b][c]) / (2.0 * h);
+                    }
+                }
+            }
+        }
+
+        // R^a_bcd = ∂_c Γ^a_db - ∂_d Γ^a_cb + Γ^a_ce Γ^e_db - Γ^a_de Γ^e_cb, then lower a
+        let g = boyer_lindquist::metric_components(r, theta, bh);
+        let mut mixed = [[[[0.0; 4]; 4]; 4]; 4];
+        for a in 0..4 {
+            for b in 0..4 {
+                for c in 0..4 {
+                    for d in 0..4 {
+                        let products: f64 = (0..4).map(|e| gamma[a][c][e] * gamma[e][d][b] - gamma[a][d][e] * gamma[e][c][b]).sum();
+                        mixed[a][b][c][d] = derivative[c][a][d][b] - derivative[d][a][c][b] + products;
+                    }
+                }
+            }
+        }
+        let mut lowered = [[[[0.0; 4]; 4]; 4]; 4];
+        for a in 0..4 {
+            for b in 0..4 {
+                for c in 0..4 {
+                    for d in 0..4 {
+                        lowered[a][b][c][d] = (0..4).map(|e| g[a][e] * mixed[e][b][c][d]).sum();
+                    }
+                }
+            }
+        }
+        lowered
+    }
+
+    #[test]
+    fn test_tidal_tensor_matches_curvature() {
+        let bh = KerrBlackHole::<f64>::new(1.0, 0.8);
+        for (r, theta) in [(3.0, 0.7), (6.0, 2.2), (2.2, std::f64::consts::FRAC_PI_2)] {
+            let riemann = numerical_riemann(&bh, r, theta);
+            for observer in [Observer::Static, Observer::ZeroAngularMomentum, Observer::FreeFall] {
+                let Some(tetrad) = observer.tetrad(&bh, r, theta) else {
+                    continue;
+                };
+                let tidal = tidal_tensor(&bh, observer, r, theta).unwrap();
+                let scale = bh.mass / (r * r * r);
+                for i in 0..3 {
+                    for j in 0..3 {
+                        let mut expected = 0.0;
+                        for a in 0..4 {
+                            for b in 0..4 {
+                                for c in 0..4 {
+                                    for d in 0..4 {
+                                        expected += riemann[a][b][c][d] * tetrad[0][a] * tetrad[i + 1][b] * tetrad[0][c] * tetrad[j + 1][d];
+                                    }
+                                }
+                            }
+                        }
+                        let actual = tidal.components[i][j];
+                        assert!((actual - expected).abs() < 1e-5 * scale, "{:?} at r = {}: E{}{} = {} vs {}", observer, r, i, j, actual, expected);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_schwarzschild_spaghettification() {
+        // Radial stretch 2M/r³ and transverse squeeze M/r³, the same whether hovering or falling
+        let bh = KerrBlackHole::<f64>::schwarzschild(1.0);
+        let r = 4.0;
+        for observer in [Observer::Static, Observer::FreeFall] {
+            let stretches = tidal_tensor(&bh, observer, r, 1.0).unwrap().principal_stretches();
+            for (actual, expected) in stretches.iter().zip([2.0, -1.0, -1.0]) {
+                assert!((actual - expected / (r * r * r)).abs() < 1e-12, "{:?}: {:?}", observer, stretches);
+            }
+        }
+
+        // A 2 m, 70 kg body at r = 4M from a 10 M☉ hole, pulled apart at millions of g
+        let tensor = tidal_tensor(&bh, Observer::FreeFall, r, 1.0).unwrap();
+        let load = tensor.in_si_units(10.0 * SOLAR_MASS_LENGTH).load(2.0, 70.0);
+        let expected = 2.0 * 2.0 / 64.0 * (SPEED_OF_LIGHT / (10.0 * SOLAR_MASS_LENGTH)).powi(2);
+        assert!((load.stretch_acceleration - expected).abs() < 1e-9 * expected);
+        assert!((load.compression_acceleration - expected / 2.0).abs() < 1e-9 * expected);
+        assert!((load.tension - 70.0 * expected / 8.0).abs() < 1e-9 * load.tension);
+        assert!(load.stretch_acceleration > 1e7);
+    }
+}
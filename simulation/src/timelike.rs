@@ -0,0 +1,398 @@
+//! Massive test particles on Kerr geodesics.
+//!
+//! A particle of rest mass μ has p_μ p^μ = -μ², so the Hamiltonian H = ½ g^μν p_μ p_ν that
+//! drives `KerrLightRay` also drives it, just on the shell H = -μ²/2 instead of H = 0.
+//! Proper time advances by dτ = μ dλ. The radial potential R(r) picks up a -Δμ²r² term,
+//! which is what makes bound orbits, plunges and the ISCO possible.
+
+use crate::boyer_lindquist::BoyerLindquist;
+use crate::float::{bisect, Float};
+use crate::metric::hamilton_equations;
+use crate::outcome::{RayEnd, RayOutcome};
+use crate::potential::{scan_turning_points, HORIZON_GAP, SCAN_LIMIT};
+use crate::{kerr_schild, AdaptiveRK45, ConservedQuantities, Geodesic, Integrator, KerrBlackHole};
+
+/// How a particle's radial motion ends up, from the turning points around its radius
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OrbitClass<T: Float = f32> {
+    /// Confined between two turning points outside the horizon
+    Bound { periapsis: T, apoapsis: T },
+    /// Reaches the horizon, possibly after turning around once further out
+    Plunging,
+    /// Reaches infinity, possibly after turning around once further in
+    Scattering,
+}
+
+/// Massive test particle following a Kerr geodesic
+#[derive(Debug, Clone)]
+pub struct KerrParticle<T: Float = f32, I: Integrator<T> = AdaptiveRK45<T>> {
+    /// Current geodesic state, with covariant momentum p_μ = μ u_μ
+    pub geodesic: Geodesic<T>,
+    /// Conserved quantities, including the rest mass
+    pub conserved: ConservedQuantities<T>,
+    /// Black hole parameters
+    pub black_hole: KerrBlackHole<T>,
+    /// Stepping method, Fehlberg RK45 unless replaced with `with_integrator`
+    pub integrator: I,
+    /// Current step size in the affine parameter λ = τ/μ
+    pub step_size: T,
+    /// Maximum number of integration steps
+    pub max_steps: u32,
+    /// Current step count
+    pub step_count: u32,
+    /// Proper time τ elapsed along the orbit
+    pub proper_time: T,
+    /// Radius beyond which the particle counts as escaped
+    pub escape_radius: T,
+}
+
+impl<T: Float> KerrParticle<T> {
+    /// Place a particle with the given conserved quantities at Boyer-Lindquist (r, θ, φ)
+    ///
+    /// p_r = ±√R / Δ and p_θ = ±√Θ follow from the potentials, with the signs of
+    /// `radial_sign` and `polar_sign` picking the direction of motion.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rest mass is not positive, the position is inside the outer horizon,
+    /// or either potential is clearly negative there, i.e. the particle cannot be there.
+    pub fn new(black_hole: KerrBlackHole<T>, conserved: ConservedQuantities<T>, position: [T; 3], radial_sign: T, polar_sign: T) -> Self {
+        assert!(conserved.rest_mass > T::ZERO, "a massive particle needs a positive rest mass");
+        let [r, theta, phi] = position;
+        let delta = kerr_schild::delta(r, black_hole.mass, black_hole.spin);
+        assert!(delta > T::ZERO, "particle must start outside the outer event horizon");
+
+        let radial = conserved.radial_potential(r, black_hole.mass, black_hole.spin);
+        let polar = conserved.polar_potential(theta, black_hole.spin);
+        let scale = conserved.energy * conserved.energy * r.powi(4) + conserved.carter_constant.abs();
+        let slack = T::from_f64(1e3) * T::EPSILON * (T::ONE + scale);
+        assert!(radial >= -slack && polar >= -slack, "the potentials forbid this position");
+
+        let p_r = radial.max(T::ZERO).sqrt() / delta * radial_sign.signum();
+        let p_theta = polar.max(T::ZERO).sqrt() * polar_sign.signum();
+        let momentum = [-conserved.energy, p_r, p_theta, conserved.angular_momentum_z];
+
+        Self {
+            geodesic: Geodesic::new([T::ZERO, r, theta, phi], momentum),
+            conserved,
+            black_hole,
+            integrator: AdaptiveRK45::default(),
+            step_size: T::from_f64(0.1),
+            max_steps: 100_000,
+            step_count: 0,
+            proper_time: T::ZERO,
+            escape_radius: T::from_f64(1000.0) * black_hole.mass,
+        }
+    }
+}
+
+impl<T: Float, I: Integrator<T>> KerrParticle<T, I> {
+    /// Swap in a different stepping method, such as `DormandPrince54`
+    pub fn with_integrator<J: Integrator<T>>(self, integrator: J) -> KerrParticle<T, J> {
+        KerrParticle {
+            geodesic: self.geodesic,
+            conserved: self.conserved,
+            black_hole: self.black_hole,
+            integrator,
+            step_size: self.step_size,
+            max_steps: self.max_steps,
+            step_count: self.step_count,
+            proper_time: self.proper_time,
+            escape_radius: self.escape_radius,
+        }
+    }
+
+    /// Take one integration step, reporting `RayOutcome::Continuing` while in flight
+    pub fn step(&mut self) -> RayOutcome<T> {
+        let current = self.outcome();
+        if !current.is_continuing() {
+            return current;
+        }
+
+        let metric = BoyerLindquist::new(self.black_hole);
+        let (new_state, actual_step, next_step) = self.integrator.step(
+            self.geodesic,
+            self.step_size,
+            |state| hamilton_equations(&metric, state),
+        );
+        if !new_state.is_finite() || !next_step.is_finite() {
+            return RayOutcome::NumericalFailure(self.end());
+        }
+
+        self.geodesic = new_state;
+        self.step_size = next_step;
+        self.step_count += 1;
+        self.proper_time += self.conserved.rest_mass * actual_step;
+        self.outcome()
+    }
+
+    /// Step until the particle is captured, escapes, runs out of steps or fails
+    pub fn trace(&mut self) -> RayOutcome<T> {
+        loop {
+            let outcome = self.step();
+            if !outcome.is_continuing() {
+                return outcome;
+            }
+        }
+    }
+
+    /// Classify the current state without stepping
+    pub fn outcome(&self) -> RayOutcome<T> {
+        let capture_radius = self.black_hole.outer_horizon() * T::from_f64(1.0 + 1e-6);
+        let captured = self.geodesic.radius() <= capture_radius;
+        let escaped = self.geodesic.radius() > self.escape_radius;
+        RayOutcome::classify(self.end(), captured, escaped, self.max_steps)
+    }
+
+    /// Classify the orbit the particle is on, from its current radius and direction
+    pub fn orbit_class(&self) -> OrbitClass<T> {
+        let inward = self.geodesic.momentum[1] < T::ZERO;
+        classify_orbit(&self.black_hole, &self.conserved, self.geodesic.radius(), inward)
+    }
+
+    /// Snapshot of the current state, with the proper time as the affine parameter
+    fn end(&self) -> RayEnd<T> {
+        RayEnd {
+            geodesic: self.geodesic,
+            step_count: self.step_count,
+            affine_parameter: self.proper_time,
+        }
+    }
+}
+
+/// Classify the radial motion of a particle at radius r
+///
+/// `inward` only matters for an unbound particle with no turning point on either side,
+/// which plunges if it is heading in and escapes otherwise.
+pub fn classify_orbit<T: Float>(black_hole: &KerrBlackHole<T>, conserved: &ConservedQuantities<T>, r: T, inward: bool) -> OrbitClass<T> {
+    match turning_points(black_hole, conserved, r) {
+        (Some(periapsis), Some(apoapsis)) => OrbitClass::Bound { periapsis, apoapsis },
+        (None, Some(_)) => OrbitClass::Plunging,
+        (Some(_), None) => OrbitClass::Scattering,
+        (None, None) if inward => OrbitClass::Plunging,
+        (None, None) => OrbitClass::Scattering,
+    }
+}
+
+/// Nearest turning points R(r) = 0 inside and outside radius r
+///
+/// The inner one is `None` if R stays positive down to the horizon, the outer one if it
+/// stays positive out to `SCAN_LIMIT` masses.
+pub(crate) fn turning_points<T: Float>(black_hole: &KerrBlackHole<T>, conserved: &ConservedQuantities<T>, r: T) -> (Option<T>, Option<T>) {
+    let (mass, horizon) = (black_hole.mass, black_hole.outer_horizon());
+    let floor = horizon + T::from_f64(HORIZON_GAP) * mass;
+    let inner = if r > floor { scan_turning_points(black_hole, conserved, r - horizon, floor).next() } else { None };
+    let outer = scan_turning_points(black_hole, conserved, r - horizon, T::from_f64(SCAN_LIMIT) * mass).next();
+    (inner, outer)
+}
+
+/// Conserved quantities of the circular equatorial orbit at radius r
+///
+/// Uses the Bardeen–Press–Teukolsky energy and angular momentum, scaled by the rest mass.
+/// Returns `None` inside the photon orbit, where circular orbits would need E = ∞.
+pub fn circular_orbit<T: Float>(black_hole: &KerrBlackHole<T>, r: T, prograde: bool, rest_mass: T) -> Option<ConservedQuantities<T>> {
+    let (mass, a) = (black_hole.mass, black_hole.spin);
+    let direction = orbit_direction(a, prograde);
+    let (sqrt_m, sqrt_r) = (mass.sqrt(), r.sqrt());
+    let two = T::from_f64(2.0);
+
+    let denominator_sq = r * sqrt_r - T::from_f64(3.0) * mass * sqrt_r + two * direction * a * sqrt_m;
+    if denominator_sq <= T::ZERO || r <= black_hole.outer_horizon() {
+        return None;
+    }
+    let denominator = r.powf(T::from_f64(0.75)) * denominator_sq.sqrt();
+    let energy = (r * sqrt_r - two * mass * sqrt_r + direction * a * sqrt_m) / denominator;
+    let angular = direction * sqrt_m * (r * r - two * direction * a * sqrt_m * sqrt_r + a * a) / denominator;
+
+    Some(ConservedQuantities {
+        energy: rest_mass * energy,
+        angular_momentum_z: rest_mass * angular,
+        carter_constant: T::ZERO,
+        rest_mass,
+    })
+}
+
+/// Conserved quantities of the eccentric equatorial orbit with semi-latus rectum p and
+/// eccentricity e, i.e. periapsis p/(1+e) and apoapsis p/(1-e)
+///
+/// Writing x = L - aE, R(r) = 0 gives E = (ax + √(Δ(r² + x²))) / r² at each turning
+/// point, and x is bisected until the two energies agree. Returns `None` if e is outside
+/// [0, 1) or no stable bound orbit has those turning points.
+pub fn eccentric_orbit<T: Float>(
+    black_hole: &KerrBlackHole<T>,
+    semi_latus_rectum: T,
+    eccentricity: T,
+    prograde: bool,
+    rest_mass: T,
+) -> Option<ConservedQuantities<T>> {
+    if !(eccentricity >= T::ZERO && eccentricity < T::ONE) {
+        return None;
+    }
+    if eccentricity == T::ZERO {
+        return circular_orbit(black_hole, semi_latus_rectum, prograde, rest_mass);
+    }
+    let (mass, a) = (black_hole.mass, black_hole.spin);
+    let periapsis = semi_latus_rectum / (T::ONE + eccentricity);
+    let apoapsis = semi_latus_rectum / (T::ONE - eccentricity);
+    if periapsis <= black_hole.outer_horizon() {
+        return None;
+    }
+
+    let energy_at = |x: T, r: T| (a * x + (kerr_schild::delta(r, mass, a) * (r * r + x * x)).sqrt()) / (r * r);
+    let mismatch = |x: T| energy_at(x, periapsis) - energy_at(x, apoapsis);
+
+    // The mismatch is negative at x = 0 and positive for large |x| in the orbit's direction
+    let direction = orbit_direction(a, prograde);
+    let mut reach = mass;
+    for _ in 0..64 {
+        if mismatch(direction * reach) > T::ZERO {
+            break;
+        }
+        reach *= T::from_f64(2.0);
+    }
+    if mismatch(direction * reach) <= T::ZERO {
+        return None;
+    }
+    let x = direction * bisect(T::ZERO, reach, |x| mismatch(direction * x) > T::ZERO);
+    let energy = energy_at(x, periapsis);
+
+    let orbit = ConservedQuantities {
+        energy: rest_mass * energy,
+        angular_momentum_z: rest_mass * (x + a * energy),
+        carter_constant: T::ZERO,
+        rest_mass,
+    };
+
+    // Between the turning points the particle must be allowed, and just inside the
+    // periapsis it must not be, or the "orbit" is really a plunge
+    let potential = |r: T| orbit.radial_potential(r, mass, a);
+    let inside = periapsis - T::from_f64(1e-4) * (apoapsis - periapsis);
+    let bound = energy < T::ONE && potential((periapsis + apoapsis) * T::from_f64(0.5)) > T::ZERO && potential(inside) < T::ZERO;
+    bound.then_some(orbit)
+}
+
+/// Radius of the marginally stable circular equatorial orbit, found without the
+/// closed-form ISCO by locating where R''(r) of the circular orbit changes sign
+pub fn marginally_stable_radius<T: Float>(black_hole: &KerrBlackHole<T>, prograde: bool) -> T {
+    let (mass, a) = (black_hole.mass, black_hole.spin);
+    let photon_orbit = black_hole.photon_orbit_radius(prograde);
+
+    // With x = L - aE and μ = 1, R'' = 8E²r² + 4E(E(r² + a²) - aL) - 2(r² + x²) - 8r(r - M) - 2Δ,
+    // negative for stable orbits; inside the photon orbit there are none (NaN)
+    let stable = |r: T| match circular_orbit(black_hole, r, prograde, T::ONE) {
+        Some(orbit) => {
+            let (e, l) = (orbit.energy, orbit.angular_momentum_z);
+            let x = l - a * e;
+            let curvature = T::from_f64(8.0) * e * e * r * r + T::from_f64(4.0) * e * (e * (r * r + a * a) - a * l)
+                - T::from_f64(2.0) * (r * r + x * x)
+                - T::from_f64(8.0) * r * (r - mass)
+                - T::from_f64(2.0) * kerr_schild::delta(r, mass, a);
+            curvature < T::ZERO
+        }
+        None => false,
+    };
+    bisect(photon_orbit, T::from_f64(10.0) * mass, stable)
+}
+
+/// +1 for orbits with L > 0, -1 otherwise, given whether they co-rotate with the hole
+pub(crate) fn orbit_direction<T: Float>(spin: T, prograde: bool) -> T {
+    let co_rotating = if spin < T::ZERO { -T::ONE } else { T::ONE };
+    if prograde { co_rotating } else { -co_rotating }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dormand_prince::DormandPrince54;
+
+    #[test]
+    fn test_marginal_stability_matches_isco() {
+        for spin in [0.0, 0.5, 0.9, -0.7] {
+            let bh = KerrBlackHole::<f64>::new(1.0, spin);
+            let isco = bh.isco_radius();
+            let prograde = true;
+            let marginal = marginally_stable_radius(&bh, prograde);
+            assert!((marginal - isco).abs() < 1e-6, "a = {}: marginal {}, ISCO {}", spin, marginal, isco);
+
+            // Retrograde orbits take the other branch of the Bardeen–Press–Teukolsky formula
+            let z1 = 1.0 + (1.0 - spin * spin).cbrt() * ((1.0 + spin).cbrt() + (1.0 - spin).cbrt());
+            let z2 = (3.0 * spin * spin + z1 * z1).sqrt();
+            let retrograde = 3.0 + z2 + ((3.0 - z1) * (3.0 + z1 + 2.0 * z2)).sqrt();
+            assert!((marginally_stable_radius(&bh, false) - retrograde).abs() < 1e-6);
+
+            // Circular orbits are double roots of R, and the ISCO has the lowest energy
+            let mu = 2.0;
+            for r in [isco, 1.5 * isco, 20.0] {
+                let orbit = circular_orbit(&bh, r, prograde, mu).unwrap();
+                let potential = |x: f64| orbit.radial_potential(x, bh.mass, bh.spin) / (mu * mu);
+                let h = 1e-4;
+                assert!(potential(r).abs() < 1e-7 * r.powi(4));
+                assert!(((potential(r + h) - potential(r - h)) / (2.0 * h)).abs() < 1e-5 * r.powi(3));
+                assert!(orbit.energy >= circular_orbit(&bh, isco, prograde, mu).unwrap().energy);
+            }
+        }
+        assert!(circular_orbit(&KerrBlackHole::<f64>::schwarzschild(1.0), 2.9, true, 1.0).is_none());
+    }
+
+    #[test]
+    fn test_traced_orbits_stay_between_turning_points() {
+        let bh = KerrBlackHole::<f64>::new(1.0, 0.7);
+        let (p, e) = (10.0, 0.3);
+        let mu = 0.5;
+        let orbit = eccentric_orbit(&bh, p, e, true, mu).unwrap();
+        assert!(orbit.energy < mu && orbit.angular_momentum_z > 0.0);
+
+        let (periapsis, apoapsis) = (p / (1.0 + e), p / (1.0 - e));
+        match classify_orbit(&bh, &orbit, p, true) {
+            OrbitClass::Bound { periapsis: inner, apoapsis: outer } => {
+                assert!((inner - periapsis).abs() < 1e-6 && (outer - apoapsis).abs() < 1e-6);
+            }
+            other => panic!("expected a bound orbit, got {:?}", other),
+        }
+
+        // Dense output pins down every radial turning point of the traced orbit
+        let mut particle = KerrParticle::new(bh, orbit, [apoapsis, std::f64::consts::FRAC_PI_2, 0.0], -1.0, 0.0)
+            .with_integrator(DormandPrince54::default());
+        let mut turns = Vec::new();
+        while particle.proper_time < 1500.0 && particle.step().is_continuing() {
+            if let Some((_, state)) = particle.integrator.dense_output().unwrap().find_crossing(|s| s.momentum[1]) {
+                turns.push(state.radius());
+            }
+        }
+        assert!(turns.len() >= 6, "only {} turning points", turns.len());
+        for r in turns {
+            let nearest = if r < p { periapsis } else { apoapsis };
+            assert!((r - nearest).abs() < 1e-5 * nearest, "turned at {}, expected {}", r, nearest);
+        }
+    }
+
+    #[test]
+    fn test_plunging_and_scattering_orbits() {
+        let bh = KerrBlackHole::<f64>::new(1.0, 0.9);
+        let mu = 2.0;
+        let orbit = |energy: f64, angular: f64| ConservedQuantities {
+            energy: mu * energy,
+            angular_momentum_z: mu * angular,
+            carter_constant: 0.0,
+            rest_mass: mu,
+        };
+        let equator = std::f64::consts::FRAC_PI_2;
+
+        // Too little angular momentum to be held off the horizon
+        let plunge = orbit(0.95, 2.0);
+        assert_eq!(classify_orbit(&bh, &plunge, 10.0, true), OrbitClass::Plunging);
+        let outcome = KerrParticle::new(bh, plunge, [10.0, equator, 0.0], -1.0, 0.0).trace();
+        assert!(matches!(outcome, RayOutcome::Captured(_)), "{:?}", outcome);
+
+        // Unbound with a centrifugal barrier: in from afar, out again
+        let flyby = orbit(1.05, 6.0);
+        assert_eq!(classify_orbit(&bh, &flyby, 50.0, true), OrbitClass::Scattering);
+        let outcome = KerrParticle::new(bh, flyby, [50.0, equator, 0.0], -1.0, 0.0).trace();
+        assert!(matches!(outcome, RayOutcome::Escaped(_)), "{:?}", outcome);
+
+        // With no barrier at all the direction of motion decides
+        let radial = orbit(1.05, 0.0);
+        assert_eq!(classify_orbit(&bh, &radial, 50.0, true), OrbitClass::Plunging);
+        assert_eq!(classify_orbit(&bh, &radial, 50.0, false), OrbitClass::Scattering);
+    }
+}
@@ -0,0 +1,342 @@
+//! Geometrically thick, optically thin torus of hot gas: the Polish doughnut.
+//!
+//! Gas orbiting with the same specific angular momentum ℓ = -u_φ / u_t everywhere is in
+//! hydrostatic equilibrium when its enthalpy h satisfies ln h = W_in - W, where the
+//! effective potential W = ln(-u_t) follows from the normalization of the four-velocity:
+//! u_t⁻² = -(g^tt - 2ℓ g^tφ + ℓ² g^φφ). The torus fills the region W < W_in bounded by the
+//! equipotential through its inner edge, and ℓ is the Keplerian value at the pressure
+//! maximum. A polytrope p = Kρ^(1 + 1/n) then has temperature ∝ h - 1 and ρ ∝ (h - 1)ⁿ.
+//!
+//! The gas both emits and absorbs as a gray body obeying Kirchhoff's law, so a ray
+//! collects I = ∫ S e^(-τ) dτ with source function S = σT⁴/π. Along a ray with affine
+//! parameter λ the gas measures path length ds = ν dλ for ν = -p_μ u^μ, and sees the light
+//! that reaches a distant camera shifted by g = E / ν, so S arrives scaled by g⁴.
+
+use crate::blackbody::thermal_color;
+use crate::float::{bisect, Float};
+use crate::outcome::RayOutcome;
+use crate::timelike::circular_orbit;
+use crate::{kerr_schild, Integrator, KerrBlackHole, KerrLightRay};
+
+/// Constant angular momentum torus co-rotating with the hole
+#[derive(Debug, Clone, Copy)]
+pub struct PolishDoughnut<T: Float = f32> {
+    /// Black hole the torus orbits
+    pub black_hole: KerrBlackHole<T>,
+    /// Specific angular momentum ℓ = -u_φ / u_t shared by all the gas
+    pub angular_momentum: T,
+    /// Equatorial radius of the inner edge
+    pub inner_radius: T,
+    /// Equatorial radius of the pressure and density maximum
+    pub pressure_max_radius: T,
+    /// Equatorial radius of the outer edge
+    pub outer_radius: T,
+    /// Polytropic index n, 3 by default for a radiation-dominated gas
+    pub polytropic_index: T,
+    /// Temperature in kelvin at the pressure maximum, 10⁴ K by default
+    pub peak_temperature: T,
+    /// Absorption coefficient at the pressure maximum per unit mass length, 0.1 by default
+    pub opacity: T,
+    /// Longest affine step a ray takes inside the torus, 0.05 by default
+    pub max_step: T,
+    /// Potential W_in on the surface
+    surface_potential: T,
+    /// h - 1 at the pressure maximum
+    peak_enthalpy: T,
+}
+
+/// What a ray traced through a torus collected
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TorusImage<T: Float = f32> {
+    /// Bolometric intensity reaching the camera, in units of σT⁴/π at the peak temperature
+    pub intensity: T,
+    /// Luminance reaching the camera, relative to a blackbody at the peak temperature
+    pub luminance: T,
+    /// Linear sRGB color whose luminance is `luminance`
+    pub rgb: [T; 3],
+    /// Optical depth accumulated along the ray
+    pub optical_depth: T,
+    /// How the ray ended once past the torus
+    pub outcome: RayOutcome<T>,
+}
+
+impl<T: Float> PolishDoughnut<T> {
+    /// Torus with its inner edge and pressure maximum at the given equatorial radii
+    ///
+    /// Returns `None` unless the pressure maximum lies outside the ISCO and the inner edge
+    /// lies between the cusp of the potential and the pressure maximum, so that the torus
+    /// is closed and does not spill onto the hole.
+    pub fn new(black_hole: KerrBlackHole<T>, inner_radius: T, pressure_max_radius: T) -> Option<Self> {
+        if inner_radius >= pressure_max_radius || pressure_max_radius <= black_hole.isco_radius() {
+            return None;
+        }
+        let orbit = circular_orbit(&black_hole, pressure_max_radius, true, T::ONE)?;
+        let mut torus = Self {
+            black_hole,
+            angular_momentum: orbit.angular_momentum_z / orbit.energy,
+            inner_radius,
+            pressure_max_radius,
+            outer_radius: pressure_max_radius,
+            polytropic_index: T::from_f64(3.0),
+            peak_temperature: T::from_f64(1e4),
+            opacity: T::from_f64(0.1),
+            max_step: T::from_f64(0.05),
+            surface_potential: T::ZERO,
+            peak_enthalpy: T::ZERO,
+        };
+
+        let equator = T::PI * T::from_f64(0.5);
+        let surface = torus.potential(inner_radius, equator)?;
+        let center = torus.potential(pressure_max_radius, equator)?;
+        // Past the cusp the potential between the edge and the maximum rises above W_in
+        let samples = 64;
+        let closed = surface < T::ZERO
+            && (1..samples).all(|i| {
+                let r = inner_radius + (pressure_max_radius - inner_radius) * T::from_f64(i as f64 / samples as f64);
+                torus.potential(r, equator).is_some_and(|w| w <= surface)
+            });
+        if !closed {
+            return None;
+        }
+        torus.surface_potential = surface;
+        torus.peak_enthalpy = (surface - center).exp() - T::ONE;
+
+        // Far from the hole W climbs back to zero, so the outer edge is bracketed
+        let mut far = pressure_max_radius;
+        while torus.potential(far, equator)? < surface {
+            far *= T::from_f64(1.1);
+        }
+        torus.outer_radius = bisect(pressure_max_radius, far, |r| {
+            torus.potential(r, equator).is_some_and(|w| w >= surface)
+        });
+        Some(torus)
+    }
+
+    /// Effective potential W = ln(-u_t) at (r, θ), or `None` where no gas with this ℓ can orbit
+    pub fn potential(&self, r: T, theta: T) -> Option<T> {
+        let norm = self.inverse_norm(r, theta)?;
+        Some(-norm.ln() * T::from_f64(0.5))
+    }
+
+    /// Potential W_in on the torus surface
+    pub fn surface_potential(&self) -> T {
+        self.surface_potential
+    }
+
+    /// Specific enthalpy minus one, h - 1, at the pressure maximum
+    pub fn peak_enthalpy(&self) -> T {
+        self.peak_enthalpy
+    }
+
+    /// Whether (r, θ) lies inside the torus
+    pub fn contains(&self, r: T, theta: T) -> bool {
+        r >= self.inner_radius && r <= self.outer_radius && self.potential(r, theta).is_some_and(|w| w < self.surface_potential)
+    }
+
+    /// Temperature in kelvin at (r, θ), zero outside the torus
+    pub fn temperature(&self, r: T, theta: T) -> T {
+        self.peak_temperature * self.relative_temperature(r, theta)
+    }
+
+    /// Density at (r, θ) relative to the pressure maximum, zero outside the torus
+    pub fn density(&self, r: T, theta: T) -> T {
+        self.relative_temperature(r, theta).powf(self.polytropic_index)
+    }
+
+    /// Four-velocity u^μ of the gas at (r, θ) in Boyer-Lindquist coordinates, or `None`
+    /// where it cannot orbit
+    pub fn four_velocity(&self, r: T, theta: T) -> Option<[T; 4]> {
+        let norm = self.inverse_norm(r, theta)?;
+        let [tt, t_phi, phi_phi] = self.inverse_metric(r, theta);
+        let u_t = -T::ONE / norm.sqrt();
+        let ell = self.angular_momentum;
+        Some([u_t * (tt - ell * t_phi), T::ZERO, T::ZERO, u_t * (t_phi - ell * phi_phi)])
+    }
+
+    /// Redshift factor from the gas at (r, θ) to a distant observer receiving light with
+    /// λ = L/E, or `None` where the gas cannot orbit
+    pub fn redshift(&self, r: T, theta: T, lambda: T) -> Option<T> {
+        let [u_t, _, _, u_phi] = self.four_velocity(r, theta)?;
+        Some(T::ONE / (u_t - u_phi * lambda))
+    }
+
+    /// (h - 1) / (h_c - 1), the temperature relative to the pressure maximum
+    fn relative_temperature(&self, r: T, theta: T) -> T {
+        if !self.contains(r, theta) {
+            return T::ZERO;
+        }
+        let w = self.potential(r, theta).unwrap_or(self.surface_potential);
+        (((self.surface_potential - w).exp() - T::ONE) / self.peak_enthalpy).max(T::ZERO)
+    }
+
+    /// u_t⁻² for gas with this ℓ, if positive and outside the horizon
+    fn inverse_norm(&self, r: T, theta: T) -> Option<T> {
+        if r <= self.black_hole.outer_horizon() {
+            return None;
+        }
+        let [tt, t_phi, phi_phi] = self.inverse_metric(r, theta);
+        let ell = self.angular_momentum;
+        let norm = -(tt - T::from_f64(2.0) * ell * t_phi + ell * ell * phi_phi);
+        (norm > T::ZERO).then_some(norm)
+    }
+
+    /// Inverse Boyer-Lindquist metric components (g^tt, g^tφ, g^φφ)
+    fn inverse_metric(&self, r: T, theta: T) -> [T; 3] {
+        let (mass, a) = (self.black_hole.mass, self.black_hole.spin);
+        let sin_sq = theta.sin().powi(2);
+        let sigma = kerr_schild::sigma(r, theta, a);
+        let delta = kerr_schild::delta(r, mass, a);
+        let scale = T::ONE / (delta * sigma);
+        [
+            -kerr_schild::a_function(r, theta, mass, a) * scale,
+            -T::from_f64(2.0) * mass * a * r * scale,
+            (delta - a * a * sin_sq) * scale / sin_sq,
+        ]
+    }
+}
+
+/// Step `ray`, made past-directed, through `torus`, collecting emission and absorption,
+/// until it stops
+///
+/// The light it collects has energy -E and the ray's own λ = L/E. Steps are capped at
+/// `torus.max_step` within the torus's outer radius, and each is integrated with the
+/// trapezoidal rule in affine parameter.
+pub(crate) fn trace<T: Float, I: Integrator<T>>(ray: &mut KerrLightRay<T, I>, torus: &PolishDoughnut<T>) -> TorusImage<T> {
+    ray.make_past_directed();
+    let energy = -ray.conserved.energy;
+    let lambda = ray.conserved.angular_momentum_z / ray.conserved.energy;
+    let reference = thermal_color(torus.peak_temperature, T::ONE).luminance;
+
+    // dτ/dλ and the bolometric and luminance-weighted color source functions as seen
+    // by the camera
+    let sample = |r: T, theta: T| {
+        let relative = torus.relative_temperature(r, theta);
+        let redshift = torus.redshift(r, theta, lambda).filter(|_| relative > T::ZERO);
+        let Some(g) = redshift else {
+            return (T::ZERO, T::ZERO, [T::ZERO; 3]);
+        };
+        let absorption = torus.opacity * relative.powf(torus.polytropic_index) * energy / g;
+        let color = thermal_color(torus.peak_temperature * relative, g);
+        let luminance = color.luminance / reference;
+        (absorption, (g * relative).powi(4), color.rgb.map(|channel| channel * luminance))
+    };
+
+    let mut image = TorusImage {
+        intensity: T::ZERO,
+        luminance: T::ZERO,
+        rgb: [T::ZERO; 3],
+        optical_depth: T::ZERO,
+        outcome: RayOutcome::Continuing,
+    };
+    let mut previous = sample(ray.tracer.geodesic.radius(), ray.tracer.geodesic.position[2]);
+    loop {
+        if ray.tracer.geodesic.radius() <= torus.outer_radius {
+            ray.tracer.step_size = ray.tracer.step_size.min(torus.max_step);
+        }
+        let start = ray.tracer.affine_parameter;
+        let outcome = ray.step();
+        let step = ray.tracer.affine_parameter - start;
+        let current = sample(ray.tracer.geodesic.radius(), ray.tracer.geodesic.position[2]);
+
+        let half = T::from_f64(0.5);
+        let depth = (previous.0 + current.0) * half * step;
+        if depth > T::ZERO {
+            let weight = (-image.optical_depth).exp() * (T::ONE - (-depth).exp()) * half;
+            image.intensity += weight * (previous.1 + current.1);
+            for (channel, (a, b)) in image.rgb.iter_mut().zip(previous.2.iter().zip(current.2)) {
+                *channel += weight * (*a + b);
+            }
+            image.optical_depth += depth;
+        }
+        previous = current;
+
+        if !outcome.is_continuing() {
+            // sRGB luminance weights recover Y from the accumulated color
+            let [r, g, b] = image.rgb;
+            image.luminance = T::from_f64(0.2126) * r + T::from_f64(0.7152) * g + T::from_f64(0.0722) * b;
+            image.outcome = outcome;
+            return image;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_torus_structure() {
+        // In Schwarzschild the equatorial potential is W = -½ ln(r/(r - 2M) - ℓ²/r²)
+        let schwarzschild = KerrBlackHole::<f64>::schwarzschild(1.0);
+        let torus = PolishDoughnut::new(schwarzschild, 8.0, 12.0).unwrap();
+        let ell = torus.angular_momentum;
+        assert!((ell - 12.0_f64.sqrt() * 12.0 / 10.0).abs() < 1e-12);
+        let equator = std::f64::consts::FRAC_PI_2;
+        for r in [7.0, 12.0, 20.0] {
+            let expected = -0.5 * (r / (r - 2.0) - ell * ell / (r * r)).ln();
+            assert!((torus.potential(r, equator).unwrap() - expected).abs() < 1e-14);
+        }
+
+        // Both edges lie on the surface, the peak temperature sits at the pressure maximum
+        // and the gas is hotter towards the midplane
+        let surface = torus.potential(8.0, equator).unwrap();
+        assert!((torus.potential(torus.outer_radius, equator).unwrap() - surface).abs() < 1e-12);
+        assert!(torus.outer_radius > 20.0 && torus.outer_radius < 25.0);
+        assert!((torus.temperature(12.0, equator) - 1e4).abs() < 1e-8);
+        assert!(torus.temperature(12.0, 1.3) < torus.temperature(12.0, 1.5));
+        assert!(torus.temperature(7.9, equator) == 0.0 && torus.density(torus.outer_radius + 0.1, equator) == 0.0);
+
+        // Spilling over the cusp or a pressure maximum inside the ISCO give no torus
+        let bh = KerrBlackHole::<f64>::new(1.0, 0.9);
+        assert!(PolishDoughnut::new(bh, 6.0, 10.0).is_some());
+        assert!(PolishDoughnut::new(bh, 1.8, 10.0).is_none());
+        assert!(PolishDoughnut::new(bh, 2.0, 2.2).is_none());
+    }
+
+    #[test]
+    fn test_gas_orbits_with_constant_angular_momentum() {
+        let bh = KerrBlackHole::<f64>::new(1.0, 0.9);
+        let torus = PolishDoughnut::new(bh, 6.0, 10.0).unwrap();
+        let (r, theta) = (9.0, 1.3);
+        let u = torus.four_velocity(r, theta).unwrap();
+        let g = kerr_schild::metric_components(r, theta, &bh);
+        let lower = [g[0][0] * u[0] + g[0][3] * u[3], g[3][0] * u[0] + g[3][3] * u[3]];
+
+        // metric_components is Kerr-Schild, whose t and φ differ from Boyer-Lindquist by
+        // functions of r alone, so they agree on vectors with no radial part
+        assert!((lower[0] * u[0] + lower[1] * u[3] + 1.0).abs() < 1e-12);
+        assert!((-lower[1] / lower[0] - torus.angular_momentum).abs() < 1e-12);
+        assert!((torus.potential(r, theta).unwrap() - (-lower[0]).ln()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_emission_and_absorption() {
+        let bh = KerrBlackHole::<f64>::new(1.0, 0.5);
+        let camera = [50.0 * 1.3_f64.sin(), 0.0, 50.0 * 1.3_f64.cos()];
+        let image = |opacity: f64, target: [f64; 3]| {
+            let mut torus = PolishDoughnut::new(bh, 8.0, 12.0).unwrap();
+            torus.opacity = opacity;
+            let direction = [0, 1, 2].map(|i| target[i] - camera[i]);
+            KerrLightRay::new(camera, direction, bh).trace_torus(&torus)
+        };
+
+        // Optically thin, the light grows with the opacity and the optical depth with it
+        let thin = image(1e-4, [0.0, 12.0, 0.0]);
+        let thicker = image(2e-4, [0.0, 12.0, 0.0]);
+        assert!(thin.intensity > 0.0 && thin.luminance > 0.0);
+        assert!((thicker.optical_depth / thin.optical_depth - 2.0).abs() < 1e-9);
+        assert!((thicker.intensity / thin.intensity - 2.0).abs() < 1e-2);
+        assert!(matches!(thin.outcome, RayOutcome::Escaped(_)));
+
+        // Optically thick, absorption hides the hot core behind ever cooler outer layers
+        let thick = image(1e2, [0.0, 12.0, 0.0]);
+        let thickest = image(1e3, [0.0, 12.0, 0.0]);
+        assert!(thick.optical_depth > 10.0 && thickest.intensity < thick.intensity);
+        let luminance = 0.2126 * thick.rgb[0] + 0.7152 * thick.rgb[1] + 0.0722 * thick.rgb[2];
+        assert!((luminance - thick.luminance).abs() < 1e-12);
+
+        // Over the pole the ray misses the torus entirely
+        let missed = image(1.0, [0.0, 0.0, 30.0]);
+        assert_eq!((missed.intensity, missed.optical_depth), (0.0, 0.0));
+    }
+}
@@ -0,0 +1,180 @@
+//! Opt-in recording of the path a ray takes.
+//!
+//! A tracer with a `TrajectoryRecorder` attached stores (λ, t, r, θ, φ) together with
+//! Cartesian points after every `decimation` steps, up to `max_points` entries. The
+//! starting state and the state the ray stops in are always kept, even when the ray
+//! fails numerically; a full recorder gives up its last point to the final state.
+
+use crate::float::Float;
+use crate::metric::cartesian_position;
+use crate::Geodesic;
+
+/// One recorded point along a ray
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrajectoryPoint<T: Float = f32> {
+    /// Affine parameter λ
+    pub affine_parameter: T,
+    /// Spacetime position (t, r, θ, φ)
+    pub position: [T; 4],
+    /// Cartesian point (x, y, z) in the same convention as camera positions
+    pub cartesian: [T; 3],
+}
+
+impl<T: Float> TrajectoryPoint<T> {
+    /// Create a point from a geodesic state
+    pub fn new(affine_parameter: T, geodesic: &Geodesic<T>) -> Self {
+        Self {
+            affine_parameter,
+            position: geodesic.position,
+            cartesian: cartesian_position(geodesic.position),
+        }
+    }
+}
+
+/// Decimating, length-limited recorder of a ray's trajectory
+#[derive(Debug, Clone)]
+pub struct TrajectoryRecorder<T: Float = f32> {
+    /// Keep one point every `decimation` steps
+    pub decimation: u32,
+    /// Maximum number of points kept
+    pub max_points: usize,
+    /// Recorded points in order of increasing λ
+    pub points: Vec<TrajectoryPoint<T>>,
+    /// Set once a point had to be dropped because the recorder was full
+    pub truncated: bool,
+    steps_since_record: u32,
+}
+
+impl<T: Float> TrajectoryRecorder<T> {
+    /// Create a recorder keeping every `decimation`-th step, up to `max_points` points
+    ///
+    /// # Panics
+    ///
+    /// Panics if `decimation` is zero.
+    pub fn new(decimation: u32, max_points: usize) -> Self {
+        assert!(decimation > 0, "decimation must be at least one step");
+        Self {
+            decimation,
+            max_points,
+            points: Vec::new(),
+            truncated: false,
+            steps_since_record: 0,
+        }
+    }
+
+    /// Record the state a ray starts from, unless something was already recorded
+    pub fn record_start(&mut self, affine_parameter: T, geodesic: &Geodesic<T>) {
+        if self.points.is_empty() {
+            self.push(affine_parameter, geodesic);
+        }
+    }
+
+    /// Record the state after a step if it falls on the decimation, or through
+    /// `record_end` if `last` is set
+    pub fn record_step(&mut self, affine_parameter: T, geodesic: &Geodesic<T>, last: bool) {
+        if last {
+            self.record_end(affine_parameter, geodesic);
+            return;
+        }
+        self.steps_since_record += 1;
+        if self.steps_since_record >= self.decimation {
+            self.push(affine_parameter, geodesic);
+        }
+    }
+
+    /// Record the state a ray stops in, displacing the last point if the recorder is full
+    pub fn record_end(&mut self, affine_parameter: T, geodesic: &Geodesic<T>) {
+        let point = TrajectoryPoint::new(affine_parameter, geodesic);
+        if self.points.last() == Some(&point) {
+            return;
+        }
+        if self.points.len() >= self.max_points {
+            self.truncated = true;
+            if self.points.pop().is_none() {
+                return;
+            }
+        }
+        self.points.push(point);
+    }
+
+    /// Cartesian points only, e.g. for plotting
+    pub fn cartesian_points(&self) -> Vec<[T; 3]> {
+        self.points.iter().map(|point| point.cartesian).collect()
+    }
+
+    fn push(&mut self, affine_parameter: T, geodesic: &Geodesic<T>) {
+        self.steps_since_record = 0;
+        if self.points.len() >= self.max_points {
+            self.truncated = true;
+            return;
+        }
+        self.points.push(TrajectoryPoint::new(affine_parameter, geodesic));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::outcome::RayOutcome;
+    use crate::{KerrBlackHole, KerrLightRay, LightRay};
+
+    #[test]
+    fn test_recorded_kerr_trajectory() {
+        let bh = KerrBlackHole::<f64>::new(1.0, 0.9);
+        let mut ray = KerrLightRay::new([20.0, 0.0, 2.0], [-1.0, 0.35, 0.0], bh);
+        let start = ray.tracer.geodesic;
+        let (outcome, trajectory) = ray.trace_recorded(TrajectoryRecorder::new(4, 10_000));
+        let end = outcome.end().unwrap();
+
+        assert!(matches!(outcome, RayOutcome::Escaped(_)));
+        assert!(!trajectory.truncated);
+        assert_eq!(trajectory.points.len() as u32, 1 + end.step_count.div_ceil(4));
+
+        // Starts at the camera, ends in the final state, with λ increasing in between
+        let first = trajectory.points[0];
+        let last = trajectory.points[trajectory.points.len() - 1];
+        assert_eq!(first.position, start.position);
+        assert_eq!(last.position, end.geodesic.position);
+        assert_eq!(last.affine_parameter, end.affine_parameter);
+        assert!(trajectory.points.windows(2).all(|w| w[1].affine_parameter > w[0].affine_parameter));
+
+        for point in &trajectory.points {
+            let [x, y, z] = point.cartesian;
+            assert!(((x * x + y * y + z * z).sqrt() - point.position[1]).abs() < 1e-9);
+        }
+        assert!((first.cartesian[0] - 20.0).abs() < 1e-9 && (first.cartesian[2] - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_recorder_stops_at_max_points() {
+        let mut ray = LightRay::new([10.0, 0.0, 0.0], [-1.0, 1.0, 0.0], 1.0);
+        ray.tracer.max_steps = 50;
+        let (outcome, trajectory) = ray.trace_recorded(TrajectoryRecorder::new(1, 20));
+
+        assert!(matches!(outcome, RayOutcome::MaxSteps(_)));
+        assert_eq!(trajectory.points.len(), 20);
+        assert!(trajectory.truncated);
+        assert_eq!(trajectory.cartesian_points().len(), 20);
+        assert_eq!(trajectory.points[19].position, outcome.end().unwrap().geodesic.position);
+    }
+
+    #[test]
+    fn test_recorder_keeps_state_after_numerical_failure() {
+        let mut ray = LightRay::new([10.0, 0.0, 0.0], [-1.0, 1.0, 0.0], 1.0);
+        let start = ray.tracer.geodesic;
+        ray.tracer.trajectory = Some(TrajectoryRecorder::new(8, 100));
+        for _ in 0..3 {
+            ray.tracer.step();
+        }
+        // A poisoned step size makes the next step fail
+        ray.tracer.step_size = f32::NAN;
+        let outcome = ray.tracer.step();
+        let trajectory = ray.tracer.trajectory.take().unwrap();
+
+        assert!(matches!(outcome, RayOutcome::NumericalFailure(_)));
+        assert_eq!(trajectory.points.len(), 2);
+        assert_eq!(trajectory.points[0].position, start.position);
+        assert_eq!(trajectory.points[1].position, outcome.end().unwrap().geodesic.position);
+        assert_ne!(trajectory.points[1].position, start.position);
+    }
+}
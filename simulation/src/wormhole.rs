@@ -0,0 +1,119 @@
+//! Traversable Ellis wormhole, the simplest Morris–Thorne wormhole.
+//!
+//! In proper radial distance ℓ the metric is ds² = -dt² + dℓ² + (ℓ² + b²) dΩ², with the
+//! throat of radius b at ℓ = 0. Positive ℓ is our universe and negative ℓ is the far
+//! side. There is no horizon and no redshift, so a light ray either passes through the
+//! throat or turns back, depending only on its impact parameter.
+
+use crate::float::Float;
+use crate::metric::{spherical_ray, GeodesicTracer};
+use crate::Geodesic;
+
+/// Ellis wormhole parameters
+#[derive(Debug, Clone, Copy)]
+pub struct EllisWormhole {
+    /// Throat radius b in geometric units
+    pub throat_radius: f32,
+}
+
+impl EllisWormhole {
+    /// Create a new Ellis wormhole
+    pub fn new(throat_radius: f32) -> Self {
+        Self { throat_radius }
+    }
+
+    /// Areal radius √(ℓ² + b²) at proper radial distance ℓ
+    pub fn areal_radius(&self, l: f32) -> f32 {
+        (l * l + self.throat_radius * self.throat_radius).sqrt()
+    }
+
+    /// Check if a position (t, ℓ, θ, φ) is on the far side of the throat
+    pub fn is_beyond_throat(&self, position: [f32; 4]) -> bool {
+        position[1] < 0.0
+    }
+
+    /// Check if a ray with impact parameter h = L/E passes through the throat
+    ///
+    /// The radial equation (dℓ/dλ)² = E² - L²/(ℓ² + b²) has a turning point only when h > b.
+    pub fn passes_throat(&self, impact_parameter: f32) -> bool {
+        impact_parameter.abs() < self.throat_radius
+    }
+
+    /// Create a light ray from a camera on our side of the throat
+    ///
+    /// The camera's distance from the origin is its proper distance ℓ, and the ray
+    /// direction is taken in the camera's local orthonormal frame, so the impact
+    /// parameter is √(ℓ² + b²) sin α for a ray at angle α from the outward radial.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the camera sits at the origin, or if the ray direction is zero or not
+    /// finite.
+    pub fn light_ray(self, camera_pos: [f32; 3], ray_dir: [f32; 3]) -> GeodesicTracer<Self> {
+        let (position, [dl, dtheta, dphi]) = spherical_ray(camera_pos, ray_dir);
+        let l = position[1];
+        let sin_theta = position[2].sin();
+
+        // Flat-space coordinate rates give the orthonormal components of the direction
+        let n = [dl, l * dtheta, l * sin_theta * dphi];
+        let norm = (n[0] * n[0] + n[1] * n[1] + n[2] * n[2]).sqrt();
+
+        // Covariant momentum with E = 1: p_i = √g_ii n_i
+        let areal = self.areal_radius(l);
+        let momentum = [-1.0, n[0] / norm, areal * n[1] / norm, areal * sin_theta * n[2] / norm];
+
+        GeodesicTracer::from_geodesic(self, Geodesic::new(position, momentum))
+    }
+}
+
+/// Calculate the metric components at proper distance ℓ and polar angle θ
+pub fn metric_components<T: Float>(l: T, theta: T, wormhole: &EllisWormhole) -> [[T; 4]; 4] {
+    let throat_radius: T = wormhole.throat_radius.cast();
+    let areal_sq = l * l + throat_radius * throat_radius;
+
+    let mut g = [[T::ZERO; 4]; 4];
+    g[0][0] = -T::ONE;
+    g[1][1] = T::ONE;
+    g[2][2] = areal_sq;
+    g[3][3] = areal_sq * theta.sin().powi(2);
+    g
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::Metric;
+
+    /// Trace a ray leaving ℓ = 20 at angle α from the inward radial with impact parameter h
+    fn trace_with_impact_parameter(wormhole: EllisWormhole, h: f32) -> GeodesicTracer<EllisWormhole> {
+        let l = 20.0;
+        let sin_alpha = h / wormhole.areal_radius(l);
+        let cos_alpha = (1.0 - sin_alpha * sin_alpha).sqrt();
+
+        let mut ray = wormhole.light_ray([l, 0.0, 0.0], [-cos_alpha, sin_alpha, 0.0]);
+        assert!(ray.hamiltonian().abs() < 1e-5);
+        ray.trace();
+        assert!(ray.has_escaped());
+        ray
+    }
+
+    #[test]
+    fn test_ray_through_throat() {
+        let wormhole = EllisWormhole::new(1.0);
+        let ray = trace_with_impact_parameter(wormhole, 0.7);
+
+        assert!(wormhole.passes_throat(0.7));
+        assert!(wormhole.is_beyond_throat(ray.geodesic.position));
+        assert!(ray.hamiltonian().abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_ray_turns_back_outside_throat() {
+        let wormhole = EllisWormhole::new(1.0);
+        let ray = trace_with_impact_parameter(wormhole, 1.4);
+
+        assert!(!wormhole.passes_throat(1.4));
+        assert!(!wormhole.is_beyond_throat(ray.geodesic.position));
+        assert!(!wormhole.is_inside_horizon(ray.geodesic.position));
+    }
+}
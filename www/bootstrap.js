@@ -228,6 +228,70 @@ window.updateDebugInfo = function(position, orientation, lastKey, fps, renderWid
     `Resolution: ${renderWidth.toFixed(0)}x${renderHeight.toFixed(0)}`;
 };
 
+window.updateTidalInfo = function(radius, stretch, compression, tension) {
+  const element = document.getElementById('debug-tidal');
+  if (!element) {
+    return;
+  }
+  if (!isFinite(stretch)) {
+    element.textContent = `Tides at r = ${radius.toFixed(2)} M: inside the horizon`;
+    return;
+  }
+  // Accelerations across a 2 m, 70 kg body near a 10 solar-mass hole, in units of Earth gravity
+  const g = 9.81;
+  element.textContent =
+    `Tides at r = ${radius.toFixed(2)} M: stretch ${(stretch / g).toExponential(2)} g, ` +
+    `squeeze ${(compression / g).toExponential(2)} g, tension ${tension.toExponential(2)} N`;
+};
+
+// Where a fall inside the hole ends
+const fallEnds = {
+  'singularity': 'to the singularity',
+  'ring': 'to passing through the ring',
+  'inner-horizon': 'to the inner horizon',
+};
+
+window.updateFallInfo = function(falling, radius, timeRemaining, end) {
+  const element = document.getElementById('fall-counter');
+  if (!element) {
+    return;
+  }
+  element.style.display = falling ? 'block' : 'none';
+  if (!falling) {
+    return;
+  }
+  // Proper time near a 10 solar-mass hole
+  const remaining = isFinite(timeRemaining)
+    ? `${(timeRemaining * 1000).toFixed(3)} ms ${fallEnds[end]}`
+    : 'escaping';
+  element.textContent = `Free fall at r = ${radius.toFixed(2)} M: ${remaining}`;
+};
+
+window.updateObserverInfo = function(visible, observer, fallback) {
+  const element = document.getElementById('observer-counter');
+  if (!element) {
+    return;
+  }
+  element.style.display = visible ? 'block' : 'none';
+  // An observer that cannot exist here, such as a static one inside the ergosphere
+  element.textContent = fallback
+    ? `Observer: ${observer} cannot exist here, showing ${fallback}`
+    : `Observer: ${observer}`;
+};
+
+window.updateChargeLimit = function(charge, maxCharge) {
+  const slider = document.getElementById('charge-slider');
+  const element = document.getElementById('charge-value');
+  if (!slider || !element) {
+    return;
+  }
+  // A near-extremal spin leaves little or no room for charge under a² + Q² ≤ M²
+  const requested = parseFloat(slider.value);
+  element.textContent = Math.abs(requested) > maxCharge + 1e-3
+    ? `${requested.toFixed(1)} → ${charge.toFixed(2)} (a² + Q² ≤ M²)`
+    : requested.toFixed(1);
+};
+
 window.updateFpsCounter = function(fps, visible) {
   const fpsCounter = document.getElementById('fps-counter');
   if (fpsCounter) {
@@ -327,6 +391,26 @@ function setupDebugControls() {
     (value) => wasmModule?.set_debug_spin?.(value), 
     (value) => value.toFixed(1));
 
+  // Charge slider (limited to a² + Q² ≤ M² by the simulation)
+  setupSlider('charge-slider', 'charge-value', 
+    (value) => wasmModule?.set_debug_charge?.(value), 
+    (value) => value.toFixed(1));
+
+  // Wormhole throat radius slider
+  setupSlider('throat-slider', 'throat-value', 
+    (value) => wasmModule?.set_debug_throat_radius?.(value), 
+    (value) => value.toFixed(1));
+
+  // Accretion disk outer radius slider, in units of the mass
+  setupSlider('disk-outer-slider', 'disk-outer-value', 
+    (value) => wasmModule?.set_debug_disk_outer_radius?.(value), 
+    (value) => value.toFixed(0));
+
+  // Jet bulk Lorentz factor slider
+  setupSlider('jet-lorentz-slider', 'jet-lorentz-value', 
+    (value) => wasmModule?.set_debug_jet_lorentz_factor?.(value), 
+    (value) => value.toFixed(1));
+
   // Ray steps slider
   setupSlider('ray-steps-slider', 'ray-steps-value', 
     (value) => wasmModule?.set_debug_ray_steps?.(value), 